@@ -3,5 +3,7 @@ pub mod log;
 pub mod macros;
 #[cfg(feature = "magical_rs")]
 pub use magical_rs::*;
+#[cfg(feature = "py")]
+pub mod py;
 #[cfg(feature = "types")]
 pub mod types;
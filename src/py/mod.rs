@@ -0,0 +1,4 @@
+//! Python standard library counterparts.
+
+mod pystruct;
+pub use pystruct::*;
@@ -0,0 +1,788 @@
+use crate::types::{ByteOrder, Ordered};
+use std::ffi::{c_int, c_long, c_longlong, c_short, c_void};
+use std::mem::{align_of, size_of};
+
+/// Errors raised by [`pack`], [`unpack`] and [`calcsize`], worded like Python's `struct.error`.
+#[derive(Debug, Clone, Eq, PartialEq, thiserror::Error)]
+pub enum Error {
+    #[error("bad char in struct format")]
+    BadChar,
+    #[error("repeat count given without format specifier")]
+    RepeatWithoutFormat,
+    #[error("total struct size too long")]
+    TooLong,
+    #[error("pack expected {expected} items for packing (got {actual})")]
+    ItemCount { expected: usize, actual: usize },
+    #[error("'{code}' format requires {min} <= number <= {max}")]
+    OutOfRange { code: char, min: i128, max: i128 },
+    #[error("required argument is not an integer")]
+    NotAnInteger,
+    #[error("required argument is not a float")]
+    NotAFloat,
+    #[error("char format requires a bytes object of length 1")]
+    CharLength,
+    #[error("argument for '{0}' must be a bytes object")]
+    NotBytes(char),
+    #[error("float too large to pack with {0} format")]
+    FloatOverflow(char),
+    #[error("unpack requires a buffer of {0} bytes")]
+    BufferSize(usize),
+    #[error("unpack_from requires a buffer of at least {expected} bytes for unpacking {size} bytes at offset {offset} (actual buffer size is {actual})")]
+    BufferOffset {
+        expected: usize,
+        size: usize,
+        offset: usize,
+        actual: usize,
+    },
+}
+
+/// A Python object as seen by the `struct` module.
+///
+/// Integer formats unpack to `Int`, `e`/`f`/`d` to `Float`, `?` to `Bool` and `c`/`s`/`p` to
+/// `Bytes`. Packing is as lenient as Python: a `Bool` or `Int` packs into a float format, and any
+/// value packs into `?` by its truthiness.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Bool(bool),
+    Int(i128),
+    Float(f64),
+    Bytes(Vec<u8>),
+}
+
+impl Value {
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Bool(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn as_int(&self) -> Option<i128> {
+        match self {
+            Self::Int(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn as_float(&self) -> Option<f64> {
+        match self {
+            Self::Float(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            Self::Bytes(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    fn truthy(&self) -> bool {
+        match self {
+            Self::Bool(v) => *v,
+            Self::Int(v) => *v != 0,
+            Self::Float(v) => *v != 0.,
+            Self::Bytes(v) => !v.is_empty(),
+        }
+    }
+
+    fn to_int(&self) -> Result<i128, Error> {
+        match self {
+            Self::Bool(v) => Ok(*v as i128),
+            Self::Int(v) => Ok(*v),
+            _ => Err(Error::NotAnInteger),
+        }
+    }
+
+    fn to_float(&self) -> Result<f64, Error> {
+        match self {
+            Self::Bool(v) => Ok(*v as u8 as f64),
+            Self::Int(v) => Ok(*v as f64),
+            Self::Float(v) => Ok(*v),
+            Self::Bytes(_) => Err(Error::NotAFloat),
+        }
+    }
+}
+
+macro_rules! value_from {
+    ($variant:ident: $($ty:ty),*) => {
+        $(
+        impl From<$ty> for Value {
+            #[inline]
+            fn from(v: $ty) -> Self {
+                Self::$variant(v.into())
+            }
+        }
+        )*
+    };
+}
+
+value_from!(Bool: bool);
+value_from!(Int: i8, i16, i32, i64, i128, u8, u16, u32, u64);
+value_from!(Float: f32, f64);
+value_from!(Bytes: Vec<u8>, &[u8]);
+
+impl From<usize> for Value {
+    #[inline]
+    fn from(v: usize) -> Self {
+        Self::Int(v as i128)
+    }
+}
+
+impl From<isize> for Value {
+    #[inline]
+    fn from(v: isize) -> Self {
+        Self::Int(v as i128)
+    }
+}
+
+impl<const N: usize> From<&[u8; N]> for Value {
+    #[inline]
+    fn from(v: &[u8; N]) -> Self {
+        Self::Bytes(v.to_vec())
+    }
+}
+
+/// One `<count><code>` unit of a format string.
+#[derive(Debug, Clone, Copy)]
+struct Item {
+    code: u8,
+    count: usize,
+    size: usize,
+    align: usize,
+}
+
+impl Item {
+    /// How many values the item consumes or produces.
+    const fn values(&self) -> usize {
+        match self.code {
+            b'x' => 0,
+            b's' | b'p' => 1,
+            _ => self.count,
+        }
+    }
+}
+
+/// The parsed prefix of a format string: the byte order and whether native sizes and
+/// alignment apply (`@` only).
+const fn prefix(fmt: &[u8]) -> (ByteOrder, bool, usize) {
+    if fmt.is_empty() {
+        return (ByteOrder::Native, true, 0);
+    }
+    match fmt[0] {
+        b'@' => (ByteOrder::Native, true, 1),
+        b'=' => (ByteOrder::Native, false, 1),
+        b'<' => (ByteOrder::Little, false, 1),
+        b'>' | b'!' => (ByteOrder::Big, false, 1),
+        _ => (ByteOrder::Native, true, 0),
+    }
+}
+
+/// Size and alignment of a format code, `None` if the code is unknown in this mode.
+const fn layout(code: u8, native: bool) -> Option<(usize, usize)> {
+    if native {
+        return match code {
+            b'x' | b'c' | b'b' | b'B' | b'?' | b's' | b'p' => Some((1, 1)),
+            b'h' | b'H' => Some((size_of::<c_short>(), align_of::<c_short>())),
+            b'i' | b'I' => Some((size_of::<c_int>(), align_of::<c_int>())),
+            b'l' | b'L' => Some((size_of::<c_long>(), align_of::<c_long>())),
+            b'q' | b'Q' => Some((size_of::<c_longlong>(), align_of::<c_longlong>())),
+            b'n' | b'N' => Some((size_of::<isize>(), align_of::<isize>())),
+            b'e' => Some((2, align_of::<c_short>())),
+            b'f' => Some((size_of::<f32>(), align_of::<f32>())),
+            b'd' => Some((size_of::<f64>(), align_of::<f64>())),
+            b'P' => Some((size_of::<*const c_void>(), align_of::<*const c_void>())),
+            _ => None,
+        };
+    }
+    match code {
+        b'x' | b'c' | b'b' | b'B' | b'?' | b's' | b'p' => Some((1, 1)),
+        b'h' | b'H' | b'e' => Some((2, 1)),
+        b'i' | b'I' | b'l' | b'L' | b'f' => Some((4, 1)),
+        b'q' | b'Q' | b'd' => Some((8, 1)),
+        _ => None,
+    }
+}
+
+/// Parses the item starting at `pos`, skipping whitespace; returns the item and the position
+/// after it, or `None` at the end of the format.
+const fn next_item(
+    fmt: &[u8],
+    mut pos: usize,
+    native: bool,
+) -> Result<Option<(Item, usize)>, Error> {
+    while pos < fmt.len() && fmt[pos].is_ascii_whitespace() {
+        pos += 1;
+    }
+    if pos == fmt.len() {
+        return Ok(None);
+    }
+
+    let mut count: usize = 1;
+    if fmt[pos].is_ascii_digit() {
+        count = 0;
+        while pos < fmt.len() && fmt[pos].is_ascii_digit() {
+            count = match count.checked_mul(10) {
+                Some(v) => match v.checked_add((fmt[pos] - b'0') as usize) {
+                    Some(v) => v,
+                    None => return Err(Error::TooLong),
+                },
+                None => return Err(Error::TooLong),
+            };
+            pos += 1;
+        }
+        if pos == fmt.len() {
+            return Err(Error::RepeatWithoutFormat);
+        }
+    }
+
+    let code = fmt[pos];
+    match layout(code, native) {
+        Some((size, align)) => Ok(Some((
+            Item {
+                code,
+                count,
+                size,
+                align,
+            },
+            pos + 1,
+        ))),
+        None => Err(Error::BadChar),
+    }
+}
+
+/// Walks the format once, returning the packed size and the number of values.
+const fn measure(fmt: &str) -> Result<(usize, usize), Error> {
+    let fmt = fmt.as_bytes();
+    let (_, native, mut pos) = prefix(fmt);
+    let (mut size, mut values) = (0usize, 0usize);
+    loop {
+        match next_item(fmt, pos, native) {
+            Ok(Some((item, next))) => {
+                size = match size.checked_next_multiple_of(item.align) {
+                    Some(v) => v,
+                    None => return Err(Error::TooLong),
+                };
+                size = match item.size.checked_mul(item.count) {
+                    Some(v) => match size.checked_add(v) {
+                        Some(v) => v,
+                        None => return Err(Error::TooLong),
+                    },
+                    None => return Err(Error::TooLong),
+                };
+                values += item.values();
+                pos = next;
+            }
+            Ok(None) => return Ok((size, values)),
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// Returns the size of the struct described by `fmt`, like Python's `struct.calcsize`.
+///
+/// # Example
+///
+/// ```rust
+/// use rsutil::py::calcsize;
+///
+/// assert_eq!(calcsize("<IHB"), Ok(7));
+/// assert_eq!(calcsize(">2sxq"), Ok(11));
+/// assert_eq!(calcsize("=bi"), Ok(5));
+/// #[cfg(target_pointer_width = "64")]
+/// assert_eq!(calcsize("@bi"), Ok(8));
+/// assert!(calcsize("<3").is_err());
+/// ```
+pub const fn calcsize(fmt: &str) -> Result<usize, Error> {
+    match measure(fmt) {
+        Ok((size, _)) => Ok(size),
+        Err(e) => Err(e),
+    }
+}
+
+/// Validates `fmt` against `values` at compile time; used by [`pack!`](crate::pack),
+/// [`unpack!`](crate::unpack) and [`calcsize!`](crate::calcsize).
+#[doc(hidden)]
+pub const fn __check(fmt: &str, values: Option<usize>) -> (usize, usize) {
+    match measure(fmt) {
+        Ok((size, count)) => {
+            if let Some(values) = values {
+                if values != count {
+                    panic!("number of values does not match the struct format");
+                }
+            }
+            (size, count)
+        }
+        Err(Error::BadChar) => panic!("bad char in struct format"),
+        Err(Error::RepeatWithoutFormat) => panic!("repeat count given without format specifier"),
+        Err(_) => panic!("total struct size too long"),
+    }
+}
+
+fn int_range(code: u8, size: usize) -> (i128, i128) {
+    let bits = size as u32 * 8;
+    match code {
+        // like `PyLong_AsVoidPtr`, a pointer takes negative values too
+        b'P' => (-(1i128 << (bits - 1)), (1i128 << bits) - 1),
+        b'B' | b'H' | b'I' | b'L' | b'Q' | b'N' => (0, (1i128 << bits) - 1),
+        _ => (-(1i128 << (bits - 1)), (1i128 << (bits - 1)) - 1),
+    }
+}
+
+fn write_int(out: &mut Vec<u8>, order: ByteOrder, size: usize, value: i128) {
+    // two's complement truncation is exact once the range check has passed
+    match size {
+        1 => out.push(value as u8),
+        2 => out.extend_from_slice(&(value as u16).to_ordered(order)),
+        4 => out.extend_from_slice(&(value as u32).to_ordered(order)),
+        _ => out.extend_from_slice(&(value as u64).to_ordered(order)),
+    }
+}
+
+fn read_int(buf: &[u8], order: ByteOrder, signed: bool) -> i128 {
+    let value = match buf.len() {
+        1 => buf[0] as u64,
+        2 => order.read_u16(buf).unwrap_or_default() as u64,
+        4 => order.read_u32(buf).unwrap_or_default() as u64,
+        _ => order.read_u64(buf).unwrap_or_default(),
+    };
+    let bits = buf.len() as u32 * 8;
+    if signed && bits < 128 && value >> (bits - 1) & 1 == 1 {
+        value as i128 - (1i128 << bits)
+    } else {
+        value as i128
+    }
+}
+
+/// IEEE 754 binary16 bits of `value`, rounded half to even; `None` when it overflows.
+fn f16_bits(value: f64) -> Option<u16> {
+    let sign = if value.is_sign_negative() { 0x8000 } else { 0 };
+    if value.is_nan() {
+        return Some(sign | 0x7E00);
+    }
+    if value.is_infinite() {
+        return Some(sign | 0x7C00);
+    }
+    let abs = value.abs();
+    if abs < 2f64.powi(-14) {
+        // subnormal: units of 2^-24, a carry into 1024 becomes the smallest normal
+        return Some(sign | (abs * 2f64.powi(24)).round_ties_even() as u16);
+    }
+    let mut exp = abs.log2().floor() as i32;
+    // log2 may be off by one right at a power of two
+    if 2f64.powi(exp) > abs {
+        exp -= 1;
+    } else if 2f64.powi(exp + 1) <= abs {
+        exp += 1;
+    }
+    let mut frac = (abs * 2f64.powi(10 - exp)).round_ties_even() as u16;
+    if frac == 2048 {
+        frac = 1024;
+        exp += 1;
+    }
+    if exp > 15 {
+        return None;
+    }
+    Some(sign | ((exp + 15) as u16) << 10 | (frac - 1024))
+}
+
+fn f16_value(bits: u16) -> f64 {
+    let sign = if bits & 0x8000 != 0 { -1. } else { 1. };
+    let exp = (bits >> 10 & 0x1F) as i32;
+    let frac = (bits & 0x3FF) as f64;
+    sign * match exp {
+        0 => frac * 2f64.powi(-24),
+        0x1F if frac == 0. => f64::INFINITY,
+        0x1F => f64::NAN,
+        _ => (1. + frac / 1024.) * 2f64.powi(exp - 15),
+    }
+}
+
+/// Packs `values` according to `fmt`, like Python's `struct.pack`.
+///
+/// The prefix maps onto [`ByteOrder`]: `<` is `Little`, `>` and `!` are `Big`, `@` and `=`
+/// are `Native`. Only `@` (the default) uses native sizes and alignment.
+///
+/// # Example
+///
+/// ```rust
+/// use rsutil::py::{pack, Value};
+///
+/// assert_eq!(
+///     pack("<IHB", &[1u32.into(), 2u16.into(), 3u8.into()]),
+///     Ok(vec![1, 0, 0, 0, 2, 0, 3])
+/// );
+/// assert_eq!(
+///     pack(">2sxq", &[b"abc".into(), (-1i64).into()]),
+///     Ok(b"ab\x00\xff\xff\xff\xff\xff\xff\xff\xff".to_vec())
+/// );
+/// assert_eq!(pack("<?e", &[Value::Int(7), 1.5.into()]), Ok(vec![1, 0x00, 0x3E]));
+/// assert!(pack("<B", &[256.into()]).is_err());
+/// ```
+pub fn pack(fmt: &str, values: &[Value]) -> Result<Vec<u8>, Error> {
+    let (size, expected) = measure(fmt)?;
+    if expected != values.len() {
+        return Err(Error::ItemCount {
+            expected,
+            actual: values.len(),
+        });
+    }
+
+    let bytes = fmt.as_bytes();
+    let (order, native, mut pos) = prefix(bytes);
+    let mut out = Vec::with_capacity(size);
+    let mut values = values.iter();
+    while let Some((item, next)) = next_item(bytes, pos, native)? {
+        pos = next;
+        out.resize(out.len().next_multiple_of(item.align), 0);
+        let code = item.code as char;
+        match item.code {
+            b'x' => out.resize(out.len() + item.count, 0),
+            b's' | b'p' => {
+                let Some(Value::Bytes(data)) = values.next() else {
+                    return Err(Error::NotBytes(code));
+                };
+                let start = out.len();
+                if item.code == b'p' && item.count > 0 {
+                    let len = data.len().min(item.count - 1).min(255);
+                    out.push(len as u8);
+                    out.extend_from_slice(&data[..len]);
+                } else {
+                    out.extend_from_slice(&data[..data.len().min(item.count)]);
+                }
+                out.resize(start + item.count, 0);
+            }
+            _ => {
+                for value in values.by_ref().take(item.count) {
+                    match item.code {
+                        b'c' => match value {
+                            Value::Bytes(v) if v.len() == 1 => out.push(v[0]),
+                            _ => return Err(Error::CharLength),
+                        },
+                        b'?' => out.push(value.truthy() as u8),
+                        b'e' => {
+                            let bits =
+                                f16_bits(value.to_float()?).ok_or(Error::FloatOverflow(code))?;
+                            out.extend_from_slice(&bits.to_ordered(order));
+                        }
+                        b'f' => {
+                            // the native format casts like C, standard ones fail like Python
+                            let v = value.to_float()?;
+                            if !native && v.is_finite() && (v as f32).is_infinite() {
+                                return Err(Error::FloatOverflow(code));
+                            }
+                            out.extend_from_slice(&(v as f32).to_ordered(order));
+                        }
+                        b'd' => out.extend_from_slice(&value.to_float()?.to_ordered(order)),
+                        _ => {
+                            let v = value.to_int()?;
+                            let (min, max) = int_range(item.code, item.size);
+                            if v < min || v > max {
+                                return Err(Error::OutOfRange { code, min, max });
+                            }
+                            write_int(&mut out, order, item.size, v);
+                        }
+                    }
+                }
+            }
+        }
+    }
+    Ok(out)
+}
+
+/// Unpacks `buf` according to `fmt`, like Python's `struct.unpack`; `buf` must be exactly
+/// [`calcsize`] bytes long.
+///
+/// # Example
+///
+/// ```rust
+/// use rsutil::py::{unpack, Value};
+///
+/// let values = unpack("<hB3s?", b"\xfe\xff\x07abc\x01").unwrap();
+/// assert_eq!(
+///     values,
+///     vec![Value::Int(-2), Value::Int(7), Value::Bytes(b"abc".to_vec()), Value::Bool(true)]
+/// );
+/// assert!(unpack("<I", b"\x00\x00").is_err());
+/// ```
+pub fn unpack(fmt: &str, buf: &[u8]) -> Result<Vec<Value>, Error> {
+    let size = calcsize(fmt)?;
+    if buf.len() != size {
+        return Err(Error::BufferSize(size));
+    }
+    unpack_exact(fmt, buf)
+}
+
+/// Unpacks from `buf` starting at `offset`, like Python's `struct.unpack_from`; trailing
+/// bytes are ignored.
+pub fn unpack_from(fmt: &str, buf: &[u8], offset: usize) -> Result<Vec<Value>, Error> {
+    let size = calcsize(fmt)?;
+    match buf.get(offset..).and_then(|rest| rest.get(..size)) {
+        Some(data) => unpack_exact(fmt, data),
+        None => Err(Error::BufferOffset {
+            expected: offset.saturating_add(size),
+            size,
+            offset,
+            actual: buf.len(),
+        }),
+    }
+}
+
+fn unpack_exact(fmt: &str, buf: &[u8]) -> Result<Vec<Value>, Error> {
+    let bytes = fmt.as_bytes();
+    let (order, native, mut pos) = prefix(bytes);
+    let mut values = Vec::new();
+    let mut offset: usize = 0;
+    while let Some((item, next)) = next_item(bytes, pos, native)? {
+        pos = next;
+        offset = offset.next_multiple_of(item.align);
+        match item.code {
+            b'x' => offset += item.count,
+            b's' => {
+                values.push(Value::Bytes(buf[offset..offset + item.count].to_vec()));
+                offset += item.count;
+            }
+            b'p' => {
+                let data = &buf[offset..offset + item.count];
+                let len = data
+                    .first()
+                    .map_or(0, |&v| (v as usize).min(item.count.saturating_sub(1)));
+                values.push(Value::Bytes(
+                    data.get(1..1 + len).unwrap_or_default().to_vec(),
+                ));
+                offset += item.count;
+            }
+            code => {
+                for _ in 0..item.count {
+                    let data = &buf[offset..offset + item.size];
+                    values.push(match code {
+                        b'c' => Value::Bytes(data.to_vec()),
+                        b'?' => Value::Bool(data[0] != 0),
+                        b'e' => Value::Float(f16_value(order.read_u16(data).unwrap_or_default())),
+                        b'f' => Value::Float(order.read_f32(data).unwrap_or_default() as f64),
+                        b'd' => Value::Float(order.read_f64(data).unwrap_or_default()),
+                        _ => Value::Int(read_int(data, order, code.is_ascii_lowercase())),
+                    });
+                    offset += item.size;
+                }
+            }
+        }
+    }
+    Ok(values)
+}
+
+/// Packs values with a format string checked at compile time.
+///
+/// A malformed format or a wrong number of values fails the build; range and type errors
+/// are still reported at runtime through [`pack`](crate::py::pack()).
+///
+/// # Example
+///
+/// ```rust
+/// use rsutil::pack;
+///
+/// assert_eq!(pack!("!HH", 1u16, 2u16), Ok(vec![0, 1, 0, 2]));
+/// assert_eq!(pack!("<2sxq", b"ab", 1i64).unwrap().len(), 11);
+/// ```
+///
+/// ```compile_fail
+/// rsutil::pack!("<IH", 1u32);
+/// ```
+#[macro_export]
+macro_rules! pack {
+    ($fmt:literal $(, $value:expr)* $(,)?) => {{
+        const _: (usize, usize) =
+            $crate::py::__check($fmt, Some([$(stringify!($value)),*].len()));
+        $crate::py::pack($fmt, &[$($crate::py::Value::from($value)),*])
+    }};
+}
+
+/// Unpacks a buffer with a format string checked at compile time, returning an array sized to
+/// the format so it can be destructured.
+///
+/// # Example
+///
+/// ```rust
+/// use rsutil::{py::Value, unpack};
+///
+/// let [tag, len] = unpack!(">BH", &[0x01, 0x00, 0x10]).unwrap();
+/// assert_eq!(tag, Value::Int(1));
+/// assert_eq!(len.as_int(), Some(16));
+/// ```
+///
+/// ```compile_fail
+/// rsutil::unpack!("<Z", &[0u8]);
+/// ```
+#[macro_export]
+macro_rules! unpack {
+    ($fmt:literal, $buf:expr $(,)?) => {{
+        const __N: usize = $crate::py::__check($fmt, None).1;
+        $crate::py::unpack($fmt, $buf).map(|values| {
+            match <[$crate::py::Value; __N]>::try_from(values) {
+                Ok(values) => values,
+                Err(_) => unreachable!("the format was checked at compile time"),
+            }
+        })
+    }};
+}
+
+/// Evaluates the size of a struct format at compile time.
+///
+/// # Example
+///
+/// ```rust
+/// const SIZE: usize = rsutil::calcsize!("<IHB");
+/// assert_eq!(SIZE, 7);
+/// assert_eq!(rsutil::calcsize!("@bi"), rsutil::py::calcsize("@bi").unwrap());
+/// ```
+///
+/// ```compile_fail
+/// let size = rsutil::calcsize!("<Z");
+/// ```
+#[macro_export]
+macro_rules! calcsize {
+    ($fmt:literal) => {{
+        const SIZE: usize = $crate::py::__check($fmt, None).0;
+        SIZE
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Packs the values and unpacks the result, checking both against CPython.
+    fn check(fmt: &str, values: &[Value], bytes: &[u8], unpacked: &[Value]) {
+        assert_eq!(pack(fmt, values).as_deref(), Ok(bytes), "{fmt}");
+        assert_eq!(unpack(fmt, bytes).as_deref(), Ok(unpacked), "{fmt}");
+    }
+
+    fn ints(values: &[i128]) -> Vec<Value> {
+        values.iter().map(|&v| Value::Int(v)).collect()
+    }
+
+    fn floats(values: &[f64]) -> Vec<Value> {
+        values.iter().map(|&v| Value::Float(v)).collect()
+    }
+
+    #[test]
+    fn test_standard() {
+        // the results of CPython's `struct.pack` and `struct.unpack`
+        let values = ints(&[
+            -128,
+            255,
+            -32768,
+            65535,
+            -1 << 31,
+            (1 << 32) - 1,
+            -1,
+            7,
+            -1 << 63,
+        ]);
+        let bytes = b"\x80\xff\x00\x80\xff\xff\x00\x00\x00\x80\xff\xff\xff\xff\xff\xff\xff\xff\
+            \x07\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x80";
+        check("<bBhHiIlLq", &values, bytes, &values);
+        let values = ints(&[127, 0, 32767, 1, (1 << 31) - 1, 0x01020304, -2, 1 << 31]);
+        let bytes = b"\x7f\x00\x7f\xff\x00\x01\x7f\xff\xff\xff\x01\x02\x03\x04\xff\xff\xff\xfe\
+            \x80\x00\x00\x00";
+        check(">bBhHiIlL", &values, bytes, &values);
+        let values = ints(&[(1 << 63) - 1, 0x0102030405060708, (1 << 64) - 1]);
+        let bytes = b"\x7f\xff\xff\xff\xff\xff\xff\xff\x01\x02\x03\x04\x05\x06\x07\x08\
+            \xff\xff\xff\xff\xff\xff\xff\xff";
+        check("!qQQ", &values, bytes, &values);
+
+        let values = floats(&[1.5, -0., 0.1]);
+        let bytes = b"\x3e\x00\x80\x00\x00\x00\x3f\xb9\x99\x99\x99\x99\x99\x9a";
+        check("!efd", &values, bytes, &values);
+        let bytes = b"\xff\x7b\x01\x00\x00\xfc";
+        let unpacked = floats(&[65504., 5.960464477539063e-8, f64::NEG_INFINITY]);
+        check(
+            "<eee",
+            &floats(&[65504., 6e-8, f64::NEG_INFINITY]),
+            bytes,
+            &unpacked,
+        );
+        let bytes = b"\x7f\x7f\xff\xff\x00\x00\x00\x00";
+        let unpacked = floats(&[3.4028234663852886e38, 0.]);
+        check(">ff", &floats(&[3.4028235e38, 1e-46]), bytes, &unpacked);
+
+        let values = [true.into(), b"z".into(), b"abcd".into(), b"hi".into()];
+        let unpacked = [true.into(), b"z".into(), b"abc".into(), b"hi".into()];
+        check("=?c3s5px", &values, b"\x01zabc\x02hi\0\0\0", &unpacked);
+        let values = [0.into(), 5.into(), b"abcdef".into()];
+        let unpacked = [false.into(), true.into(), b"abc".into()];
+        check("<2?4p", &values, b"\x00\x01\x03abc", &unpacked);
+
+        let overflows = [
+            ("<f", 1e300, 'f'),
+            ("=f", -1e300, 'f'),
+            ("!e", 65520., 'e'),
+            ("e", 1e300, 'e'),
+        ];
+        for (fmt, value, code) in overflows {
+            assert_eq!(
+                pack(fmt, &[value.into()]),
+                Err(Error::FloatOverflow(code)),
+                "{fmt}"
+            );
+        }
+        assert!(pack("<B", &[256.into()]).is_err());
+        assert!(pack("=I", &[(-1).into()]).is_err());
+    }
+
+    #[test]
+    #[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+    fn test_native() {
+        // the results of CPython's `struct.pack` and `struct.unpack` on x86-64 Linux
+        let max = ints(&[(1 << 64) - 1]);
+        check("P", &ints(&[-1]), b"\xff\xff\xff\xff\xff\xff\xff\xff", &max);
+        check("P", &max, b"\xff\xff\xff\xff\xff\xff\xff\xff", &max);
+        let bytes = b"\x00\x00\x00\x00\x00\x00\x00\x80";
+        check("@P", &ints(&[-1 << 63]), bytes, &ints(&[1 << 63]));
+        assert!(pack("P", &[Value::Int(1 << 64)]).is_err());
+        assert!(pack("P", &[Value::Int((-1 << 63) - 1)]).is_err());
+
+        // unlike the standard sizes, native floats overflow to infinity
+        let bytes = b"\x00\x00\x80\x7f";
+        check("f", &floats(&[1e300]), bytes, &floats(&[f64::INFINITY]));
+        let bytes = b"\x00\x00\x80\xff";
+        check(
+            "@f",
+            &floats(&[-1e300]),
+            bytes,
+            &floats(&[f64::NEG_INFINITY]),
+        );
+        check("e", &floats(&[1.]), b"\x00\x3c", &floats(&[1.]));
+
+        let values = ints(&[1, -1]);
+        let bytes = b"\x01\0\0\0\0\0\0\0\xff\xff\xff\xff\xff\xff\xff\xff";
+        check("bq", &values, bytes, &values);
+        let values = [b"x".into(), 2.into()];
+        check("ci", &values, b"x\0\0\0\x02\0\0\0", &values);
+        let values = [3.into(), 0.5.into()];
+        check(
+            "bd",
+            &values,
+            b"\x03\0\0\0\0\0\0\0\0\0\0\0\0\0\xe0\x3f",
+            &values,
+        );
+        let values = [true.into(), 1.into()];
+        check(
+            "?P",
+            &values,
+            b"\x01\0\0\0\0\0\0\0\x01\0\0\0\0\0\0\0",
+            &values,
+        );
+        let values = ints(&[-3, 3]);
+        let bytes = b"\xfd\xff\xff\xff\xff\xff\xff\xff\x03\0\0\0\0\0\0\0";
+        check("nN", &values, bytes, &values);
+        let values = ints(&[-2, 1 << 40]);
+        check(
+            "hl",
+            &values,
+            b"\xfe\xff\0\0\0\0\0\0\0\0\0\0\0\x01\0\0",
+            &values,
+        );
+    }
+}
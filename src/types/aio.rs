@@ -0,0 +1,104 @@
+use super::{ByteOrder, Ordered};
+use std::{future::Future, io::Result};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+macro_rules! ordered_methods {
+    (read_ordered => $($name:ident: $ty:ty),* $(,)?) => {
+        $(
+        #[doc = concat!("Reads a `", stringify!($ty), "` in the given byte order.")]
+        #[inline]
+        fn $name(&mut self, order: ByteOrder) -> impl Future<Output = Result<$ty>> + '_ {
+            self.read_ordered(order)
+        }
+        )*
+    };
+    (write_ordered => $($name:ident: $ty:ty),* $(,)?) => {
+        $(
+        #[doc = concat!("Writes a `", stringify!($ty), "` in the given byte order.")]
+        #[inline]
+        fn $name(
+            &mut self,
+            order: ByteOrder,
+            value: $ty,
+        ) -> impl Future<Output = Result<()>> + '_ {
+            self.write_ordered(order, value)
+        }
+        )*
+    };
+}
+
+/// Async counterpart of [`ReadBytesOrdered`](super::ReadBytesOrdered) for tokio's [`AsyncRead`].
+///
+/// The methods carry an `_ordered` suffix so they never clash with tokio's own big-endian
+/// [`AsyncReadExt::read_u16`] and friends. Like theirs, the futures are `Send` whenever the
+/// reader is, so a reader that isn't can still be used on a `LocalSet`.
+///
+/// # Example
+///
+/// ```rust
+/// use rsutil::types::{AsyncReadBytesOrdered, ByteOrder};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let mut stream: &[u8] = &[0x34, 0x12, 0x00, 0x00, 0x80, 0x3F];
+///     assert_eq!(stream.read_u16_ordered(ByteOrder::Little).await.unwrap(), 0x1234);
+///     assert_eq!(stream.read_f32_ordered(ByteOrder::Little).await.unwrap(), 1.0);
+///     assert!(stream.read_u16_ordered(ByteOrder::Big).await.is_err());
+/// }
+/// ```
+pub trait AsyncReadBytesOrdered: AsyncRead + Unpin {
+    /// Reads a `T` in the given byte order.
+    fn read_ordered<T: Ordered>(
+        &mut self,
+        order: ByteOrder,
+    ) -> impl Future<Output = Result<T>> + '_ {
+        async move {
+            let mut bytes = T::Bytes::default();
+            self.read_exact(bytes.as_mut())
+                .await
+                .map(|_| T::from_ordered(order, bytes))
+        }
+    }
+
+    ordered_methods!(read_ordered => read_u16_ordered: u16, read_u32_ordered: u32,
+        read_u64_ordered: u64, read_u128_ordered: u128, read_i16_ordered: i16,
+        read_i32_ordered: i32, read_i64_ordered: i64, read_i128_ordered: i128,
+        read_f32_ordered: f32, read_f64_ordered: f64);
+}
+
+impl<R: AsyncRead + Unpin + ?Sized> AsyncReadBytesOrdered for R {}
+
+/// Async counterpart of [`WriteBytesOrdered`](super::WriteBytesOrdered) for tokio's [`AsyncWrite`].
+///
+/// The futures are `Send` whenever the writer is.
+///
+/// # Example
+///
+/// ```rust
+/// use rsutil::types::{AsyncWriteBytesOrdered, ByteOrder};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let mut out = Vec::new();
+///     out.write_u32_ordered(ByteOrder::Big, 0x01020304).await.unwrap();
+///     out.write_i16_ordered(ByteOrder::Little, -2).await.unwrap();
+///     assert_eq!(out, vec![0x01, 0x02, 0x03, 0x04, 0xFE, 0xFF]);
+/// }
+/// ```
+pub trait AsyncWriteBytesOrdered: AsyncWrite + Unpin {
+    /// Writes a `T` in the given byte order.
+    fn write_ordered<T: Ordered>(
+        &mut self,
+        order: ByteOrder,
+        value: T,
+    ) -> impl Future<Output = Result<()>> + '_ {
+        async move { self.write_all(value.to_ordered(order).as_ref()).await }
+    }
+
+    ordered_methods!(write_ordered => write_u16_ordered: u16, write_u32_ordered: u32,
+        write_u64_ordered: u64, write_u128_ordered: u128, write_i16_ordered: i16,
+        write_i32_ordered: i32, write_i64_ordered: i64, write_i128_ordered: i128,
+        write_f32_ordered: f32, write_f64_ordered: f64);
+}
+
+impl<W: AsyncWrite + Unpin + ?Sized> AsyncWriteBytesOrdered for W {}
@@ -0,0 +1,271 @@
+use super::Error;
+use serde::{Deserialize, Serialize};
+
+/// Represents the byte order (endianness) used for interpreting binary data.
+///
+/// Byte order determines how multi-byte data types (e.g., integers, floating-point numbers)
+/// are stored in memory. This enum provides variants for the most common byte orders:
+///
+/// - `Big`: Big-endian byte order, where the most significant byte is stored first.
+/// - `Little`: Little-endian byte order, where the least significant byte is stored first.
+/// - `Native`: The native byte order of the target platform, determined at compile time.
+/// - `BigSwap`/`LittleSwap`: The word-swapped ("mid-endian") layouts used by Modbus-style
+///   field devices, which transfer data as a sequence of 16-bit registers.
+///
+/// Writing the bytes of the 32-bit value `0xAABBCCDD` as `A B C D`, the four layouts are:
+///
+/// | Variant      | Layout | 16-bit `0xAABB` |
+/// |--------------|--------|-----------------|
+/// | `Big`        | ABCD   | AB              |
+/// | `Little`     | DCBA   | BA              |
+/// | `BigSwap`    | BADC   | BA              |
+/// | `LittleSwap` | CDAB   | AB              |
+///
+/// 64-bit values extend the same rule: `BigSwap` swaps the bytes of every 16-bit word of the
+/// big-endian layout, `LittleSwap` keeps each word big-endian but stores the words last first.
+///
+/// # Variants
+///
+/// - `Big`: Represents big-endian byte order.
+/// - `Little`: Represents little-endian byte order. This is the default variant.
+/// - `Native`: Represents the native byte order of the target platform. This variant is
+///   conditionally compiled based on the target platform's endianness.
+/// - `BigSwap`: Represents the BADC layout, serialized as `"bigswap"` or `"badc"`.
+/// - `LittleSwap`: Represents the CDAB layout, serialized as `"littleswap"` or `"cdab"`.
+///
+/// `Big` and `Little` also deserialize from `"abcd"` and `"dcba"`.
+///
+/// # Example
+///
+/// ```rust
+/// use rsutil::types::ByteOrder;
+///
+/// #[cfg(target_endian = "little")]
+/// assert!(ByteOrder::Native.is_little());
+/// #[cfg(target_endian = "little")]
+/// assert!(!ByteOrder::Native.is_big());
+/// #[cfg(target_endian = "big")]
+/// assert!(!ByteOrder::Native.is_little());
+/// #[cfg(target_endian = "big")]
+/// assert!(ByteOrder::Native.is_big());
+/// assert!(ByteOrder::Native.is_native());
+///
+/// let order = ByteOrder::Little;
+/// assert!(order.is_little());
+/// assert!(!order.is_big());
+/// #[cfg(target_endian = "little")]
+/// assert!(order.is_native());
+/// #[cfg(target_endian = "big")]
+/// assert!(!order.is_native());
+///
+/// let order = ByteOrder::Big;
+/// assert!(!order.is_little());
+/// assert!(order.is_big());
+/// #[cfg(target_endian = "little")]
+/// assert!(!order.is_native());
+/// #[cfg(target_endian = "big")]
+/// assert!(order.is_native());
+///
+/// // the word-swapped layouts are neither plain big nor plain little endian
+/// assert!(!ByteOrder::LittleSwap.is_little());
+/// assert!(!ByteOrder::BigSwap.is_big());
+/// assert!(ByteOrder::BigSwap.is_swapped());
+/// ```
+///
+/// # Reading and writing
+///
+/// Every fixed-size integer and float type has a `read_*`/`write_*` pair working on the
+/// head of a byte slice, and the generic [`ByteOrder::read`]/[`ByteOrder::write`] accept any
+/// [`Ordered`] type. A short buffer yields [`Error::BufferTooShort`] instead of panicking.
+///
+/// ```rust
+/// use rsutil::types::{ByteOrder, Error};
+///
+/// let buf = [0x12, 0x34, 0x56, 0x78, 0x9A];
+/// assert_eq!(ByteOrder::Big.read_u32(&buf), Ok(0x12345678));
+/// assert_eq!(ByteOrder::Little.read_u16(&buf), Ok(0x3412));
+/// assert_eq!(ByteOrder::Big.read::<i16>(&buf[2..]), Ok(0x5678));
+/// assert_eq!(
+///     ByteOrder::Big.read_u64(&buf),
+///     Err(Error::BufferTooShort { expected: 8, actual: 5 })
+/// );
+///
+/// let mut out = [0u8; 4];
+/// ByteOrder::Little.write_f32(&mut out, 1.5).unwrap();
+/// assert_eq!(out, 1.5f32.to_le_bytes());
+/// assert_eq!(ByteOrder::Little.read_f32(&out), Ok(1.5));
+///
+/// // a float from a gateway that ships CDAB
+/// let regs = [0x00, 0x00, 0x3F, 0xC0];
+/// assert_eq!(ByteOrder::LittleSwap.read_f32(&regs), Ok(1.5));
+/// assert_eq!(ByteOrder::BigSwap.read_u32(&[0xBB, 0xAA, 0xDD, 0xCC]), Ok(0xAABBCCDD));
+/// ```
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ByteOrder {
+    #[serde(alias = "abcd")]
+    Big,
+    #[default]
+    #[serde(alias = "dcba")]
+    Little,
+    #[cfg(target_endian = "little")]
+    Native,
+    #[cfg(target_endian = "big")]
+    Native,
+    #[serde(alias = "badc")]
+    BigSwap,
+    #[serde(alias = "cdab")]
+    LittleSwap,
+}
+
+impl ByteOrder {
+    /// Whether this is the plain little-endian layout, DCBA; `false` for the word-swapped
+    /// [`ByteOrder::LittleSwap`].
+    pub fn is_little(&self) -> bool {
+        match self {
+            Self::Big => false,
+            Self::Little => true,
+            Self::Native => cfg!(target_endian = "little"),
+            Self::BigSwap | Self::LittleSwap => false,
+        }
+    }
+
+    /// Whether this is the plain big-endian layout, ABCD; `false` for the word-swapped
+    /// [`ByteOrder::BigSwap`].
+    pub fn is_big(&self) -> bool {
+        match self {
+            Self::Big => true,
+            Self::Little => false,
+            Self::Native => cfg!(target_endian = "big"),
+            Self::BigSwap | Self::LittleSwap => false,
+        }
+    }
+
+    pub fn is_native(&self) -> bool {
+        match self {
+            Self::Big => cfg!(target_endian = "big"),
+            Self::Little => cfg!(target_endian = "little"),
+            Self::Native => true,
+            Self::BigSwap | Self::LittleSwap => false,
+        }
+    }
+
+    /// Whether this is one of the word-swapped layouts.
+    pub fn is_swapped(&self) -> bool {
+        matches!(self, Self::BigSwap | Self::LittleSwap)
+    }
+}
+
+/// Swaps the two bytes of every 16-bit word, turning ABCD into BADC and DCBA into CDAB.
+#[inline(always)]
+fn swap_words<const N: usize>(mut bytes: [u8; N]) -> [u8; N] {
+    bytes.chunks_exact_mut(2).for_each(|word| word.swap(0, 1));
+    bytes
+}
+
+/// Fixed-size numbers that a [`ByteOrder`] can convert to and from raw bytes.
+pub trait Ordered: Copy + Send + Sync + 'static {
+    /// The raw representation, always `[u8; size_of::<Self>()]`.
+    type Bytes: AsRef<[u8]> + AsMut<[u8]> + Default + Send;
+
+    fn from_ordered(order: ByteOrder, bytes: Self::Bytes) -> Self;
+    fn to_ordered(self, order: ByteOrder) -> Self::Bytes;
+}
+
+impl ByteOrder {
+    /// Reads a `T` from the head of `buf` in this byte order.
+    #[inline]
+    pub fn read<T: Ordered>(&self, buf: &[u8]) -> Result<T, Error> {
+        let mut bytes = T::Bytes::default();
+        let len = bytes.as_ref().len();
+        match buf.get(..len) {
+            Some(src) => {
+                bytes.as_mut().copy_from_slice(src);
+                Ok(T::from_ordered(*self, bytes))
+            }
+            None => Err(Error::BufferTooShort {
+                expected: len,
+                actual: buf.len(),
+            }),
+        }
+    }
+
+    /// Writes a `T` to the head of `buf` in this byte order.
+    #[inline]
+    pub fn write<T: Ordered>(&self, buf: &mut [u8], value: T) -> Result<(), Error> {
+        let bytes = value.to_ordered(*self);
+        let bytes = bytes.as_ref();
+        let actual = buf.len();
+        match buf.get_mut(..bytes.len()) {
+            Some(dst) => {
+                dst.copy_from_slice(bytes);
+                Ok(())
+            }
+            None => Err(Error::BufferTooShort {
+                expected: bytes.len(),
+                actual,
+            }),
+        }
+    }
+}
+
+macro_rules! impl_read_write {
+    ($($ty:ty => $read:ident, $write:ident;)*) => {
+        $(
+        impl Ordered for $ty {
+            type Bytes = [u8; std::mem::size_of::<$ty>()];
+
+            #[inline(always)]
+            fn from_ordered(order: ByteOrder, bytes: Self::Bytes) -> Self {
+                match order {
+                    ByteOrder::Big => <$ty>::from_be_bytes(bytes),
+                    ByteOrder::Little => <$ty>::from_le_bytes(bytes),
+                    ByteOrder::Native => <$ty>::from_ne_bytes(bytes),
+                    ByteOrder::BigSwap => <$ty>::from_be_bytes(swap_words(bytes)),
+                    ByteOrder::LittleSwap => <$ty>::from_le_bytes(swap_words(bytes)),
+                }
+            }
+
+            #[inline(always)]
+            fn to_ordered(self, order: ByteOrder) -> Self::Bytes {
+                match order {
+                    ByteOrder::Big => self.to_be_bytes(),
+                    ByteOrder::Little => self.to_le_bytes(),
+                    ByteOrder::Native => self.to_ne_bytes(),
+                    ByteOrder::BigSwap => swap_words(self.to_be_bytes()),
+                    ByteOrder::LittleSwap => swap_words(self.to_le_bytes()),
+                }
+            }
+        }
+        )*
+
+        impl ByteOrder {
+            $(
+            #[doc = concat!("Reads a `", stringify!($ty), "` from the head of `buf` in this byte order.")]
+            #[inline]
+            pub fn $read(&self, buf: &[u8]) -> Result<$ty, Error> {
+                self.read(buf)
+            }
+
+            #[doc = concat!("Writes a `", stringify!($ty), "` to the head of `buf` in this byte order.")]
+            #[inline]
+            pub fn $write(&self, buf: &mut [u8], value: $ty) -> Result<(), Error> {
+                self.write(buf, value)
+            }
+            )*
+        }
+    };
+}
+
+impl_read_write! {
+    u16 => read_u16, write_u16;
+    u32 => read_u32, write_u32;
+    u64 => read_u64, write_u64;
+    u128 => read_u128, write_u128;
+    i16 => read_i16, write_i16;
+    i32 => read_i32, write_i32;
+    i64 => read_i64, write_i64;
+    i128 => read_i128, write_i128;
+    f32 => read_f32, write_f32;
+    f64 => read_f64, write_f64;
+}
@@ -0,0 +1,292 @@
+use super::{ByteOrder, Error, Ordered};
+
+/// A bounds-checked read cursor over a byte slice.
+///
+/// The reader keeps a position and a default [`ByteOrder`]; each `read_*` method uses the
+/// default order while the matching `read_*_with` takes an override for that single call.
+///
+/// # Example
+///
+/// ```rust
+/// use rsutil::types::{ByteOrder, ByteReader};
+///
+/// // tag(u8) | len(u16 BE) | value(len bytes) | crc(u16 LE)
+/// let frame = [0x01, 0x00, 0x03, 0xAA, 0xBB, 0xCC, 0x34, 0x12];
+/// let mut reader = ByteReader::new(&frame, ByteOrder::Big);
+///
+/// assert_eq!(reader.read_u8(), Ok(0x01));
+/// let len = reader.read_u16().unwrap() as usize;
+/// let mut value = reader.sub_reader(len).unwrap();
+/// assert_eq!(value.peek(2), Ok(&[0xAA, 0xBB][..]));
+/// assert_eq!(value.read_bytes(3), Ok(&[0xAA, 0xBB, 0xCC][..]));
+/// assert!(value.is_empty());
+///
+/// assert_eq!(reader.read_u16_with(ByteOrder::Little), Ok(0x1234));
+/// assert_eq!(reader.remaining(), 0);
+/// assert!(reader.read_u8().is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct ByteReader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+    order: ByteOrder,
+}
+
+impl<'a> ByteReader<'a> {
+    pub fn new(buf: &'a [u8], order: ByteOrder) -> Self {
+        Self { buf, pos: 0, order }
+    }
+
+    #[inline]
+    pub fn order(&self) -> ByteOrder {
+        self.order
+    }
+    #[inline]
+    pub fn set_order(&mut self, order: ByteOrder) -> &mut Self {
+        self.order = order;
+        self
+    }
+    #[inline]
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+    /// Number of bytes left to read.
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        self.buf.len() - self.pos
+    }
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.remaining() == 0
+    }
+    /// The unread part of the underlying slice.
+    #[inline]
+    pub fn rest(&self) -> &'a [u8] {
+        &self.buf[self.pos..]
+    }
+
+    /// Moves the cursor to an absolute position; `pos` may be at most the slice length.
+    pub fn seek(&mut self, pos: usize) -> Result<(), Error> {
+        if pos > self.buf.len() {
+            return Err(Error::BufferTooShort {
+                expected: pos,
+                actual: self.buf.len(),
+            });
+        }
+        self.pos = pos;
+        Ok(())
+    }
+
+    /// Returns the next `len` bytes without advancing.
+    pub fn peek(&self, len: usize) -> Result<&'a [u8], Error> {
+        self.rest().get(..len).ok_or(Error::BufferTooShort {
+            expected: len,
+            actual: self.remaining(),
+        })
+    }
+
+    pub fn peek_u8(&self) -> Result<u8, Error> {
+        self.peek(1).map(|v| v[0])
+    }
+
+    pub fn skip(&mut self, len: usize) -> Result<(), Error> {
+        self.read_bytes(len).map(|_| ())
+    }
+
+    /// Advances to the next multiple of `alignment`, counted from the start of this reader.
+    /// An alignment of 0 or 1 is a no-op.
+    pub fn align_to(&mut self, alignment: usize) -> Result<(), Error> {
+        if alignment <= 1 {
+            return Ok(());
+        }
+        match self.pos % alignment {
+            0 => Ok(()),
+            r => self.skip(alignment - r),
+        }
+    }
+
+    pub fn read_bytes(&mut self, len: usize) -> Result<&'a [u8], Error> {
+        let bytes = self.peek(len)?;
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    /// Consumes the next `len` bytes and returns a new reader over them, sharing the byte order.
+    pub fn sub_reader(&mut self, len: usize) -> Result<ByteReader<'a>, Error> {
+        self.read_bytes(len)
+            .map(|buf| ByteReader::new(buf, self.order))
+    }
+
+    /// Reads a `T` in the reader's byte order.
+    #[inline]
+    pub fn read<T: Ordered>(&mut self) -> Result<T, Error> {
+        self.read_with(self.order)
+    }
+
+    /// Reads a `T` in the given byte order.
+    pub fn read_with<T: Ordered>(&mut self, order: ByteOrder) -> Result<T, Error> {
+        let value = order.read::<T>(self.rest())?;
+        self.pos += std::mem::size_of::<T>();
+        Ok(value)
+    }
+
+    pub fn read_u8(&mut self) -> Result<u8, Error> {
+        self.read_bytes(1).map(|v| v[0])
+    }
+
+    pub fn read_i8(&mut self) -> Result<i8, Error> {
+        self.read_u8().map(|v| v as i8)
+    }
+}
+
+/// A growable write cursor over a `Vec<u8>`.
+///
+/// Like [`ByteReader`], every `write_*` method uses the writer's default [`ByteOrder`] and the
+/// matching `write_*_with` takes an override for that single call.
+///
+/// # Example
+///
+/// ```rust
+/// use rsutil::types::{ByteOrder, ByteWriter};
+///
+/// let mut writer = ByteWriter::new(ByteOrder::Big);
+/// writer.write_u8(0x01);
+/// writer.write_u16(0x0203);
+/// writer.align_to(4);
+/// writer.write_u32_with(ByteOrder::Little, 0x07060504);
+///
+/// assert_eq!(writer.position(), 8);
+/// assert_eq!(writer.into_inner(), vec![0x01, 0x02, 0x03, 0x00, 0x04, 0x05, 0x06, 0x07]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ByteWriter {
+    buf: Vec<u8>,
+    order: ByteOrder,
+}
+
+impl ByteWriter {
+    pub fn new(order: ByteOrder) -> Self {
+        Self::from_vec(Vec::new(), order)
+    }
+
+    pub fn with_capacity(capacity: usize, order: ByteOrder) -> Self {
+        Self::from_vec(Vec::with_capacity(capacity), order)
+    }
+
+    /// Wraps an existing buffer; new data is appended after its current content.
+    pub fn from_vec(buf: Vec<u8>, order: ByteOrder) -> Self {
+        Self { buf, order }
+    }
+
+    #[inline]
+    pub fn order(&self) -> ByteOrder {
+        self.order
+    }
+    #[inline]
+    pub fn set_order(&mut self, order: ByteOrder) -> &mut Self {
+        self.order = order;
+        self
+    }
+    #[inline]
+    pub fn position(&self) -> usize {
+        self.buf.len()
+    }
+    #[inline]
+    pub fn as_slice(&self) -> &[u8] {
+        &self.buf
+    }
+    #[inline]
+    pub fn into_inner(self) -> Vec<u8> {
+        self.buf
+    }
+
+    /// Appends `len` zero bytes.
+    pub fn pad(&mut self, len: usize) -> &mut Self {
+        self.buf.resize(self.buf.len() + len, 0);
+        self
+    }
+
+    /// Pads with zero bytes up to the next multiple of `alignment`.
+    /// An alignment of 0 or 1 is a no-op.
+    pub fn align_to(&mut self, alignment: usize) -> &mut Self {
+        match alignment {
+            0 | 1 => self,
+            _ => self.pad((alignment - self.buf.len() % alignment) % alignment),
+        }
+    }
+
+    pub fn write_bytes(&mut self, bytes: &[u8]) -> &mut Self {
+        self.buf.extend_from_slice(bytes);
+        self
+    }
+
+    /// Appends a `T` in the writer's byte order.
+    #[inline]
+    pub fn write<T: Ordered>(&mut self, value: T) -> &mut Self {
+        self.write_with(self.order, value)
+    }
+
+    /// Appends a `T` in the given byte order.
+    pub fn write_with<T: Ordered>(&mut self, order: ByteOrder, value: T) -> &mut Self {
+        self.buf.extend_from_slice(value.to_ordered(order).as_ref());
+        self
+    }
+
+    pub fn write_u8(&mut self, value: u8) -> &mut Self {
+        self.buf.push(value);
+        self
+    }
+
+    pub fn write_i8(&mut self, value: i8) -> &mut Self {
+        self.write_u8(value as u8)
+    }
+}
+
+macro_rules! impl_cursor {
+    ($($ty:ty => $read:ident, $read_with:ident, $write:ident, $write_with:ident;)*) => {
+        impl ByteReader<'_> {
+            $(
+            #[doc = concat!("Reads a `", stringify!($ty), "` in the reader's byte order.")]
+            #[inline]
+            pub fn $read(&mut self) -> Result<$ty, Error> {
+                self.$read_with(self.order)
+            }
+
+            #[doc = concat!("Reads a `", stringify!($ty), "` in the given byte order.")]
+            #[inline]
+            pub fn $read_with(&mut self, order: ByteOrder) -> Result<$ty, Error> {
+                self.read_with(order)
+            }
+            )*
+        }
+
+        impl ByteWriter {
+            $(
+            #[doc = concat!("Appends a `", stringify!($ty), "` in the writer's byte order.")]
+            #[inline]
+            pub fn $write(&mut self, value: $ty) -> &mut Self {
+                self.$write_with(self.order, value)
+            }
+
+            #[doc = concat!("Appends a `", stringify!($ty), "` in the given byte order.")]
+            #[inline]
+            pub fn $write_with(&mut self, order: ByteOrder, value: $ty) -> &mut Self {
+                self.write_with(order, value)
+            }
+            )*
+        }
+    };
+}
+
+impl_cursor! {
+    u16 => read_u16, read_u16_with, write_u16, write_u16_with;
+    u32 => read_u32, read_u32_with, write_u32, write_u32_with;
+    u64 => read_u64, read_u64_with, write_u64, write_u64_with;
+    u128 => read_u128, read_u128_with, write_u128, write_u128_with;
+    i16 => read_i16, read_i16_with, write_i16, write_i16_with;
+    i32 => read_i32, read_i32_with, write_i32, write_i32_with;
+    i64 => read_i64, read_i64_with, write_i64, write_i64_with;
+    i128 => read_i128, read_i128_with, write_i128, write_i128_with;
+    f32 => read_f32, read_f32_with, write_f32, write_f32_with;
+    f64 => read_f64, read_f64_with, write_f64, write_f64_with;
+}
@@ -0,0 +1,259 @@
+use super::codec::{len_utf8, run_fault, Decoder, Encoder, Fault};
+use super::{Encoding, Wtf8, Wtf8Buf};
+
+mod tables;
+pub(crate) use tables::table;
+
+const UNDEFINED: u16 = 0xFFFE;
+
+/// Whether `encoding` maps every character to a single byte and back, without state.
+pub(crate) fn single_byte(encoding: Encoding) -> bool {
+    matches!(encoding, Encoding::Ascii | Encoding::Latin1) || table(encoding).is_some()
+}
+
+/// A single-byte codec driven by a 256-entry decoding table, like Python's `charmap` codecs.
+pub(crate) struct Charmap {
+    decode: [u16; 256],
+    /// `(char, byte)` pairs sorted by char, the first `len` entries are used.
+    encode: [(u16, u8); 256],
+    len: usize,
+}
+
+impl Charmap {
+    /// Builds the reverse table at compile time.
+    pub const fn new(decode: [u16; 256]) -> Self {
+        let mut encode = [(0u16, 0u8); 256];
+        let mut len = 0;
+        let mut byte = 0;
+        while byte < 256 {
+            let ch = decode[byte];
+            if ch != UNDEFINED {
+                let mut i = 0;
+                while i < len && encode[i].0 < ch {
+                    i += 1;
+                }
+                if i < len && encode[i].0 == ch {
+                    // a char mapped twice keeps the highest byte, as in CPython
+                    encode[i].1 = byte as u8;
+                } else {
+                    let mut j = len;
+                    while j > i {
+                        encode[j] = encode[j - 1];
+                        j -= 1;
+                    }
+                    encode[i] = (ch, byte as u8);
+                    len += 1;
+                }
+            }
+            byte += 1;
+        }
+        Self {
+            decode,
+            encode,
+            len,
+        }
+    }
+
+    #[inline]
+    fn lookup(&self, c: u32) -> Option<u8> {
+        let c = u16::try_from(c).ok()?;
+        self.encode[..self.len]
+            .binary_search_by_key(&c, |&(ch, _)| ch)
+            .ok()
+            .map(|i| self.encode[i].1)
+    }
+}
+
+impl Decoder for &'static Charmap {
+    fn decode(&mut self, input: &[u8], output: &mut Wtf8Buf, _last: bool) -> Result<usize, Fault> {
+        for (pos, &byte) in input.iter().enumerate() {
+            match self.decode[byte as usize] {
+                UNDEFINED => return Err(Fault::new(pos, pos + 1, "character maps to <undefined>")),
+                ch => output.push_code_point(ch as u32),
+            }
+        }
+        Ok(input.len())
+    }
+}
+
+impl Encoder for &'static Charmap {
+    fn encode(&mut self, input: &Wtf8, output: &mut Vec<u8>, _last: bool) -> Result<usize, Fault> {
+        output.reserve(input.len());
+        let mut points = input.code_points();
+        while let Some(c) = points.next() {
+            match self.lookup(c) {
+                Some(byte) => output.push(byte),
+                None => {
+                    let start = points.offset() - len_utf8(c);
+                    return Err(run_fault(
+                        input,
+                        start,
+                        "character maps to <undefined>",
+                        |c| self.lookup(c).is_none(),
+                    ));
+                }
+            }
+        }
+        Ok(input.len())
+    }
+}
+
+/// `ascii` and `latin-1`, which map the code points below `limit` to the byte of that value.
+pub(crate) struct Ordinal {
+    limit: u32,
+    reason: &'static str,
+}
+
+impl Ordinal {
+    pub const ASCII: Self = Self {
+        limit: 0x80,
+        reason: "ordinal not in range(128)",
+    };
+    pub const LATIN1: Self = Self {
+        limit: 0x100,
+        reason: "ordinal not in range(256)",
+    };
+}
+
+impl Decoder for Ordinal {
+    fn decode(&mut self, input: &[u8], output: &mut Wtf8Buf, _last: bool) -> Result<usize, Fault> {
+        for (pos, &byte) in input.iter().enumerate() {
+            if byte as u32 >= self.limit {
+                return Err(Fault::new(pos, pos + 1, self.reason));
+            }
+            output.push(byte as char);
+        }
+        Ok(input.len())
+    }
+}
+
+impl Encoder for Ordinal {
+    fn encode(&mut self, input: &Wtf8, output: &mut Vec<u8>, _last: bool) -> Result<usize, Fault> {
+        output.reserve(input.len());
+        let mut points = input.code_points();
+        while let Some(c) = points.next() {
+            if c >= self.limit {
+                let start = points.offset() - len_utf8(c);
+                return Err(run_fault(input, start, self.reason, |c| c >= self.limit));
+            }
+            output.push(c as u8);
+        }
+        Ok(input.len())
+    }
+
+    fn partial_escape(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::codec::digest;
+    use super::super::CodecError;
+    use super::*;
+
+    #[test]
+    fn test_tables() {
+        // from `tools/gen_tables.py --digests`
+        let digests = [
+            (Encoding::Cp1250, 0x4c718f25b518bdbc),
+            (Encoding::Cp1251, 0x5ea45a3a09280e10),
+            (Encoding::Cp1252, 0x4cb7becd9439144d),
+            (Encoding::Cp1253, 0x785143bf84b4f31d),
+            (Encoding::Cp1254, 0xe97808c0acc20df5),
+            (Encoding::Cp1255, 0xb67e65bf83107498),
+            (Encoding::Cp1256, 0x8e8d0df6014d0d65),
+            (Encoding::Cp1257, 0x93115c5c798e57d0),
+            (Encoding::Cp1258, 0x3285706e939a6062),
+            (Encoding::Iso8859_1, 0x3a2c9f1c59616b65),
+            (Encoding::Iso8859_2, 0x2d4d0bb143f8d77c),
+            (Encoding::Iso8859_3, 0xb1b8d5611fff4b68),
+            (Encoding::Iso8859_4, 0xad61bbca30b431b9),
+            (Encoding::Iso8859_5, 0x940d814a81f3a0ad),
+            (Encoding::Iso8859_6, 0x4e92fc07e3de7f57),
+            (Encoding::Iso8859_7, 0x9a655906117a8a5c),
+            (Encoding::Iso8859_8, 0xb62ac2aff3a3105c),
+            (Encoding::Iso8859_9, 0xd1606f3737c3b740),
+            (Encoding::Iso8859_10, 0xc7d7487cbf44459b),
+            (Encoding::Iso8859_11, 0xba1da8a902fc96b5),
+            (Encoding::Iso8859_13, 0x6df4ff0b3d780e5f),
+            (Encoding::Iso8859_14, 0x6b0f9aeb2db97c05),
+            (Encoding::Iso8859_15, 0x673c53e27bf8ee4e),
+            (Encoding::Iso8859_16, 0xd8bd5c57d4a9aaf4),
+            (Encoding::Cp037, 0x65c90d4f8bd27b23),
+            (Encoding::Cp273, 0x579e88a8192f6bbc),
+            (Encoding::Cp424, 0x8994b3d78654b2fa),
+            (Encoding::Cp500, 0x805469c2d10adf69),
+            (Encoding::Cp1026, 0xb6b85803f10b0bc0),
+            (Encoding::Cp1140, 0x8c519c771017ce2d),
+            (Encoding::Cp437, 0x66d7481de795d337),
+            (Encoding::Cp775, 0x2525ccc7e36e3514),
+            (Encoding::Cp850, 0x99fe68580d58ed2b),
+            (Encoding::Cp852, 0x18f1d1193b0c2552),
+            (Encoding::Cp855, 0x7b60cb7f5e439e33),
+            (Encoding::Cp857, 0x8e2ea5fa2746df03),
+            (Encoding::Cp858, 0x3684aca7ba44a454),
+            (Encoding::Cp860, 0x5ea2d13935fd639f),
+            (Encoding::Cp861, 0x20765bdf70c9c206),
+            (Encoding::Cp862, 0x50f975c5f90c21b9),
+            (Encoding::Cp863, 0xfbf0d19b0f750565),
+            (Encoding::Cp864, 0x14e0c0fe9e5ed677),
+            (Encoding::Cp865, 0x0b9cfb6dee53b2bf),
+            (Encoding::Cp866, 0x3377418c584351c6),
+            (Encoding::Cp869, 0xa4d5667aabfad0c2),
+            (Encoding::Cp1125, 0x96d7a1d9d015ff9a),
+            (Encoding::MacCyrillic, 0xab049f730ac08425),
+            (Encoding::MacGreek, 0x8e498e5eead9736b),
+            (Encoding::MacIceland, 0x1150df8c772dba84),
+            (Encoding::MacLatin2, 0x0658ad59af39e2bf),
+            (Encoding::MacRoman, 0xb50c62df85be29ef),
+            (Encoding::MacTurkish, 0x9976ce1923acdb0f),
+            (Encoding::HpRoman8, 0x68a7e3531669e5cf),
+            (Encoding::Koi8R, 0xef2dbe2118dd94cd),
+            (Encoding::Kz1048, 0x04ed9bf56f92d2d8),
+            (Encoding::Ptcp154, 0x20f7df2f7b4d697b),
+            (Encoding::Tis620, 0x26d4f3b2dcbb77e2),
+            (Encoding::Ascii, 0x8ec40cf491f74d25),
+            (Encoding::Latin1, 0x3a2c9f1c59616b65),
+        ];
+        for (encoding, expected) in digests {
+            let codes = (0..=0xFF).map(|byte| vec![byte]);
+            assert_eq!(digest(encoding, codes), expected, "{encoding}");
+            let bytes = (0..=0xFF)
+                .filter(|&byte| encoding.decode(&[byte]).is_ok())
+                .collect::<Vec<u8>>();
+            let text = encoding.decode(&bytes).unwrap();
+            assert_eq!(encoding.encode(&text), Ok(bytes), "{encoding}");
+        }
+    }
+
+    #[test]
+    fn test_vectors() {
+        assert_eq!(
+            Encoding::Cp1252.decode(b"5\x80 \x9c"),
+            Ok("5\u{20ac} \u{153}".to_string())
+        );
+        assert_eq!(
+            Encoding::Iso8859_15.encode("\u{20ac}\u{160}"),
+            Ok(b"\xa4\xa6".to_vec())
+        );
+        assert_eq!(
+            Encoding::Koi8R.encode("\u{41c}\u{438}\u{440}"),
+            Ok(b"\xed\xc9\xd2".to_vec())
+        );
+        assert_eq!(
+            Encoding::Cp037.decode(b"\xc8\x85\x93\x93\x96"),
+            Ok("Hello".to_string())
+        );
+        let err = Encoding::Cp1253.decode(b"a\xaa").unwrap_err();
+        assert!(matches!(
+            err,
+            CodecError::Decode {
+                start: 1,
+                end: 2,
+                reason: "character maps to <undefined>",
+                ..
+            }
+        ));
+    }
+}
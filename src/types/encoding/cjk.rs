@@ -0,0 +1,255 @@
+//! The CJK multibyte codecs, modeled after CPython's `cjkcodecs`.
+
+use super::codec::{len_utf8, Fault};
+use super::{Wtf8, Wtf8Buf};
+use std::cmp::Reverse;
+use std::sync::OnceLock;
+
+mod chinese;
+pub(crate) use chinese::*;
+mod iso2022;
+pub(crate) use iso2022::*;
+mod japanese;
+pub(crate) use japanese::*;
+mod korean;
+pub(crate) use korean::*;
+mod tables;
+
+pub(crate) const ILLEGAL: &str = "illegal multibyte sequence";
+pub(crate) const INCOMPLETE: &str = "incomplete multibyte sequence";
+
+/// Marks an unmapped code in a [`Table`].
+const HOLE: char = '\0';
+
+/// A dense table of a character set, `cols` codes per row starting at code `first`, where a
+/// code is `row << 8 | col`. The characters are spelled as a string, `\0` for unmapped codes,
+/// and the lookup tables in both directions are built on first use.
+pub(crate) struct Table {
+    first: u16,
+    cols: u8,
+    chars: &'static str,
+    decoded: OnceLock<Box<[char]>>,
+    encoded: OnceLock<Box<[(char, u16)]>>,
+}
+
+impl Table {
+    pub const fn new(first: u16, cols: u8, chars: &'static str) -> Self {
+        Self {
+            first,
+            cols,
+            chars,
+            decoded: OnceLock::new(),
+            encoded: OnceLock::new(),
+        }
+    }
+
+    fn decoded(&self) -> &[char] {
+        self.decoded.get_or_init(|| self.chars.chars().collect())
+    }
+
+    pub fn decode(&self, row: u8, col: u8) -> Option<char> {
+        let row = row.checked_sub((self.first >> 8) as u8)?;
+        let col = col
+            .checked_sub(self.first as u8)
+            .filter(|&c| c < self.cols)?;
+        self.decoded()
+            .get(row as usize * self.cols as usize + col as usize)
+            .copied()
+            .filter(|&ch| ch != HOLE)
+    }
+
+    /// The code of `c`; a character mapped twice gets the higher code, as in CPython.
+    pub fn encode(&self, c: u32) -> Option<u16> {
+        let encoded = self.encoded.get_or_init(|| {
+            let cols = self.cols as u16;
+            let mut encoded: Vec<_> = (0u16..)
+                .zip(self.decoded())
+                .filter(|(_, &ch)| ch != HOLE)
+                .map(|(i, &ch)| (ch, self.first + ((i / cols) << 8) + i % cols))
+                .collect();
+            encoded.sort_by_key(|&(ch, code)| (ch, Reverse(code)));
+            encoded.dedup_by_key(|&mut (ch, _)| ch);
+            encoded.into_boxed_slice()
+        });
+        let c = char::from_u32(c)?;
+        encoded
+            .binary_search_by_key(&c, |&(ch, _)| ch)
+            .ok()
+            .map(|i| encoded[i].1)
+    }
+}
+
+/// A sparse table of a character set, `(code, char)` pairs sorted by code; the reverse table
+/// is built on first use.
+pub(crate) struct Sparse {
+    codes: &'static [(u16, char)],
+    encoded: OnceLock<Box<[(char, u16)]>>,
+}
+
+impl Sparse {
+    pub const fn new(codes: &'static [(u16, char)]) -> Self {
+        Self {
+            codes,
+            encoded: OnceLock::new(),
+        }
+    }
+
+    pub fn decode(&self, code: u16) -> Option<char> {
+        search(self.codes, code)
+    }
+
+    /// The code of `c`; a character mapped twice gets the lower code, like the NEC codes of
+    /// cp932 over the IBM ones.
+    pub fn encode(&self, c: u32) -> Option<u16> {
+        let encoded = self.encoded.get_or_init(|| {
+            let mut encoded: Vec<_> = self.codes.iter().map(|&(code, ch)| (ch, code)).collect();
+            encoded.sort();
+            encoded.dedup_by_key(|&mut (ch, _)| ch);
+            encoded.into_boxed_slice()
+        });
+        search(encoded, char::from_u32(c)?)
+    }
+}
+
+/// Looks `c` followed by `next` up in a table of `(code, base, mark)` pairs: the code and 2
+/// when they make a pair, `Some((0, 0))` to wait for the character after a base ending the
+/// input, or `None` when `c` is encoded alone.
+pub(crate) fn pair(
+    pairs: &[(u16, char, char)],
+    c: u32,
+    next: Option<u32>,
+    last: bool,
+) -> Option<(u16, usize)> {
+    let base = char::from_u32(c)?;
+    if !pairs.iter().any(|&(_, b, _)| b == base) {
+        return None;
+    }
+    match next {
+        None if !last => Some((0, 0)),
+        None => None,
+        Some(next) => pairs
+            .iter()
+            .find(|&&(_, b, m)| b == base && m as u32 == next)
+            .map(|&(code, ..)| (code, 2)),
+    }
+}
+
+/// Looks `key` up in a table of pairs sorted by key.
+#[inline]
+fn search<K: Ord + Copy, V: Copy>(table: &[(K, V)], key: K) -> Option<V> {
+    table
+        .binary_search_by_key(&key, |&(k, _)| k)
+        .ok()
+        .map(|i| table[i].1)
+}
+
+/// The outcome of decoding the sequence at the head of the input.
+pub(crate) enum Step {
+    /// The sequence had this many bytes and was decoded.
+    Done(usize),
+    /// The sequence is invalid; Python skips this many bytes.
+    Illegal(usize),
+    /// The input ends inside the sequence.
+    Incomplete,
+}
+
+/// Runs `step` over `input`, the decoding loop of every multibyte codec.
+pub(crate) fn decode(
+    input: &[u8],
+    output: &mut Wtf8Buf,
+    last: bool,
+    mut step: impl FnMut(&[u8], &mut Wtf8Buf) -> Step,
+) -> Result<usize, Fault> {
+    let mut pos = 0;
+    while pos < input.len() {
+        match step(&input[pos..], output) {
+            Step::Done(len) => pos += len,
+            Step::Illegal(len) => {
+                return Err(Fault::new(pos, (pos + len).min(input.len()), ILLEGAL))
+            }
+            Step::Incomplete if last => return Err(Fault::new(pos, input.len(), INCOMPLETE)),
+            Step::Incomplete => break,
+        }
+    }
+    Ok(pos)
+}
+
+/// Runs `step` over the code points of `input`, the encoding loop of every multibyte codec.
+///
+/// `step` gets the code point, the one after it if any, and whether the input is final; it
+/// returns how many code points it encoded, `Some(0)` to wait for more input, or `None` if
+/// the code point can't be encoded, dropping what it wrote.
+pub(crate) fn encode(
+    input: &Wtf8,
+    output: &mut Vec<u8>,
+    last: bool,
+    mut step: impl FnMut(u32, Option<u32>, bool, &mut Vec<u8>) -> Option<usize>,
+) -> Result<usize, Fault> {
+    let mut points = input.code_points();
+    while let Some(c) = points.next() {
+        let start = points.offset() - len_utf8(c);
+        let written = output.len();
+        match step(c, points.clone().next(), last, output) {
+            Some(0) => return Ok(start),
+            Some(1) => {}
+            Some(_) => {
+                let _ = points.next();
+            }
+            None => {
+                output.truncate(written);
+                return Err(Fault::new(start, points.offset(), ILLEGAL));
+            }
+        }
+    }
+    Ok(input.len())
+}
+
+/// Decodes the two-byte sequence at the head of `input`, which is at least a lead byte long.
+pub(crate) fn decode_pair(
+    input: &[u8],
+    output: &mut Wtf8Buf,
+    lookup: impl Fn(u8, u8) -> Option<char>,
+) -> Step {
+    let Some(&c2) = input.get(1) else {
+        return Step::Incomplete;
+    };
+    match lookup(input[0], c2) {
+        Some(ch) => {
+            output.push(ch);
+            Step::Done(2)
+        }
+        None => Step::Illegal(1),
+    }
+}
+
+/// Decodes ASCII, or a two-byte code with `lookup`.
+pub(crate) fn decode_dbcs(
+    input: &[u8],
+    output: &mut Wtf8Buf,
+    last: bool,
+    lookup: impl Fn(u8, u8) -> Option<char>,
+) -> Result<usize, Fault> {
+    decode(input, output, last, |input, output| match input[0] {
+        c @ 0..=0x7F => {
+            output.push(c as char);
+            Step::Done(1)
+        }
+        _ => decode_pair(input, output, &lookup),
+    })
+}
+
+/// Encodes ASCII, or a two-byte code found by `lookup`.
+pub(crate) fn encode_dbcs(
+    input: &Wtf8,
+    output: &mut Vec<u8>,
+    last: bool,
+    lookup: impl Fn(u32) -> Option<u16>,
+) -> Result<usize, Fault> {
+    encode(input, output, last, |c, _, _, output| {
+        match c {
+            0..=0x7F => output.push(c as u8),
+            _ => output.extend_from_slice(&lookup(c)?.to_be_bytes()),
+        }
+        Some(1)
+    })
+}
@@ -0,0 +1,347 @@
+use super::tables::{
+    BIG5, BIG5HKSCS, BIG5HKSCS_PAIRS, CP950EXT, GB18030EXT, GB18030_RANGES, GB2312, GBK,
+};
+use super::{decode, decode_dbcs, decode_pair, encode, encode_dbcs, pair, Step};
+use crate::types::encoding::codec::{Decoder, Encoder, Fault};
+use crate::types::encoding::{Wtf8, Wtf8Buf};
+
+/// Number of four-byte GB 18030 codes mapped to the BMP by [`GB18030_RANGES`].
+const BMP_CODES: u32 = 39420;
+
+pub(crate) struct Gb2312;
+
+impl Decoder for Gb2312 {
+    fn decode(&mut self, input: &[u8], output: &mut Wtf8Buf, last: bool) -> Result<usize, Fault> {
+        decode_dbcs(input, output, last, |c, c2| {
+            GB2312.decode(c ^ 0x80, c2 ^ 0x80)
+        })
+    }
+}
+
+impl Encoder for Gb2312 {
+    fn encode(&mut self, input: &Wtf8, output: &mut Vec<u8>, last: bool) -> Result<usize, Fault> {
+        encode_dbcs(input, output, last, |c| {
+            GB2312.encode(c).map(|code| code | 0x8080)
+        })
+    }
+}
+
+pub(crate) struct Gbk;
+
+impl Decoder for Gbk {
+    fn decode(&mut self, input: &[u8], output: &mut Wtf8Buf, last: bool) -> Result<usize, Fault> {
+        decode_dbcs(input, output, last, |c, c2| GBK.decode(c, c2))
+    }
+}
+
+impl Encoder for Gbk {
+    fn encode(&mut self, input: &Wtf8, output: &mut Vec<u8>, last: bool) -> Result<usize, Fault> {
+        encode_dbcs(input, output, last, |c| GBK.encode(c))
+    }
+}
+
+/// GB 18030, which extends GBK with four-byte codes to cover all of Unicode: the BMP code
+/// points GBK lacks go to `0x81308130..=0x8439FE39` by [`GB18030_RANGES`], and the other
+/// planes to `0x90308130..=0xE3329A35` in order.
+pub(crate) struct Gb18030;
+
+impl Gb18030 {
+    /// Writes the four-byte code `index` places after `lead 30 81 30`.
+    fn push_four(lead: u8, index: u32, output: &mut Vec<u8>) {
+        output.extend_from_slice(&[
+            lead + (index / 12600) as u8,
+            0x30 + (index / 1260 % 10) as u8,
+            0x81 + (index / 10 % 126) as u8,
+            0x30 + (index % 10) as u8,
+        ]);
+    }
+
+    fn decode_four(input: &[u8]) -> Option<char> {
+        let &[c1, c2, c3, c4, ..] = input else {
+            return None;
+        };
+        if !(0x81..=0xFE).contains(&c1) || !(0x81..=0xFE).contains(&c3) || !c4.is_ascii_digit() {
+            return None;
+        }
+        let index = (((c1 - 0x81) as u32 * 10 + (c2 - 0x30) as u32) * 126 + (c3 - 0x81) as u32)
+            * 10
+            + (c4 - 0x30) as u32;
+        match c1 {
+            0x81..=0x84 if index < BMP_CODES => {
+                let i = GB18030_RANGES.partition_point(|&(_, _, i)| i as u32 <= index) - 1;
+                let (first, _, start) = GB18030_RANGES[i];
+                char::from_u32(first as u32 + index - start as u32)
+            }
+            0x90.. => char::from_u32(0x10000 + index - 15 * 12600),
+            _ => None,
+        }
+    }
+}
+
+impl Decoder for Gb18030 {
+    fn decode(&mut self, input: &[u8], output: &mut Wtf8Buf, last: bool) -> Result<usize, Fault> {
+        decode(input, output, last, |input, output| match *input {
+            [c @ 0..=0x7F, ..] => {
+                output.push(c as char);
+                Step::Done(1)
+            }
+            [_, b'0'..=b'9', ..] if input.len() < 4 => Step::Incomplete,
+            [_, b'0'..=b'9', ..] => match Self::decode_four(input) {
+                Some(ch) => {
+                    output.push(ch);
+                    Step::Done(4)
+                }
+                None => Step::Illegal(1),
+            },
+            _ => decode_pair(input, output, |c, c2| {
+                GBK.decode(c, c2)
+                    .or_else(|| GB18030EXT.decode(u16::from_be_bytes([c, c2])))
+            }),
+        })
+    }
+}
+
+impl Encoder for Gb18030 {
+    fn encode(&mut self, input: &Wtf8, output: &mut Vec<u8>, last: bool) -> Result<usize, Fault> {
+        encode(input, output, last, |c, _, _, output| {
+            match c {
+                0..=0x7F => output.push(c as u8),
+                0x10000.. => Self::push_four(0x90, c - 0x10000, output),
+                _ => match GBK.encode(c).or_else(|| GB18030EXT.encode(c)) {
+                    Some(code) => output.extend_from_slice(&code.to_be_bytes()),
+                    None => {
+                        let i = GB18030_RANGES.partition_point(|&(first, ..)| first as u32 <= c);
+                        let (first, last, start) = *GB18030_RANGES.get(i.checked_sub(1)?)?;
+                        if c > last as u32 {
+                            return None;
+                        }
+                        Self::push_four(0x81, start as u32 + c - first as u32, output);
+                    }
+                },
+            }
+            Some(1)
+        })
+    }
+}
+
+/// HZ, 7-bit GB 2312 text switching to GB with `~{` and back to ASCII with `~}`; `~~` is a
+/// tilde and `~` before a newline continues the line.
+#[derive(Default)]
+pub(crate) struct Hz {
+    gb: bool,
+}
+
+impl Decoder for Hz {
+    fn decode(&mut self, input: &[u8], output: &mut Wtf8Buf, last: bool) -> Result<usize, Fault> {
+        decode(input, output, last, |input, output| match input[0] {
+            b'~' => {
+                let Some(&c2) = input.get(1) else {
+                    return Step::Incomplete;
+                };
+                match (c2, self.gb) {
+                    (b'~', false) => output.push('~'),
+                    (b'{', false) => self.gb = true,
+                    (b'\n', false) => {}
+                    (b'}', true) => self.gb = false,
+                    _ => return Step::Illegal(1),
+                }
+                Step::Done(2)
+            }
+            0x80.. => Step::Illegal(1),
+            c if !self.gb => {
+                output.push(c as char);
+                Step::Done(1)
+            }
+            _ => decode_pair(input, output, |c, c2| GB2312.decode(c, c2)),
+        })
+    }
+}
+
+impl Encoder for Hz {
+    fn encode(&mut self, input: &Wtf8, output: &mut Vec<u8>, last: bool) -> Result<usize, Fault> {
+        let len = encode(input, output, last, |c, _, _, output| {
+            if c < 0x80 {
+                if self.gb {
+                    output.extend_from_slice(b"~}");
+                    self.gb = false;
+                }
+                output.push(c as u8);
+                if c == b'~' as u32 {
+                    output.push(b'~');
+                }
+                return Some(1);
+            }
+            let code = GB2312.encode(c)?;
+            if !self.gb {
+                output.extend_from_slice(b"~{");
+                self.gb = true;
+            }
+            output.extend_from_slice(&code.to_be_bytes());
+            Some(1)
+        })?;
+        if last && self.gb {
+            output.extend_from_slice(b"~}");
+            self.gb = false;
+        }
+        Ok(len)
+    }
+}
+
+pub(crate) struct Big5;
+
+impl Decoder for Big5 {
+    fn decode(&mut self, input: &[u8], output: &mut Wtf8Buf, last: bool) -> Result<usize, Fault> {
+        decode_dbcs(input, output, last, |c, c2| BIG5.decode(c, c2))
+    }
+}
+
+impl Encoder for Big5 {
+    fn encode(&mut self, input: &Wtf8, output: &mut Vec<u8>, last: bool) -> Result<usize, Fault> {
+        encode_dbcs(input, output, last, |c| BIG5.encode(c))
+    }
+}
+
+/// Microsoft's Big5. A character keeps its Big5 code unless the extensions remap that code;
+/// the characters losing their code that way still encode to it, one way.
+pub(crate) struct Cp950;
+
+impl Decoder for Cp950 {
+    fn decode(&mut self, input: &[u8], output: &mut Wtf8Buf, last: bool) -> Result<usize, Fault> {
+        decode_dbcs(input, output, last, |c, c2| {
+            CP950EXT
+                .decode(u16::from_be_bytes([c, c2]))
+                .or_else(|| BIG5.decode(c, c2))
+        })
+    }
+}
+
+impl Encoder for Cp950 {
+    fn encode(&mut self, input: &Wtf8, output: &mut Vec<u8>, last: bool) -> Result<usize, Fault> {
+        encode_dbcs(input, output, last, |c| match c {
+            // the other Big5 codes of these are remapped
+            0xFF0F => Some(0xA1FE),
+            0xFF3C => Some(0xA240),
+            _ => {
+                let code = BIG5.encode(c);
+                code.filter(|&code| CP950EXT.decode(code).is_none())
+                    .or_else(|| CP950EXT.encode(c))
+                    .or(code)
+            }
+        })
+    }
+}
+
+/// Big5 with the Hong Kong Supplementary Character Set, HKSCS-2004.
+pub(crate) struct Big5HkScs;
+
+impl Decoder for Big5HkScs {
+    fn decode(&mut self, input: &[u8], output: &mut Wtf8Buf, last: bool) -> Result<usize, Fault> {
+        decode(input, output, last, |input, output| {
+            let c = input[0];
+            if c < 0x80 {
+                output.push(c as char);
+                return Step::Done(1);
+            }
+            let Some(&c2) = input.get(1) else {
+                return Step::Incomplete;
+            };
+            let code = u16::from_be_bytes([c, c2]);
+            // HKSCS takes over the Big5 codes 0xC6A1..=0xC8FE
+            let big5 = !(0xC6A1..=0xC8FE).contains(&code);
+            match BIG5HKSCS
+                .decode(c, c2)
+                .or_else(|| BIG5.decode(c, c2).filter(|_| big5))
+            {
+                Some(ch) => output.push(ch),
+                None => match BIG5HKSCS_PAIRS.iter().find(|&&(c, ..)| c == code) {
+                    Some(&(_, base, mark)) => {
+                        output.push(base);
+                        output.push(mark);
+                    }
+                    None => return Step::Illegal(1),
+                },
+            }
+            Step::Done(2)
+        })
+    }
+}
+
+impl Encoder for Big5HkScs {
+    fn encode(&mut self, input: &Wtf8, output: &mut Vec<u8>, last: bool) -> Result<usize, Fault> {
+        encode(input, output, last, |c, next, last, output| {
+            if c < 0x80 {
+                output.push(c as u8);
+                return Some(1);
+            }
+            let (code, len) = match pair(BIG5HKSCS_PAIRS, c, next, last) {
+                Some((_, 0)) => return Some(0),
+                Some(found) => found,
+                None => (BIG5HKSCS.encode(c).or_else(|| BIG5.encode(c))?, 1),
+            };
+            output.extend_from_slice(&code.to_be_bytes());
+            Some(len)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::encoding::codec::{digest, pairs};
+    use crate::types::Encoding;
+
+    #[test]
+    fn test_gb_tables() {
+        // from `tools/gen_tables.py --digests`
+        let digests = [
+            (Encoding::Gb2312, 0x020166559e6089e3),
+            (Encoding::Gbk, 0x373a5cecd1bd9477),
+            (Encoding::Gb18030, 0x9bf56208d6203e18),
+        ];
+        for (encoding, expected) in digests {
+            assert_eq!(digest(encoding, pairs()), expected, "{encoding}");
+        }
+        // the four-byte codes of the BMP and the first and last plane rows
+        let codes = [0x81, 0x82, 0x83, 0x84, 0x90, 0xE3]
+            .into_iter()
+            .flat_map(|a| {
+                (0x30..=0x39).flat_map(move |b| {
+                    (0x81..=0xFE).flat_map(move |c| (0x30..=0x39).map(move |d| vec![a, b, c, d]))
+                })
+            });
+        assert_eq!(digest(Encoding::Gb18030, codes), 0x6ceb2c02916a57b4);
+    }
+
+    #[test]
+    fn test_big5_tables() {
+        // from `tools/gen_tables.py --digests`
+        let digests = [
+            (Encoding::Big5, 0xfc42b39b1cec691d),
+            (Encoding::Cp950, 0x7a8149e7dc4b1abe),
+            (Encoding::Big5HkScs, 0x52533671c3363a75),
+        ];
+        for (encoding, expected) in digests {
+            assert_eq!(digest(encoding, pairs()), expected, "{encoding}");
+        }
+    }
+
+    #[test]
+    fn test_hz() {
+        // the results of CPython, which drops the escaped newline and merges the GB runs
+        let cases = [
+            (
+                &b"~{<:Ky2;S{#,NpGrH)!#~}Bye."[..],
+                "\u{5df1}\u{6240}\u{4e0d}\u{6b32}\u{ff0c}\u{52ff}\u{7403}\u{919b}\u{3002}Bye.",
+                None,
+            ),
+            (b"a~~b~\nc", "a~bc", Some(&b"a~~bc"[..])),
+            (b"~{VP~}~{ND~}", "\u{4e2d}\u{6587}", Some(b"~{VPND~}")),
+        ];
+        for (bytes, text, encoded) in cases {
+            assert_eq!(Encoding::Hz.decode(bytes), Ok(text.to_string()));
+            assert_eq!(
+                Encoding::Hz.encode(text),
+                Ok(encoded.unwrap_or(bytes).to_vec())
+            );
+        }
+    }
+}
@@ -0,0 +1,555 @@
+use super::tables::{GB2312, JISX0208, JISX0212, JISX0213_1, JISX0213_2, JISX0213_PAIRS, KSX1001};
+use super::{decode, encode, katakana, pair, Jisx0213, Step, JISX0213_2004_CODES, PLANE2};
+use crate::types::encoding::codec::{Decoder, Encoder, Fault};
+use crate::types::encoding::{Wtf8, Wtf8Buf};
+
+const ESC: u8 = 0x1B;
+const SO: u8 = 0x0E;
+const SI: u8 = 0x0F;
+
+/// The longest escape sequence looked for.
+const MAX_ESCAPE: usize = 16;
+
+/// A character set designated by an escape sequence.
+#[derive(Copy, Clone, Eq, PartialEq)]
+enum Charset {
+    Ascii,
+    /// JIS X 0201 Roman, ASCII with the yen sign and overline for `\` and `~`.
+    Roman,
+    /// JIS X 0201 half-width katakana.
+    Katakana,
+    Jisx0208,
+    /// JIS C 6226-1978, read as JIS X 0208.
+    Jisx0208_1978,
+    Jisx0212,
+    Jisx0213 {
+        plane2: bool,
+        v2000: bool,
+    },
+    Gb2312,
+    Ksx1001,
+    /// The upper half of ISO 8859-1, only single-shifted from G2.
+    Latin1,
+    /// The upper half of ISO 8859-7, only single-shifted from G2.
+    Greek,
+}
+
+impl Charset {
+    /// The final byte of the escape sequence, and whether it designates a two-byte set.
+    fn mark(self) -> (u8, bool) {
+        match self {
+            Self::Ascii => (b'B', false),
+            Self::Roman => (b'J', false),
+            Self::Katakana => (b'I', false),
+            Self::Latin1 => (b'A', false),
+            Self::Greek => (b'F', false),
+            Self::Jisx0208 => (b'B', true),
+            Self::Jisx0208_1978 => (b'@', true),
+            Self::Jisx0212 => (b'D', true),
+            Self::Jisx0213 { plane2: true, .. } => (b'P', true),
+            Self::Jisx0213 { v2000: true, .. } => (b'O', true),
+            Self::Jisx0213 { .. } => (b'Q', true),
+            Self::Gb2312 => (b'A', true),
+            Self::Ksx1001 => (b'C', true),
+        }
+    }
+
+    #[inline]
+    fn width(self) -> usize {
+        match self.mark().1 {
+            true => 2,
+            false => 1,
+        }
+    }
+
+    /// Decodes the code at the head of `input`, [`Charset::width`] bytes of 7 bits.
+    fn decode(self, input: &[u8], output: &mut Wtf8Buf) -> bool {
+        let (c, c2) = (input[0], input.get(1).copied().unwrap_or_default());
+        let ch = match self {
+            Self::Roman => match c {
+                0x5C => Some('\u{A5}'),
+                0x7E => Some('\u{203E}'),
+                _ => Some(c as char),
+            },
+            Self::Katakana => katakana(c ^ 0x80),
+            Self::Jisx0208 | Self::Jisx0208_1978 => JISX0208.decode(c, c2),
+            Self::Jisx0212 => JISX0212.decode(c, c2),
+            Self::Jisx0213 {
+                plane2: false,
+                v2000,
+            } => {
+                let code = u16::from_be_bytes([c, c2]);
+                if v2000 && JISX0213_2004_CODES.contains(&code) {
+                    return false;
+                }
+                JISX0213_1.decode(c, c2).or_else(|| {
+                    let &(_, base, mark) = JISX0213_PAIRS.iter().find(|&&(c, ..)| c == code)?;
+                    output.push(base);
+                    Some(mark)
+                })
+            }
+            Self::Jisx0213 { .. } => JISX0213_2.decode(c, c2),
+            Self::Gb2312 => GB2312.decode(c, c2),
+            Self::Ksx1001 => KSX1001.decode(c, c2),
+            Self::Ascii | Self::Latin1 | Self::Greek => None,
+        };
+        ch.map(|ch| output.push(ch)).is_some()
+    }
+
+    /// Decodes the byte after a single shift to G2.
+    fn decode_g2(self, c: u8) -> Option<char> {
+        let c = match self {
+            Self::Ascii if c < 0x80 => c as u32,
+            Self::Latin1 if c < 0x80 => c as u32 + 0x80,
+            Self::Greek => match c ^ 0x80 {
+                c @ 0..=0x9F => c as u32,
+                c @ 0xA0..=0xBF if 0x288F3BC9u32 >> (c - 0xA0) & 1 == 1 => c as u32,
+                c @ 0xD4..=0xFE => c as u32 + 0x2D0,
+                c @ 0xB4..=0xD3 if 0xBFFFFD77u32 >> (c - 0xB4) & 1 == 1 => c as u32 + 0x2D0,
+                0xA1 => 0x2018,
+                0xA2 => 0x2019,
+                0xAF => 0x2015,
+                _ => return None,
+            },
+            _ => return None,
+        };
+        char::from_u32(c)
+    }
+
+    /// The code of a non-ASCII `c`, not counting the pairs of JIS X 0213.
+    fn encode(self, c: u32) -> Option<u16> {
+        match self {
+            Self::Roman => match c {
+                0xA5 => Some(0x5C),
+                0x203E => Some(0x7E),
+                _ => None,
+            },
+            Self::Katakana => match c {
+                0xFF61..=0xFF9F => Some((c - 0xFF40) as u16),
+                _ => None,
+            },
+            Self::Jisx0208 => JISX0208.encode(c),
+            // only the characters missing from JIS X 0208
+            Self::Jisx0212 => match JISX0208.encode(c) {
+                Some(_) => None,
+                None => JISX0212.encode(c),
+            },
+            Self::Jisx0213 { plane2, v2000 } => {
+                let jisx0213 = match v2000 {
+                    true => Jisx0213::SHIFT_JISX0213,
+                    false => Jisx0213::SHIFT_JIS_2004,
+                };
+                let (code, _) = jisx0213.lookup(c, None, true)?;
+                match (plane2, code & PLANE2 != 0) {
+                    (false, false) => Some(code),
+                    (true, true) => Some(code & !PLANE2),
+                    _ => None,
+                }
+            }
+            Self::Gb2312 => GB2312.encode(c),
+            Self::Ksx1001 => KSX1001.encode(c),
+            Self::Ascii | Self::Jisx0208_1978 | Self::Latin1 | Self::Greek => None,
+        }
+    }
+}
+
+/// The ISO-2022 codecs of RFC 1468, 1554 and 1557 and their JIS X 0213 variants: 7-bit
+/// text where escape sequences designate the character sets of G0, G1 and G2.
+///
+/// The designations are kept between calls. An encoder returns to ASCII at the end of the
+/// last piece; a decoder only on a newline, which also shifts back to G0.
+pub(crate) struct Iso2022 {
+    /// The character sets that may be designated, in the order the encoder tries them.
+    charsets: &'static [Charset],
+    /// Whether SO and SI shift to G1 and back, where the two-byte sets go, as in ISO-2022-KR;
+    /// otherwise they pass as control characters and everything goes to G0.
+    shift: bool,
+    /// Whether `ESC .` designates G2 and `ESC N` single-shifts it, as in ISO-2022-JP-2.
+    g2: bool,
+    /// Whether `ESC & @ ESC $ B`, the JIS X 0208-1990 announcer, is understood.
+    ext: bool,
+    g: [Charset; 3],
+    shifted: bool,
+    /// Whether the decoder passes bytes through, after an escape sequence it doesn't know,
+    /// until a final byte.
+    escaped: bool,
+}
+
+impl Iso2022 {
+    pub const JP: Self = Self::new(
+        &[Charset::Jisx0208, Charset::Roman, Charset::Jisx0208_1978],
+        false,
+        false,
+        true,
+    );
+    pub const JP_1: Self = Self::new(
+        &[
+            Charset::Jisx0208,
+            Charset::Jisx0212,
+            Charset::Roman,
+            Charset::Jisx0208_1978,
+        ],
+        false,
+        false,
+        true,
+    );
+    pub const JP_2: Self = Self::new(
+        &[
+            Charset::Jisx0208,
+            Charset::Jisx0212,
+            Charset::Ksx1001,
+            Charset::Gb2312,
+            Charset::Roman,
+            Charset::Jisx0208_1978,
+            Charset::Latin1,
+            Charset::Greek,
+        ],
+        false,
+        true,
+        true,
+    );
+    pub const JP_2004: Self = Self::new(
+        &[
+            Charset::Jisx0208,
+            Charset::Jisx0213 {
+                plane2: false,
+                v2000: false,
+            },
+            Charset::Jisx0213 {
+                plane2: true,
+                v2000: false,
+            },
+        ],
+        false,
+        false,
+        true,
+    );
+    pub const JP_3: Self = Self::new(
+        &[
+            Charset::Jisx0208,
+            Charset::Jisx0213 {
+                plane2: false,
+                v2000: true,
+            },
+            Charset::Jisx0213 {
+                plane2: true,
+                v2000: true,
+            },
+        ],
+        false,
+        false,
+        true,
+    );
+    pub const JP_EXT: Self = Self::new(
+        &[
+            Charset::Jisx0208,
+            Charset::Jisx0212,
+            Charset::Roman,
+            Charset::Katakana,
+            Charset::Jisx0208_1978,
+        ],
+        false,
+        false,
+        true,
+    );
+    pub const KR: Self = Self::new(&[Charset::Ksx1001], true, false, false);
+
+    const fn new(charsets: &'static [Charset], shift: bool, g2: bool, ext: bool) -> Self {
+        Self {
+            charsets,
+            shift,
+            g2,
+            ext,
+            g: [Charset::Ascii; 3],
+            shifted: false,
+            escaped: false,
+        }
+    }
+
+    /// Handles the escape sequence at the head of `input`, designating a character set.
+    fn designate(&mut self, input: &[u8]) -> Step {
+        let mut len = 0;
+        let mut i = 1;
+        while i < MAX_ESCAPE {
+            let Some(&c) = input.get(i) else {
+                return Step::Incomplete;
+            };
+            if c.is_ascii_uppercase() || c == b'@' {
+                len = i + 1;
+                break;
+            }
+            if self.ext && c == b'&' && input.get(i + 1) == Some(&b'@') {
+                i += 2;
+            }
+            i += 1;
+        }
+        let (g, mark) = match (len, input[1]) {
+            (0, _) => return Step::Illegal(1),
+            (3, b'$') => (0, (input[2], true)),
+            (3, b'(') => (0, (input[2], false)),
+            (3, b')') => (1, (input[2], false)),
+            (3, b'.') if self.g2 => (2, (input[2], false)),
+            (4, b'$') => match input[2] {
+                b'(' => (0, (input[3], true)),
+                b')' => (1, (input[3], true)),
+                _ => return Step::Illegal(4),
+            },
+            (6, _) if self.ext && input[3..6] == [ESC, b'$', b'B'] => (0, (b'B', true)),
+            _ => return Step::Illegal(len),
+        };
+        let charset = match mark {
+            (b'B', false) => Charset::Ascii,
+            _ => match self.charsets.iter().find(|charset| charset.mark() == mark) {
+                Some(&charset) => charset,
+                None => return Step::Illegal(len),
+            },
+        };
+        self.g[g] = charset;
+        Step::Done(len)
+    }
+
+    /// The character set and code of `c`, and of `c` followed by `next` when they make a JIS
+    /// X 0213 pair, tried before anything else; `0` as the count asks to wait for `next`.
+    fn lookup(&self, c: u32, next: Option<u32>, last: bool) -> Option<(Charset, u16, usize)> {
+        let plane1 = self
+            .charsets
+            .iter()
+            .find(|charset| matches!(charset, Charset::Jisx0213 { plane2: false, .. }));
+        if let Some(&plane1) = plane1 {
+            if let Some((code, len)) = pair(JISX0213_PAIRS, c, next, last) {
+                return Some((plane1, code, len));
+            }
+        }
+        self.charsets
+            .iter()
+            .find_map(|&charset| Some((charset, charset.encode(c)?, 1)))
+    }
+
+    /// Writes what it takes to make `charset` current.
+    fn switch(&mut self, charset: Charset, output: &mut Vec<u8>) {
+        let (mark, dbcs) = charset.mark();
+        match self.shift && charset != Charset::Ascii {
+            false => {
+                if self.shifted {
+                    output.push(SI);
+                    self.shifted = false;
+                }
+                if self.g[0] != charset {
+                    match (dbcs, charset) {
+                        (false, _) => output.extend_from_slice(&[ESC, b'(', mark]),
+                        (true, Charset::Jisx0208) => output.extend_from_slice(&[ESC, b'$', mark]),
+                        (true, _) => output.extend_from_slice(&[ESC, b'$', b'(', mark]),
+                    }
+                    self.g[0] = charset;
+                }
+            }
+            true => {
+                if self.g[1] != charset {
+                    match dbcs {
+                        false => output.extend_from_slice(&[ESC, b')', mark]),
+                        true => output.extend_from_slice(&[ESC, b'$', b')', mark]),
+                    }
+                    self.g[1] = charset;
+                }
+                if !self.shifted {
+                    output.push(SO);
+                    self.shifted = true;
+                }
+            }
+        }
+    }
+}
+
+impl Decoder for Iso2022 {
+    fn decode(&mut self, input: &[u8], output: &mut Wtf8Buf, last: bool) -> Result<usize, Fault> {
+        decode(input, output, last, |input, output| {
+            let c = input[0];
+            if self.escaped {
+                output.push(c as char);
+                self.escaped = !(c.is_ascii_uppercase() || c == b'@');
+                return Step::Done(1);
+            }
+            match c {
+                ESC => match input.get(1) {
+                    None => Step::Incomplete,
+                    Some(b'(' | b')' | b'$' | b'.' | b'&') => self.designate(input),
+                    Some(b'N') if self.g2 => match input.get(2) {
+                        None => Step::Incomplete,
+                        Some(&c) => match self.g[2].decode_g2(c) {
+                            Some(ch) => {
+                                output.push(ch);
+                                Step::Done(3)
+                            }
+                            None => Step::Illegal(3),
+                        },
+                    },
+                    Some(_) => {
+                        output.push(ESC as char);
+                        self.escaped = true;
+                        Step::Done(1)
+                    }
+                },
+                SO | SI if self.shift => {
+                    self.shifted = c == SO;
+                    Step::Done(1)
+                }
+                b'\n' => {
+                    self.shifted = false;
+                    output.push('\n');
+                    Step::Done(1)
+                }
+                0x80.. => Step::Illegal(1),
+                _ => {
+                    let charset = self.g[self.shifted as usize];
+                    if c < 0x20 || charset == Charset::Ascii {
+                        output.push(c as char);
+                        return Step::Done(1);
+                    }
+                    let width = charset.width();
+                    if input.len() < width {
+                        return Step::Incomplete;
+                    }
+                    match charset.decode(input, output) {
+                        true => Step::Done(width),
+                        false => Step::Illegal(width),
+                    }
+                }
+            }
+        })
+    }
+}
+
+impl Encoder for Iso2022 {
+    fn encode(&mut self, input: &Wtf8, output: &mut Vec<u8>, last: bool) -> Result<usize, Fault> {
+        let len = encode(input, output, last, |c, next, last, output| {
+            if c < 0x80 {
+                if self.g[0] != Charset::Ascii {
+                    output.extend_from_slice(&[ESC, b'(', b'B']);
+                    self.g[0] = Charset::Ascii;
+                }
+                if self.shifted {
+                    output.push(SI);
+                    self.shifted = false;
+                }
+                output.push(c as u8);
+                return Some(1);
+            }
+            let (charset, code, len) = self.lookup(c, next, last)?;
+            if len == 0 {
+                return Some(0);
+            }
+            self.switch(charset, output);
+            match charset.width() {
+                1 => output.push(code as u8),
+                _ => output.extend_from_slice(&code.to_be_bytes()),
+            }
+            Some(len)
+        })?;
+        if last {
+            if self.shifted {
+                output.push(SI);
+                self.shifted = false;
+            }
+            if self.g[0] != Charset::Ascii {
+                output.extend_from_slice(&[ESC, b'(', b'B']);
+                self.g[0] = Charset::Ascii;
+            }
+        }
+        Ok(len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{Encoding, Errors, IncrementalDecoder, IncrementalEncoder};
+
+    /// The results of CPython, and whether it encodes the text back to the same bytes.
+    const CASES: [(Encoding, &[u8], &str, bool); 8] = [
+        (
+            Encoding::Iso2022Jp,
+            b"abc\x1b$BF|K\\8l\x1b(J\\~\x1b(Bxyz\n\x1b$B4A;z\x1b(B",
+            "abc\u{65e5}\u{672c}\u{8a9e}\u{a5}\u{203e}xyz\n\u{6f22}\u{5b57}",
+            true,
+        ),
+        (
+            Encoding::Iso2022Jp1,
+            b"a\x1b$BF|K\\\x1b$(D0!\x1b(Bb",
+            "a\u{65e5}\u{672c}\u{4e02}b",
+            true,
+        ),
+        (
+            Encoding::Iso2022Jp2,
+            b"a\x1b.A\x1bNi\x1b$BF|\x1b$(CGQ\x1b.F\x1bNW\x1b(Bb",
+            "a\u{e9}\u{65e5}\u{d55c}\u{3a7}b",
+            false,
+        ),
+        (
+            Encoding::Iso2022Jp2,
+            b"a\x1b$(D+1\x1b$BF|K\\\x1b$(D+.\x1b$(CGQ19>n\x1b$B&8\x1b(Bb\x1b$BCfJ8\x1b(B",
+            "a\u{e9}\u{65e5}\u{672c}\u{e7}\u{d55c}\u{ad6d}\u{c5b4}\u{3a9}b\u{4e2d}\u{6587}",
+            true,
+        ),
+        (
+            Encoding::Iso2022Jp2004,
+            b"a\x1b$BF|K\\\x1b$(Q.\",5\x1b(Bb",
+            "a\u{65e5}\u{672c}\u{2000b}\u{2170}b",
+            true,
+        ),
+        (
+            Encoding::Iso2022Jp3,
+            b"a\x1b$BF|K\\\x1b$(O.\"\x1b(Bb",
+            "a\u{65e5}\u{672c}\u{2000b}b",
+            true,
+        ),
+        (
+            Encoding::Iso2022JpExt,
+            b"a\x1b(I6@\x1b$BF|K\\\x1b(Bb",
+            "a\u{ff76}\u{ff80}\u{65e5}\u{672c}b",
+            true,
+        ),
+        (
+            Encoding::Iso2022Kr,
+            b"a\x1b$)C\x0eGQ19>n\x0fb\n\x0eA_\x0fc",
+            "a\u{d55c}\u{ad6d}\u{c5b4}b\n\u{c911}c",
+            true,
+        ),
+    ];
+
+    #[test]
+    fn test_decode_chunks() {
+        for (encoding, bytes, text) in CASES.map(|(e, b, t, _)| (e, b, t)) {
+            assert_eq!(encoding.decode(bytes), Ok(text.to_string()), "{encoding}");
+            // the designations and the shift state carry over every pair of splits
+            for i in 0..=bytes.len() {
+                for j in i..=bytes.len() {
+                    let mut decoder = IncrementalDecoder::new(encoding, Errors::Strict).unwrap();
+                    let mut decoded = decoder.decode(&bytes[..i], false).unwrap();
+                    decoded.push_str(&decoder.decode(&bytes[i..j], false).unwrap());
+                    decoded.push_str(&decoder.decode(&bytes[j..], true).unwrap());
+                    assert_eq!(decoded, text, "{encoding} split at {i} and {j}");
+                }
+            }
+            let mut decoder = IncrementalDecoder::new(encoding, Errors::Strict).unwrap();
+            let mut decoded = String::new();
+            for byte in bytes.chunks(1) {
+                decoded.push_str(&decoder.decode(byte, false).unwrap());
+            }
+            decoded.push_str(&decoder.decode(b"", true).unwrap());
+            assert_eq!(decoded, text, "{encoding} byte by byte");
+        }
+    }
+
+    #[test]
+    fn test_encode_chunks() {
+        for (encoding, bytes, text, _) in CASES.into_iter().filter(|case| case.3) {
+            assert_eq!(encoding.encode(text), Ok(bytes.to_vec()), "{encoding}");
+            let splits = text.char_indices().map(|(i, _)| i).chain([text.len()]);
+            for i in splits {
+                let mut encoder = IncrementalEncoder::new(encoding, Errors::Strict).unwrap();
+                let mut encoded = encoder.encode(&text[..i], false).unwrap();
+                encoded.extend(encoder.encode(&text[i..], true).unwrap());
+                assert_eq!(encoded, bytes, "{encoding} split at {i}");
+            }
+        }
+    }
+}
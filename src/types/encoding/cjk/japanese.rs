@@ -0,0 +1,479 @@
+use super::tables::{CP932EXT, JISX0208, JISX0212, JISX0213_1, JISX0213_2, JISX0213_PAIRS};
+use super::{decode, encode, pair, Step};
+use crate::types::encoding::codec::{Decoder, Encoder, Fault};
+use crate::types::encoding::{Wtf8, Wtf8Buf};
+
+/// The JIS row and column, both `0x21`-based, of a Shift_JIS lead and trail byte, or `None`
+/// for an invalid trail byte. Rows past 94 come from the leads `0xF0..=0xFC` of the 2004
+/// variant.
+fn sjis_to_jis(lead: u8, trail: u8) -> Option<(u8, u8)> {
+    if !(0x40..=0xFC).contains(&trail) || trail == 0x7F {
+        return None;
+    }
+    let c1 = if lead < 0xE0 {
+        lead - 0x81
+    } else {
+        lead - 0xC1
+    };
+    let c2 = if trail < 0x80 {
+        trail - 0x40
+    } else {
+        trail - 0x41
+    };
+    match c2 < 0x5E {
+        true => Some((2 * c1 + 0x21, c2 + 0x21)),
+        false => Some((2 * c1 + 0x22, c2 - 0x5E + 0x21)),
+    }
+}
+
+/// The Shift_JIS bytes of a JIS row and column, the row counted from `0x21` up.
+fn jis_to_sjis(row: u8, col: u8, output: &mut Vec<u8>) {
+    let c1 = row - 0x21;
+    let c2 = col - 0x21 + if c1 & 1 == 1 { 0x5E } else { 0 };
+    let c1 = c1 >> 1;
+    output.push(c1 + if c1 < 0x1F { 0x81 } else { 0xC1 });
+    output.push(c2 + if c2 < 0x3F { 0x40 } else { 0x41 });
+}
+
+#[inline]
+fn split(code: u16) -> (u8, u8) {
+    ((code >> 8) as u8, code as u8)
+}
+
+#[inline]
+fn push_euc(code: u16, output: &mut Vec<u8>) {
+    output.extend_from_slice(&(code | 0x8080).to_be_bytes());
+}
+
+/// Decodes a half-width katakana byte `0xA1..=0xDF`.
+#[inline]
+pub(crate) fn katakana(byte: u8) -> Option<char> {
+    match byte {
+        0xA1..=0xDF => char::from_u32(0xFEC0 + byte as u32),
+        _ => None,
+    }
+}
+
+pub(crate) struct ShiftJis;
+
+impl Decoder for ShiftJis {
+    fn decode(&mut self, input: &[u8], output: &mut Wtf8Buf, last: bool) -> Result<usize, Fault> {
+        decode(input, output, last, |input, output| match input[0] {
+            c @ 0..=0x7F => {
+                output.push(c as char);
+                Step::Done(1)
+            }
+            c @ (0x81..=0x9F | 0xE0..=0xEA) => {
+                let Some(&trail) = input.get(1) else {
+                    return Step::Incomplete;
+                };
+                match sjis_to_jis(c, trail).and_then(|(row, col)| JISX0208.decode(row, col)) {
+                    Some(ch) => {
+                        output.push(ch);
+                        Step::Done(2)
+                    }
+                    None => Step::Illegal(1),
+                }
+            }
+            c => match katakana(c) {
+                Some(ch) => {
+                    output.push(ch);
+                    Step::Done(1)
+                }
+                None => Step::Illegal(1),
+            },
+        })
+    }
+}
+
+impl Encoder for ShiftJis {
+    fn encode(&mut self, input: &Wtf8, output: &mut Vec<u8>, last: bool) -> Result<usize, Fault> {
+        encode(input, output, last, |c, _, _, output| {
+            match c {
+                0..=0x7F => output.push(c as u8),
+                0xA5 => output.push(0x5C),
+                0x203E => output.push(0x7E),
+                0xFF61..=0xFF9F => output.push((c - 0xFEC0) as u8),
+                _ => {
+                    let (row, col) = split(JISX0208.encode(c)?);
+                    jis_to_sjis(row, col, output);
+                }
+            }
+            Some(1)
+        })
+    }
+}
+
+/// Microsoft's Shift_JIS, with the NEC and IBM extensions and the user-defined area
+/// `0xF040..=0xF9FC` mapped to the private use area.
+pub(crate) struct Cp932;
+
+impl Decoder for Cp932 {
+    fn decode(&mut self, input: &[u8], output: &mut Wtf8Buf, last: bool) -> Result<usize, Fault> {
+        decode(input, output, last, |input, output| {
+            let c = input[0];
+            let ch = match c {
+                0..=0x80 => Some(c as char),
+                0xA0 => Some('\u{F8F0}'),
+                0xFD..=0xFF => char::from_u32(0xF8F1 + (c - 0xFD) as u32),
+                _ => katakana(c),
+            };
+            if let Some(ch) = ch {
+                output.push(ch);
+                return Step::Done(1);
+            }
+            let Some(&trail) = input.get(1) else {
+                return Step::Incomplete;
+            };
+            let ch = CP932EXT
+                .decode(u16::from_be_bytes([c, trail]))
+                .or_else(|| match c {
+                    0x81..=0x9F | 0xE0..=0xEA => {
+                        sjis_to_jis(c, trail).and_then(|(row, col)| JISX0208.decode(row, col))
+                    }
+                    0xF0..=0xF9 if sjis_to_jis(c, trail).is_some() => {
+                        let col = trail - if trail < 0x80 { 0x40 } else { 0x41 };
+                        char::from_u32(0xE000 + 188 * (c - 0xF0) as u32 + col as u32)
+                    }
+                    _ => None,
+                });
+            match ch {
+                Some(ch) => {
+                    output.push(ch);
+                    Step::Done(2)
+                }
+                None => Step::Illegal(1),
+            }
+        })
+    }
+}
+
+impl Encoder for Cp932 {
+    fn encode(&mut self, input: &Wtf8, output: &mut Vec<u8>, last: bool) -> Result<usize, Fault> {
+        encode(input, output, last, |c, _, _, output| {
+            match c {
+                0..=0x80 => output.push(c as u8),
+                0xFF61..=0xFF9F => output.push((c - 0xFEC0) as u8),
+                0xF8F0 => output.push(0xA0),
+                0xF8F1..=0xF8F3 => output.push((c - 0xF8F1) as u8 + 0xFD),
+                0xE000..=0xE757 => {
+                    let (lead, col) = ((c - 0xE000) / 188, (c - 0xE000) % 188);
+                    output.push(0xF0 + lead as u8);
+                    output.push(col as u8 + if col < 0x3F { 0x40 } else { 0x41 });
+                }
+                // the JIS X 0208 codes remapped by the extensions still encode one way
+                _ => match JISX0208.encode(c) {
+                    Some(code) => {
+                        let (row, col) = split(code);
+                        jis_to_sjis(row, col, output);
+                    }
+                    None => output.extend_from_slice(&CP932EXT.encode(c)?.to_be_bytes()),
+                },
+            }
+            Some(1)
+        })
+    }
+}
+
+pub(crate) struct EucJp;
+
+impl Decoder for EucJp {
+    fn decode(&mut self, input: &[u8], output: &mut Wtf8Buf, last: bool) -> Result<usize, Fault> {
+        decode(input, output, last, |input, output| {
+            let c = input[0];
+            if c < 0x80 {
+                output.push(c as char);
+                return Step::Done(1);
+            }
+            let Some(&c2) = input.get(1) else {
+                return Step::Incomplete;
+            };
+            let (ch, len) = match c {
+                0x8E => (katakana(c2), 2),
+                0x8F => {
+                    let Some(&c3) = input.get(2) else {
+                        return Step::Incomplete;
+                    };
+                    (JISX0212.decode(c2 ^ 0x80, c3 ^ 0x80), 3)
+                }
+                _ => (JISX0208.decode(c ^ 0x80, c2 ^ 0x80), 2),
+            };
+            match ch {
+                Some(ch) => {
+                    output.push(ch);
+                    Step::Done(len)
+                }
+                None => Step::Illegal(1),
+            }
+        })
+    }
+}
+
+impl Encoder for EucJp {
+    fn encode(&mut self, input: &Wtf8, output: &mut Vec<u8>, last: bool) -> Result<usize, Fault> {
+        encode(input, output, last, |c, _, _, output| {
+            match c {
+                0..=0x7F => output.push(c as u8),
+                0xA5 => output.push(0x5C),
+                0x203E => output.push(0x7E),
+                0xFF61..=0xFF9F => output.extend_from_slice(&[0x8E, (c - 0xFEC0) as u8]),
+                _ => match JISX0208.encode(c) {
+                    Some(code) => push_euc(code, output),
+                    None => {
+                        output.push(0x8F);
+                        push_euc(JISX0212.encode(c)?, output);
+                    }
+                },
+            }
+            Some(1)
+        })
+    }
+}
+
+/// The codes JIS X 0213:2004 added to plane 1, not in the 2000 edition.
+pub(crate) const JISX0213_2004_CODES: [u16; 10] = [
+    0x2E21, 0x2F7E, 0x4F54, 0x4F7E, 0x7427, 0x7E7A, 0x7E7B, 0x7E7C, 0x7E7D, 0x7E7E,
+];
+
+/// The characters of [`JISX0213_2004_CODES`], and U+9B1C which the 2000 edition doesn't have
+/// either; it decodes plane 2 `0x7D3B` as U+9B1D instead.
+const JISX0213_2004_CHARS: [u32; 11] = [
+    0x4FF1, 0x525D, 0x541E, 0x5653, 0x59F8, 0x5C5B, 0x5E77, 0x7626, 0x7E6B, 0x9B1C, 0x20B9F,
+];
+
+/// `euc_jis_2004` and `shift_jis_2004` on JIS X 0213, or `euc_jisx0213` and `shift_jisx0213`
+/// on its 2000 edition.
+pub(crate) struct Jisx0213 {
+    euc: bool,
+    v2000: bool,
+}
+
+/// A JIS X 0213 code, bit 15 set for plane 2.
+pub(crate) const PLANE2: u16 = 0x8000;
+
+impl Jisx0213 {
+    pub const EUC_JIS_2004: Self = Self {
+        euc: true,
+        v2000: false,
+    };
+    pub const EUC_JISX0213: Self = Self {
+        euc: true,
+        v2000: true,
+    };
+    pub const SHIFT_JIS_2004: Self = Self {
+        euc: false,
+        v2000: false,
+    };
+    pub const SHIFT_JISX0213: Self = Self {
+        euc: false,
+        v2000: true,
+    };
+
+    /// Decodes a code of plane 1 taking `len` bytes.
+    fn decode_plane1(&self, row: u8, col: u8, len: usize, output: &mut Wtf8Buf) -> Step {
+        let code = u16::from_be_bytes([row, col]);
+        if self.v2000 && JISX0213_2004_CODES.contains(&code) {
+            return Step::Illegal(2);
+        }
+        match (self.euc, code) {
+            (true, 0x2232) => output.push('\u{FF5E}'),
+            (false, 0x2140) => output.push('\\'),
+            _ => match JISX0213_1.decode(row, col) {
+                Some(ch) => output.push(ch),
+                None => match JISX0213_PAIRS.iter().find(|&&(c, ..)| c == code) {
+                    Some(&(_, base, mark)) => {
+                        output.push(base);
+                        output.push(mark);
+                    }
+                    None => return Step::Illegal(1),
+                },
+            },
+        }
+        Step::Done(len)
+    }
+
+    /// Decodes a code of plane 2 taking `len` bytes; EUC falls back to JIS X 0212.
+    fn decode_plane2(&self, row: u8, col: u8, len: usize, output: &mut Wtf8Buf) -> Step {
+        let ch = match self.v2000 && (row, col) == (0x7D, 0x3B) {
+            true => Some('\u{9B1D}'),
+            false => JISX0213_2.decode(row, col).or_else(|| match self.euc {
+                true => JISX0212.decode(row, col),
+                false => None,
+            }),
+        };
+        match ch {
+            Some(ch) => {
+                output.push(ch);
+                Step::Done(len)
+            }
+            None => Step::Illegal(1),
+        }
+    }
+
+    /// The code of `c`, and of `c` followed by `next` when they make a pair; `Some(0)` as the
+    /// count asks to wait for the next character.
+    pub fn lookup(&self, c: u32, next: Option<u32>, last: bool) -> Option<(u16, usize)> {
+        if self.v2000 {
+            if JISX0213_2004_CHARS.contains(&c) {
+                return None;
+            }
+            if c == 0x9B1D {
+                return Some((PLANE2 | 0x7D3B, 1));
+            }
+        }
+        if let Some(found) = pair(JISX0213_PAIRS, c, next, last) {
+            return Some(found);
+        }
+        if let Some(code) = JISX0213_1.encode(c) {
+            return Some((code, 1));
+        }
+        if let Some(code) = JISX0213_2.encode(c) {
+            return Some((PLANE2 | code, 1));
+        }
+        match self.euc {
+            true => JISX0212.encode(c).map(|code| (PLANE2 | code, 1)),
+            false => None,
+        }
+    }
+}
+
+impl Decoder for Jisx0213 {
+    fn decode(&mut self, input: &[u8], output: &mut Wtf8Buf, last: bool) -> Result<usize, Fault> {
+        match self.euc {
+            true => decode(input, output, last, |input, output| {
+                let c = input[0];
+                if c < 0x80 {
+                    output.push(c as char);
+                    return Step::Done(1);
+                }
+                let Some(&c2) = input.get(1) else {
+                    return Step::Incomplete;
+                };
+                match c {
+                    0x8E => match katakana(c2) {
+                        Some(ch) => {
+                            output.push(ch);
+                            Step::Done(2)
+                        }
+                        None => Step::Illegal(1),
+                    },
+                    0x8F => match input.get(2) {
+                        Some(&c3) => self.decode_plane2(c2 ^ 0x80, c3 ^ 0x80, 3, output),
+                        None => Step::Incomplete,
+                    },
+                    _ => self.decode_plane1(c ^ 0x80, c2 ^ 0x80, 2, output),
+                }
+            }),
+            false => decode(input, output, last, |input, output| {
+                let c = input[0];
+                let ch = match c {
+                    0x5C => Some('\u{A5}'),
+                    0x7E => Some('\u{203E}'),
+                    0..=0x7F => Some(c as char),
+                    _ => katakana(c),
+                };
+                if let Some(ch) = ch {
+                    output.push(ch);
+                    return Step::Done(1);
+                }
+                if !matches!(c, 0x81..=0x9F | 0xE0..=0xFC) {
+                    return Step::Illegal(1);
+                }
+                let Some(&trail) = input.get(1) else {
+                    return Step::Incomplete;
+                };
+                let Some((row, col)) = sjis_to_jis(c, trail) else {
+                    return Step::Illegal(1);
+                };
+                match row {
+                    0x21..=0x7E => self.decode_plane1(row, col, 2, output),
+                    _ => {
+                        // the leads 0xF0..=0xFC cover the rows 1, 3..=5, 8, 12..=15 and 78..=94
+                        let row = match row - 0x21 {
+                            c1 @ 0x67.. => c1 + 7,
+                            c1 @ (0x5F | 0x63..) => c1 - 0x37,
+                            c1 => c1 - 0x3D,
+                        };
+                        self.decode_plane2(row, col, 2, output)
+                    }
+                }
+            }),
+        }
+    }
+}
+
+impl Encoder for Jisx0213 {
+    fn encode(&mut self, input: &Wtf8, output: &mut Vec<u8>, last: bool) -> Result<usize, Fault> {
+        encode(input, output, last, |c, next, last, output| {
+            match (self.euc, c) {
+                (_, 0..=0x7F) if self.euc || (c != 0x5C && c != 0x7E) => output.push(c as u8),
+                (true, 0xFF61..=0xFF9F) => output.extend_from_slice(&[0x8E, (c - 0xFEC0) as u8]),
+                (false, 0xFF61..=0xFF9F) => output.push((c - 0xFEC0) as u8),
+                (false, 0xA5) => output.push(0x5C),
+                (false, 0x203E) => output.push(0x7E),
+                (false, 0x5C) => output.extend_from_slice(&[0x81, 0x5F]),
+                (false, 0xFF3C) => return None,
+                (true, 0xFF5E) => output.extend_from_slice(&[0xA2, 0xB2]),
+                _ => {
+                    let (code, len) = self.lookup(c, next, last)?;
+                    if len == 0 {
+                        return Some(0);
+                    }
+                    let (row, col) = split(code & !PLANE2);
+                    match (self.euc, code & PLANE2 != 0) {
+                        (true, false) => push_euc(code, output),
+                        (true, true) => {
+                            output.push(0x8F);
+                            push_euc(code, output);
+                        }
+                        (false, false) => jis_to_sjis(row, col, output),
+                        (false, true) => {
+                            let row = row | 0x80;
+                            let row = match row {
+                                0xEE.. => row - 0x87,
+                                0xA8 | 0xAC.. => row - 0x49,
+                                _ => row - 0x43,
+                            };
+                            jis_to_sjis(row + 0x21, col, output);
+                        }
+                    }
+                    return Some(len);
+                }
+            }
+            Some(1)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::encoding::codec::{digest, pairs};
+    use crate::types::Encoding;
+
+    #[test]
+    fn test_tables() {
+        // from `tools/gen_tables.py --digests`
+        let digests = [
+            (Encoding::ShiftJis, 0xef7c332ea282fab4),
+            (Encoding::Cp932, 0xefc02a395c60edba),
+            (Encoding::EucJp, 0x4bf5128b5522e6ea),
+            (Encoding::ShiftJis2004, 0xeed81abc3fae20fb),
+            (Encoding::ShiftJisx0213, 0x736472fb689cbedb),
+            (Encoding::EucJis2004, 0x6a34d8dd708638e5),
+            (Encoding::EucJisx0213, 0x6f3e54f625cc4042),
+        ];
+        for (encoding, expected) in digests {
+            assert_eq!(digest(encoding, pairs()), expected, "{encoding}");
+        }
+        // JIS X 0212 and plane 2 of JIS X 0213 behind `0x8F`
+        let digests = [
+            (Encoding::EucJp, 0x7f886d48a4d1df44),
+            (Encoding::EucJis2004, 0xf9c8732764ac4846),
+            (Encoding::EucJisx0213, 0xac79c1ffba727d25),
+        ];
+        for (encoding, expected) in digests {
+            let codes = (0xA1..=0xFE).flat_map(|a| (0xA1..=0xFE).map(move |b| vec![0x8F, a, b]));
+            assert_eq!(digest(encoding, codes), expected, "{encoding}");
+        }
+    }
+}
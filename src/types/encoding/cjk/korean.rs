@@ -0,0 +1,290 @@
+use super::tables::{CP949EXT, KSX1001};
+use super::{decode, decode_dbcs, decode_pair, encode, encode_dbcs, Step};
+use crate::types::encoding::codec::{Decoder, Encoder, Fault};
+use crate::types::encoding::{Wtf8, Wtf8Buf};
+
+const SYLLABLES: u32 = 0xAC00;
+const LAST_SYLLABLE: u32 = 0xD7A3;
+
+/// The KS X 1001 row of the Hangul compatibility jamo, whose column is `0xA1` past
+/// `U+3131` and where `0xD4` is the filler.
+const JAMO_ROW: u8 = 0xA4;
+const FILLER: u8 = 0xD4;
+
+/// The jamo columns of the 19 initial consonants in syllable order; the 21 vowels are
+/// `0xBF..=0xD3` in order.
+const CHOSEONG: [u8; 19] = [
+    0xA1, 0xA2, 0xA4, 0xA7, 0xA8, 0xA9, 0xB1, 0xB2, 0xB3, 0xB5, 0xB6, 0xB7, 0xB8, 0xB9, 0xBA, 0xBB,
+    0xBC, 0xBD, 0xBE,
+];
+const JUNGSEONG: u8 = 0xBF;
+
+/// The jamo columns of the 27 final consonants in syllable order, after the filler for none.
+const JONGSEONG: [u8; 28] = [
+    FILLER, 0xA1, 0xA2, 0xA3, 0xA4, 0xA5, 0xA6, 0xA7, 0xA9, 0xAA, 0xAB, 0xAC, 0xAD, 0xAE, 0xAF,
+    0xB0, 0xB1, 0xB2, 0xB4, 0xB5, 0xB6, 0xB7, 0xB8, 0xBA, 0xBB, 0xBC, 0xBD, 0xBE,
+];
+
+#[inline]
+fn syllable(cho: usize, jung: usize, jong: usize) -> Option<char> {
+    char::from_u32(SYLLABLES + (cho * 588 + jung * 28 + jong) as u32)
+}
+
+/// The compatibility jamo of a KS X 1001 jamo column.
+#[inline]
+fn jamo(col: u8) -> Option<char> {
+    char::from_u32(0x3130 + (col - 0xA0) as u32)
+}
+
+/// EUC-KR, KS X 1001 in two bytes; the Hangul syllables it lacks are spelled with the
+/// eight-byte make-up sequences of KS X 1001:1998 annex 3, filler and three jamo.
+pub(crate) struct EucKr;
+
+impl EucKr {
+    fn decode_makeup(input: &[u8]) -> Option<char> {
+        let &[_, _, JAMO_ROW, cho, JAMO_ROW, jung, JAMO_ROW, jong, ..] = input else {
+            return None;
+        };
+        let jung = jung.checked_sub(JUNGSEONG).filter(|&jung| jung < 21)?;
+        syllable(
+            CHOSEONG.iter().position(|&c| c == cho)?,
+            jung as usize,
+            JONGSEONG.iter().position(|&c| c == jong)?,
+        )
+    }
+}
+
+impl Decoder for EucKr {
+    fn decode(&mut self, input: &[u8], output: &mut Wtf8Buf, last: bool) -> Result<usize, Fault> {
+        decode(input, output, last, |input, output| match *input {
+            [c @ 0..=0x7F, ..] => {
+                output.push(c as char);
+                Step::Done(1)
+            }
+            [JAMO_ROW, FILLER, ..] if input.len() < 8 => Step::Incomplete,
+            [JAMO_ROW, FILLER, ..] => match Self::decode_makeup(input) {
+                Some(ch) => {
+                    output.push(ch);
+                    Step::Done(8)
+                }
+                None => Step::Illegal(1),
+            },
+            _ => decode_pair(input, output, |c, c2| KSX1001.decode(c ^ 0x80, c2 ^ 0x80)),
+        })
+    }
+}
+
+impl Encoder for EucKr {
+    fn encode(&mut self, input: &Wtf8, output: &mut Vec<u8>, last: bool) -> Result<usize, Fault> {
+        encode(input, output, last, |c, _, _, output| {
+            match (c, KSX1001.encode(c)) {
+                (0..=0x7F, _) => output.push(c as u8),
+                (_, Some(code)) => output.extend_from_slice(&(code | 0x8080).to_be_bytes()),
+                (SYLLABLES..=LAST_SYLLABLE, None) => {
+                    let c = (c - SYLLABLES) as usize;
+                    output.extend_from_slice(&[
+                        JAMO_ROW,
+                        FILLER,
+                        JAMO_ROW,
+                        CHOSEONG[c / 588],
+                        JAMO_ROW,
+                        JUNGSEONG + (c / 28 % 21) as u8,
+                        JAMO_ROW,
+                        JONGSEONG[c % 28],
+                    ]);
+                }
+                _ => return None,
+            }
+            Some(1)
+        })
+    }
+}
+
+/// Microsoft's Unified Hangul Code, EUC-KR with the Hangul syllables KS X 1001 lacks in the
+/// codes below it.
+pub(crate) struct Cp949;
+
+impl Decoder for Cp949 {
+    fn decode(&mut self, input: &[u8], output: &mut Wtf8Buf, last: bool) -> Result<usize, Fault> {
+        decode_dbcs(input, output, last, |c, c2| {
+            KSX1001
+                .decode(c ^ 0x80, c2 ^ 0x80)
+                .or_else(|| CP949EXT.decode(c, c2))
+        })
+    }
+}
+
+impl Encoder for Cp949 {
+    fn encode(&mut self, input: &Wtf8, output: &mut Vec<u8>, last: bool) -> Result<usize, Fault> {
+        encode_dbcs(input, output, last, |c| {
+            KSX1001
+                .encode(c)
+                .map(|code| code | 0x8080)
+                .or_else(|| CP949EXT.encode(c))
+        })
+    }
+}
+
+/// Johab, which codes a Hangul syllable in the bits of the code point `1 ccccc vvvvv fffff`
+/// and moves the rest of KS X 1001 to the leads `0xD8..=0xF9`.
+pub(crate) struct Johab;
+
+/// What the 5-bit fields of a Johab Hangul code stand for.
+#[derive(Copy, Clone, Eq, PartialEq)]
+enum Field {
+    Fill,
+    Jamo(usize),
+    Invalid,
+}
+
+impl Johab {
+    fn choseong(bits: u16) -> Field {
+        match bits {
+            1 => Field::Fill,
+            2..=20 => Field::Jamo(bits as usize - 2),
+            _ => Field::Invalid,
+        }
+    }
+
+    fn jungseong(bits: u16) -> Field {
+        match bits {
+            2 => Field::Fill,
+            3..=7 => Field::Jamo(bits as usize - 3),
+            10..=15 => Field::Jamo(bits as usize - 5),
+            18..=23 => Field::Jamo(bits as usize - 7),
+            26..=29 => Field::Jamo(bits as usize - 9),
+            _ => Field::Invalid,
+        }
+    }
+
+    fn jongseong(bits: u16) -> Field {
+        match bits {
+            1 => Field::Fill,
+            2..=17 => Field::Jamo(bits as usize - 1),
+            19..=29 => Field::Jamo(bits as usize - 2),
+            _ => Field::Invalid,
+        }
+    }
+
+    fn decode_hangul(code: u16) -> Option<char> {
+        let cho = Self::choseong(code >> 10 & 0x1F);
+        let jung = Self::jungseong(code >> 5 & 0x1F);
+        let jong = Self::jongseong(code & 0x1F);
+        match (cho, jung, jong) {
+            (Field::Invalid, ..) | (_, Field::Invalid, _) | (.., Field::Invalid) => None,
+            (Field::Fill, Field::Fill, Field::Fill) => Some('\u{3000}'),
+            (Field::Fill, Field::Fill, Field::Jamo(jong)) => jamo(JONGSEONG[jong]),
+            (Field::Fill, Field::Jamo(jung), Field::Fill) => jamo(JUNGSEONG + jung as u8),
+            (Field::Jamo(cho), Field::Fill, Field::Fill) => jamo(CHOSEONG[cho]),
+            (Field::Jamo(cho), Field::Jamo(jung), Field::Fill) => syllable(cho, jung, 0),
+            (Field::Jamo(cho), Field::Jamo(jung), Field::Jamo(jong)) => syllable(cho, jung, jong),
+            _ => None,
+        }
+    }
+
+    /// Decodes the KS X 1001 characters other than Hangul.
+    fn decode_ksx1001(c: u8, c2: u8) -> Option<char> {
+        if c == 0xDF
+            || c > 0xF9
+            || c2 < 0x31
+            || (0x80..0x91).contains(&c2)
+            || c2 & 0x7F == 0x7F
+            || (c == 0xDA && (0xA1..=0xD3).contains(&c2))
+        {
+            return None;
+        }
+        let t1 = match c < 0xE0 {
+            true => c.wrapping_sub(0xD9).wrapping_mul(2),
+            false => (2 * c as u16 - 0x197) as u8,
+        };
+        let t2 = if c2 < 0x91 { c2 - 0x31 } else { c2 - 0x43 };
+        match t2 < 0x5E {
+            true => KSX1001.decode(t1.wrapping_add(0x21), t2 + 0x21),
+            false => KSX1001.decode(t1.wrapping_add(0x22), t2 - 0x5E + 0x21),
+        }
+    }
+
+    /// The field of a vowel, the inverse of [`Johab::jungseong`].
+    fn jungseong_bits(jung: usize) -> u16 {
+        (jung
+            + match jung {
+                0..=4 => 3,
+                5..=10 => 5,
+                11..=16 => 7,
+                _ => 9,
+            }) as u16
+    }
+
+    /// The field of a final consonant or the filler for none, the inverse of
+    /// [`Johab::jongseong`].
+    fn jongseong_bits(jong: usize) -> u16 {
+        (jong + if jong < 17 { 1 } else { 2 }) as u16
+    }
+
+    fn encode_code(c: u32) -> Option<u16> {
+        let (jungseong, jongseong) = (Self::jungseong_bits, Self::jongseong_bits);
+        match c {
+            SYLLABLES..=LAST_SYLLABLE => {
+                let c = (c - SYLLABLES) as usize;
+                let cho = (c / 588) as u16 + 2;
+                Some(0x8000 | cho << 10 | jungseong(c / 28 % 21) << 5 | jongseong(c % 28))
+            }
+            0x3131..=0x3163 => {
+                let col = (c - 0x3130) as u8 + 0xA0;
+                if let Some(cho) = CHOSEONG.iter().position(|&j| j == col) {
+                    return Some(0x8000 | (cho as u16 + 2) << 10 | 2 << 5 | 1);
+                }
+                if let Some(jong) = JONGSEONG.iter().position(|&j| j == col) {
+                    return Some(0x8000 | 1 << 10 | 2 << 5 | jongseong(jong));
+                }
+                Some(0x8000 | 1 << 10 | jungseong((col - JUNGSEONG) as usize) << 5 | 1)
+            }
+            _ => {
+                let code = KSX1001.encode(c)?;
+                let (c1, c2) = ((code >> 8) as u8, code as u8);
+                let t1 = match c1 {
+                    0x21..=0x2C => c1 as u16 - 0x21 + 0x1B2,
+                    0x4A..=0x7D => c1 as u16 - 0x21 + 0x197,
+                    _ => return None,
+                };
+                let t2 = if t1 & 1 == 1 { 0x5E } else { 0 } + (c2 - 0x21);
+                let t2 = if t2 < 0x4E { t2 + 0x31 } else { t2 + 0x43 };
+                Some((t1 >> 1) << 8 | t2 as u16)
+            }
+        }
+    }
+}
+
+impl Decoder for Johab {
+    fn decode(&mut self, input: &[u8], output: &mut Wtf8Buf, last: bool) -> Result<usize, Fault> {
+        decode_dbcs(input, output, last, |c, c2| match c < 0xD8 {
+            true => Self::decode_hangul(u16::from_be_bytes([c, c2])),
+            false => Self::decode_ksx1001(c, c2),
+        })
+    }
+}
+
+impl Encoder for Johab {
+    fn encode(&mut self, input: &Wtf8, output: &mut Vec<u8>, last: bool) -> Result<usize, Fault> {
+        encode_dbcs(input, output, last, Self::encode_code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::encoding::codec::{digest, pairs};
+    use crate::types::Encoding;
+
+    #[test]
+    fn test_tables() {
+        // from `tools/gen_tables.py --digests`
+        let digests = [
+            (Encoding::EucKr, 0xc548ed54adb9f74e),
+            (Encoding::Cp949, 0x14e2b872e17e6608),
+            (Encoding::Johab, 0x95825bd95ee89687),
+        ];
+        for (encoding, expected) in digests {
+            assert_eq!(digest(encoding, pairs()), expected, "{encoding}");
+        }
+    }
+}
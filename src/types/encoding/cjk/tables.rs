@@ -0,0 +1,8 @@
+mod big5;
+pub(crate) use big5::*;
+mod gb;
+pub(crate) use gb::*;
+mod jis;
+pub(crate) use jis::*;
+mod ksc;
+pub(crate) use ksc::*;
@@ -0,0 +1,2597 @@
+// Generated by `tools/gen_tables.py` from CPython's codecs; do not edit by hand.
+
+use super::super::{Sparse, Table};
+
+/// GB 2312, rows `0x21..=0x77` and columns `0x21..=0x7E`.
+pub(crate) static GB2312: Table = Table::new(
+    0x2121,
+    94,
+    concat!(
+        "\u{3000}、。・ˉˇ¨〃々―～‖…‘’“”〔〕〈〉《》「」『』〖〗【】±×÷∶∧∨∑∏∪∩∈∷√⊥∥∠⌒⊙∫∮≡≌≈∽∝≠≮≯≤≥∞∵∴♂♀°′″℃＄¤￠￡‰§№☆★○●◎◇◆□■△▲※→←↑↓〓",
+        "\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0⒈⒉⒊⒋⒌⒍⒎⒏⒐⒑⒒⒓⒔⒕⒖⒗⒘⒙⒚⒛⑴⑵⑶⑷⑸⑹⑺⑻⑼⑽⑾⑿⒀⒁⒂⒃⒄⒅⒆⒇①②③④⑤⑥⑦⑧⑨⑩\0\0㈠㈡㈢㈣㈤㈥㈦㈧㈨㈩\0\0ⅠⅡⅢⅣⅤⅥⅦⅧⅨⅩⅪⅫ\0\0",
+        "！＂＃￥％＆＇（）＊＋，－．／０１２３４５６７８９：；＜＝＞？＠ＡＢＣＤＥＦＧＨＩＪＫＬＭＮＯＰＱＲＳＴＵＶＷＸＹＺ［＼］＾＿｀ａｂｃｄｅｆｇｈｉｊｋｌｍｎｏｐｑｒｓｔｕｖｗｘｙｚ｛｜｝￣",
+        "ぁあぃいぅうぇえぉおかがきぎくぐけげこごさざしじすずせぜそぞただちぢっつづてでとどなにぬねのはばぱひびぴふぶぷへべぺほぼぽまみむめもゃやゅゆょよらりるれろゎわゐゑをん\0\0\0\0\0\0\0\0\0\0\0",
+        "ァアィイゥウェエォオカガキギクグケゲコゴサザシジスズセゼソゾタダチヂッツヅテデトドナニヌネノハバパヒビピフブプヘベペホボポマミムメモャヤュユョヨラリルレロヮワヰヱヲンヴヵヶ\0\0\0\0\0\0\0\0",
+        "ΑΒΓΔΕΖΗΘΙΚΛΜΝΞΟΠΡΣΤΥΦΧΨΩ\0\0\0\0\0\0\0\0αβγδεζηθικλμνξοπρστυφχψω\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0",
+        "АБВГДЕЁЖЗИЙКЛМНОПРСТУФХЦЧШЩЪЫЬЭЮЯ\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0абвгдеёжзийклмнопрстуфхцчшщъыьэюя\0\0\0\0\0\0\0\0\0\0\0\0\0",
+        "āáǎàēéěèīíǐìōóǒòūúǔùǖǘǚǜüê\0\0\0\0\0\0\0\0\0\0ㄅㄆㄇㄈㄉㄊㄋㄌㄍㄎㄏㄐㄑㄒㄓㄔㄕㄖㄗㄘㄙㄚㄛㄜㄝㄞㄟㄠㄡㄢㄣㄤㄥㄦㄧㄨㄩ\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0",
+        "\0\0\0─━│┃┄┅┆┇┈┉┊┋┌┍┎┏┐┑┒┓└┕┖┗┘┙┚┛├┝┞┟┠┡┢┣┤┥┦┧┨┩┪┫┬┭┮┯┰┱┲┳┴┵┶┷┸┹┺┻┼┽┾┿╀╁╂╃╄╅╆╇╈╉╊╋\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0",
+        "\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0",
+        "\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0",
+        "\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0",
+        "\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0",
+        "\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0",
+        "\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0",
+        "啊阿埃挨哎唉哀皑癌蔼矮艾碍爱隘鞍氨安俺按暗岸胺案肮昂盎凹敖熬翱袄傲奥懊澳芭捌扒叭吧笆八疤巴拔跋靶把耙坝霸罢爸白柏百摆佰败拜稗斑班搬扳般颁板版扮拌伴瓣半办绊邦帮梆榜膀绑棒磅蚌镑傍谤苞胞包褒剥",
+        "薄雹保堡饱宝抱报暴豹鲍爆杯碑悲卑北辈背贝钡倍狈备惫焙被奔苯本笨崩绷甭泵蹦迸逼鼻比鄙笔彼碧蓖蔽毕毙毖币庇痹闭敝弊必辟壁臂避陛鞭边编贬扁便变卞辨辩辫遍标彪膘表鳖憋别瘪彬斌濒滨宾摈兵冰柄丙秉饼炳",
+        "病并玻菠播拨钵波博勃搏铂箔伯帛舶脖膊渤泊驳捕卜哺补埠不布步簿部怖擦猜裁材才财睬踩采彩菜蔡餐参蚕残惭惨灿苍舱仓沧藏操糙槽曹草厕策侧册测层蹭插叉茬茶查碴搽察岔差诧拆柴豺搀掺蝉馋谗缠铲产阐颤昌猖",
+        "场尝常长偿肠厂敞畅唱倡超抄钞朝嘲潮巢吵炒车扯撤掣彻澈郴臣辰尘晨忱沉陈趁衬撑称城橙成呈乘程惩澄诚承逞骋秤吃痴持匙池迟弛驰耻齿侈尺赤翅斥炽充冲虫崇宠抽酬畴踌稠愁筹仇绸瞅丑臭初出橱厨躇锄雏滁除楚",
+        "础储矗搐触处揣川穿椽传船喘串疮窗幢床闯创吹炊捶锤垂春椿醇唇淳纯蠢戳绰疵茨磁雌辞慈瓷词此刺赐次聪葱囱匆从丛凑粗醋簇促蹿篡窜摧崔催脆瘁粹淬翠村存寸磋撮搓措挫错搭达答瘩打大呆歹傣戴带殆代贷袋待逮",
+        "怠耽担丹单郸掸胆旦氮但惮淡诞弹蛋当挡党荡档刀捣蹈倒岛祷导到稻悼道盗德得的蹬灯登等瞪凳邓堤低滴迪敌笛狄涤翟嫡抵底地蒂第帝弟递缔颠掂滇碘点典靛垫电佃甸店惦奠淀殿碉叼雕凋刁掉吊钓调跌爹碟蝶迭谍叠",
+        "丁盯叮钉顶鼎锭定订丢东冬董懂动栋侗恫冻洞兜抖斗陡豆逗痘都督毒犊独读堵睹赌杜镀肚度渡妒端短锻段断缎堆兑队对墩吨蹲敦顿囤钝盾遁掇哆多夺垛躲朵跺舵剁惰堕蛾峨鹅俄额讹娥恶厄扼遏鄂饿恩而儿耳尔饵洱二",
+        "贰发罚筏伐乏阀法珐藩帆番翻樊矾钒繁凡烦反返范贩犯饭泛坊芳方肪房防妨仿访纺放菲非啡飞肥匪诽吠肺废沸费芬酚吩氛分纷坟焚汾粉奋份忿愤粪丰封枫蜂峰锋风疯烽逢冯缝讽奉凤佛否夫敷肤孵扶拂辐幅氟符伏俘服",
+        "浮涪福袱弗甫抚辅俯釜斧脯腑府腐赴副覆赋复傅付阜父腹负富讣附妇缚咐噶嘎该改概钙盖溉干甘杆柑竿肝赶感秆敢赣冈刚钢缸肛纲岗港杠篙皋高膏羔糕搞镐稿告哥歌搁戈鸽胳疙割革葛格蛤阁隔铬个各给根跟耕更庚羹",
+        "埂耿梗工攻功恭龚供躬公宫弓巩汞拱贡共钩勾沟苟狗垢构购够辜菇咕箍估沽孤姑鼓古蛊骨谷股故顾固雇刮瓜剐寡挂褂乖拐怪棺关官冠观管馆罐惯灌贯光广逛瑰规圭硅归龟闺轨鬼诡癸桂柜跪贵刽辊滚棍锅郭国果裹过哈",
+        "骸孩海氦亥害骇酣憨邯韩含涵寒函喊罕翰撼捍旱憾悍焊汗汉夯杭航壕嚎豪毫郝好耗号浩呵喝荷菏核禾和何合盒貉阂河涸赫褐鹤贺嘿黑痕很狠恨哼亨横衡恒轰哄烘虹鸿洪宏弘红喉侯猴吼厚候后呼乎忽瑚壶葫胡蝴狐糊湖",
+        "弧虎唬护互沪户花哗华猾滑画划化话槐徊怀淮坏欢环桓还缓换患唤痪豢焕涣宦幻荒慌黄磺蝗簧皇凰惶煌晃幌恍谎灰挥辉徽恢蛔回毁悔慧卉惠晦贿秽会烩汇讳诲绘荤昏婚魂浑混豁活伙火获或惑霍货祸击圾基机畸稽积箕",
+        "肌饥迹激讥鸡姬绩缉吉极棘辑籍集及急疾汲即嫉级挤几脊己蓟技冀季伎祭剂悸济寄寂计记既忌际妓继纪嘉枷夹佳家加荚颊贾甲钾假稼价架驾嫁歼监坚尖笺间煎兼肩艰奸缄茧检柬碱硷拣捡简俭剪减荐槛鉴践贱见键箭件",
+        "健舰剑饯渐溅涧建僵姜将浆江疆蒋桨奖讲匠酱降蕉椒礁焦胶交郊浇骄娇嚼搅铰矫侥脚狡角饺缴绞剿教酵轿较叫窖揭接皆秸街阶截劫节桔杰捷睫竭洁结解姐戒藉芥界借介疥诫届巾筋斤金今津襟紧锦仅谨进靳晋禁近烬浸",
+        "尽劲荆兢茎睛晶鲸京惊精粳经井警景颈静境敬镜径痉靖竟竞净炯窘揪究纠玖韭久灸九酒厩救旧臼舅咎就疚鞠拘狙疽居驹菊局咀矩举沮聚拒据巨具距踞锯俱句惧炬剧捐鹃娟倦眷卷绢撅攫抉掘倔爵觉决诀绝均菌钧军君峻",
+        "俊竣浚郡骏喀咖卡咯开揩楷凯慨刊堪勘坎砍看康慷糠扛抗亢炕考拷烤靠坷苛柯棵磕颗科壳咳可渴克刻客课肯啃垦恳坑吭空恐孔控抠口扣寇枯哭窟苦酷库裤夸垮挎跨胯块筷侩快宽款匡筐狂框矿眶旷况亏盔岿窥葵奎魁傀",
+        "馈愧溃坤昆捆困括扩廓阔垃拉喇蜡腊辣啦莱来赖蓝婪栏拦篮阑兰澜谰揽览懒缆烂滥琅榔狼廊郎朗浪捞劳牢老佬姥酪烙涝勒乐雷镭蕾磊累儡垒擂肋类泪棱楞冷厘梨犁黎篱狸离漓理李里鲤礼莉荔吏栗丽厉励砾历利傈例俐",
+        "痢立粒沥隶力璃哩俩联莲连镰廉怜涟帘敛脸链恋炼练粮凉梁粱良两辆量晾亮谅撩聊僚疗燎寥辽潦了撂镣廖料列裂烈劣猎琳林磷霖临邻鳞淋凛赁吝拎玲菱零龄铃伶羚凌灵陵岭领另令溜琉榴硫馏留刘瘤流柳六龙聋咙笼窿",
+        "隆垄拢陇楼娄搂篓漏陋芦卢颅庐炉掳卤虏鲁麓碌露路赂鹿潞禄录陆戮驴吕铝侣旅履屡缕虑氯律率滤绿峦挛孪滦卵乱掠略抡轮伦仑沦纶论萝螺罗逻锣箩骡裸落洛骆络妈麻玛码蚂马骂嘛吗埋买麦卖迈脉瞒馒蛮满蔓曼慢漫",
+        "谩芒茫盲氓忙莽猫茅锚毛矛铆卯茂冒帽貌贸么玫枚梅酶霉煤没眉媒镁每美昧寐妹媚门闷们萌蒙檬盟锰猛梦孟眯醚靡糜迷谜弥米秘觅泌蜜密幂棉眠绵冕免勉娩缅面苗描瞄藐秒渺庙妙蔑灭民抿皿敏悯闽明螟鸣铭名命谬摸",
+        "摹蘑模膜磨摩魔抹末莫墨默沫漠寞陌谋牟某拇牡亩姆母墓暮幕募慕木目睦牧穆拿哪呐钠那娜纳氖乃奶耐奈南男难囊挠脑恼闹淖呢馁内嫩能妮霓倪泥尼拟你匿腻逆溺蔫拈年碾撵捻念娘酿鸟尿捏聂孽啮镊镍涅您柠狞凝宁",
+        "拧泞牛扭钮纽脓浓农弄奴努怒女暖虐疟挪懦糯诺哦欧鸥殴藕呕偶沤啪趴爬帕怕琶拍排牌徘湃派攀潘盘磐盼畔判叛乓庞旁耪胖抛咆刨炮袍跑泡呸胚培裴赔陪配佩沛喷盆砰抨烹澎彭蓬棚硼篷膨朋鹏捧碰坯砒霹批披劈琵毗",
+        "啤脾疲皮匹痞僻屁譬篇偏片骗飘漂瓢票撇瞥拼频贫品聘乒坪苹萍平凭瓶评屏坡泼颇婆破魄迫粕剖扑铺仆莆葡菩蒲埔朴圃普浦谱曝瀑期欺栖戚妻七凄漆柒沏其棋奇歧畦崎脐齐旗祈祁骑起岂乞企启契砌器气迄弃汽泣讫掐",
+        "恰洽牵扦钎铅千迁签仟谦乾黔钱钳前潜遣浅谴堑嵌欠歉枪呛腔羌墙蔷强抢橇锹敲悄桥瞧乔侨巧鞘撬翘峭俏窍切茄且怯窃钦侵亲秦琴勤芹擒禽寝沁青轻氢倾卿清擎晴氰情顷请庆琼穷秋丘邱球求囚酋泅趋区蛆曲躯屈驱渠",
+        "取娶龋趣去圈颧权醛泉全痊拳犬券劝缺炔瘸却鹊榷确雀裙群然燃冉染瓤壤攘嚷让饶扰绕惹热壬仁人忍韧任认刃妊纫扔仍日戎茸蓉荣融熔溶容绒冗揉柔肉茹蠕儒孺如辱乳汝入褥软阮蕊瑞锐闰润若弱撒洒萨腮鳃塞赛三叁",
+        "伞散桑嗓丧搔骚扫嫂瑟色涩森僧莎砂杀刹沙纱傻啥煞筛晒珊苫杉山删煽衫闪陕擅赡膳善汕扇缮墒伤商赏晌上尚裳梢捎稍烧芍勺韶少哨邵绍奢赊蛇舌舍赦摄射慑涉社设砷申呻伸身深娠绅神沈审婶甚肾慎渗声生甥牲升绳",
+        "省盛剩胜圣师失狮施湿诗尸虱十石拾时什食蚀实识史矢使屎驶始式示士世柿事拭誓逝势是嗜噬适仕侍释饰氏市恃室视试收手首守寿授售受瘦兽蔬枢梳殊抒输叔舒淑疏书赎孰熟薯暑曙署蜀黍鼠属术述树束戍竖墅庶数漱",
+        "恕刷耍摔衰甩帅栓拴霜双爽谁水睡税吮瞬顺舜说硕朔烁斯撕嘶思私司丝死肆寺嗣四伺似饲巳松耸怂颂送宋讼诵搜艘擞嗽苏酥俗素速粟僳塑溯宿诉肃酸蒜算虽隋随绥髓碎岁穗遂隧祟孙损笋蓑梭唆缩琐索锁所塌他它她塔",
+        "獭挞蹋踏胎苔抬台泰酞太态汰坍摊贪瘫滩坛檀痰潭谭谈坦毯袒碳探叹炭汤塘搪堂棠膛唐糖倘躺淌趟烫掏涛滔绦萄桃逃淘陶讨套特藤腾疼誊梯剔踢锑提题蹄啼体替嚏惕涕剃屉天添填田甜恬舔腆挑条迢眺跳贴铁帖厅听烃",
+        "汀廷停亭庭挺艇通桐酮瞳同铜彤童桶捅筒统痛偷投头透凸秃突图徒途涂屠土吐兔湍团推颓腿蜕褪退吞屯臀拖托脱鸵陀驮驼椭妥拓唾挖哇蛙洼娃瓦袜歪外豌弯湾玩顽丸烷完碗挽晚皖惋宛婉万腕汪王亡枉网往旺望忘妄威",
+        "巍微危韦违桅围唯惟为潍维苇萎委伟伪尾纬未蔚味畏胃喂魏位渭谓尉慰卫瘟温蚊文闻纹吻稳紊问嗡翁瓮挝蜗涡窝我斡卧握沃巫呜钨乌污诬屋无芜梧吾吴毋武五捂午舞伍侮坞戊雾晤物勿务悟误昔熙析西硒矽晰嘻吸锡牺",
+        "稀息希悉膝夕惜熄烯溪汐犀檄袭席习媳喜铣洗系隙戏细瞎虾匣霞辖暇峡侠狭下厦夏吓掀锨先仙鲜纤咸贤衔舷闲涎弦嫌显险现献县腺馅羡宪陷限线相厢镶香箱襄湘乡翔祥详想响享项巷橡像向象萧硝霄削哮嚣销消宵淆晓",
+        "小孝校肖啸笑效楔些歇蝎鞋协挟携邪斜胁谐写械卸蟹懈泄泻谢屑薪芯锌欣辛新忻心信衅星腥猩惺兴刑型形邢行醒幸杏性姓兄凶胸匈汹雄熊休修羞朽嗅锈秀袖绣墟戌需虚嘘须徐许蓄酗叙旭序畜恤絮婿绪续轩喧宣悬旋玄",
+        "选癣眩绚靴薛学穴雪血勋熏循旬询寻驯巡殉汛训讯逊迅压押鸦鸭呀丫芽牙蚜崖衙涯雅哑亚讶焉咽阉烟淹盐严研蜒岩延言颜阎炎沿奄掩眼衍演艳堰燕厌砚雁唁彦焰宴谚验殃央鸯秧杨扬佯疡羊洋阳氧仰痒养样漾邀腰妖瑶",
+        "摇尧遥窑谣姚咬舀药要耀椰噎耶爷野冶也页掖业叶曳腋夜液一壹医揖铱依伊衣颐夷遗移仪胰疑沂宜姨彝椅蚁倚已乙矣以艺抑易邑屹亿役臆逸肄疫亦裔意毅忆义益溢诣议谊译异翼翌绎茵荫因殷音阴姻吟银淫寅饮尹引隐",
+        "印英樱婴鹰应缨莹萤营荧蝇迎赢盈影颖硬映哟拥佣臃痈庸雍踊蛹咏泳涌永恿勇用幽优悠忧尤由邮铀犹油游酉有友右佑釉诱又幼迂淤于盂榆虞愚舆余俞逾鱼愉渝渔隅予娱雨与屿禹宇语羽玉域芋郁吁遇喻峪御愈欲狱育誉",
+        "浴寓裕预豫驭鸳渊冤元垣袁原援辕园员圆猿源缘远苑愿怨院曰约越跃钥岳粤月悦阅耘云郧匀陨允运蕴酝晕韵孕匝砸杂栽哉灾宰载再在咱攒暂赞赃脏葬遭糟凿藻枣早澡蚤躁噪造皂灶燥责择则泽贼怎增憎曾赠扎喳渣札轧",
+        "铡闸眨栅榨咋乍炸诈摘斋宅窄债寨瞻毡詹粘沾盏斩辗崭展蘸栈占战站湛绽樟章彰漳张掌涨杖丈帐账仗胀瘴障招昭找沼赵照罩兆肇召遮折哲蛰辙者锗蔗这浙珍斟真甄砧臻贞针侦枕疹诊震振镇阵蒸挣睁征狰争怔整拯正政",
+        "帧症郑证芝枝支吱蜘知肢脂汁之织职直植殖执值侄址指止趾只旨纸志挚掷至致置帜峙制智秩稚质炙痔滞治窒中盅忠钟衷终种肿重仲众舟周州洲诌粥轴肘帚咒皱宙昼骤珠株蛛朱猪诸诛逐竹烛煮拄瞩嘱主著柱助蛀贮铸筑",
+        "住注祝驻抓爪拽专砖转撰赚篆桩庄装妆撞壮状椎锥追赘坠缀谆准捉拙卓桌琢茁酌啄着灼浊兹咨资姿滋淄孜紫仔籽滓子自渍字鬃棕踪宗综总纵邹走奏揍租足卒族祖诅阻组钻纂嘴醉最罪尊遵昨左佐柞做作坐座\0\0\0\0\0",
+        "亍丌兀丐廿卅丕亘丞鬲孬噩丨禺丿匕乇夭爻卮氐囟胤馗毓睾鼗丶亟鼐乜乩亓芈孛啬嘏仄厍厝厣厥厮靥赝匚叵匦匮匾赜卦卣刂刈刎刭刳刿剀剌剞剡剜蒯剽劂劁劐劓冂罔亻仃仉仂仨仡仫仞伛仳伢佤仵伥伧伉伫佞佧攸佚佝",
+        "佟佗伲伽佶佴侑侉侃侏佾佻侪佼侬侔俦俨俪俅俚俣俜俑俟俸倩偌俳倬倏倮倭俾倜倌倥倨偾偃偕偈偎偬偻傥傧傩傺僖儆僭僬僦僮儇儋仝氽佘佥俎龠汆籴兮巽黉馘冁夔勹匍訇匐凫夙兕亠兖亳衮袤亵脔裒禀嬴蠃羸冫冱冽冼",
+        "凇冖冢冥讠讦讧讪讴讵讷诂诃诋诏诎诒诓诔诖诘诙诜诟诠诤诨诩诮诰诳诶诹诼诿谀谂谄谇谌谏谑谒谔谕谖谙谛谘谝谟谠谡谥谧谪谫谮谯谲谳谵谶卩卺阝阢阡阱阪阽阼陂陉陔陟陧陬陲陴隈隍隗隰邗邛邝邙邬邡邴邳邶邺",
+        "邸邰郏郅邾郐郄郇郓郦郢郜郗郛郫郯郾鄄鄢鄞鄣鄱鄯鄹酃酆刍奂劢劬劭劾哿勐勖勰叟燮矍廴凵凼鬯厶弁畚巯坌垩垡塾墼壅壑圩圬圪圳圹圮圯坜圻坂坩垅坫垆坼坻坨坭坶坳垭垤垌垲埏垧垴垓垠埕埘埚埙埒垸埴埯埸埤埝",
+        "堋堍埽埭堀堞堙塄堠塥塬墁墉墚墀馨鼙懿艹艽艿芏芊芨芄芎芑芗芙芫芸芾芰苈苊苣芘芷芮苋苌苁芩芴芡芪芟苄苎芤苡茉苷苤茏茇苜苴苒苘茌苻苓茑茚茆茔茕苠苕茜荑荛荜茈莒茼茴茱莛荞茯荏荇荃荟荀茗荠茭茺茳荦荥",
+        "荨茛荩荬荪荭荮莰荸莳莴莠莪莓莜莅荼莶莩荽莸荻莘莞莨莺莼菁萁菥菘堇萘萋菝菽菖萜萸萑萆菔菟萏萃菸菹菪菅菀萦菰菡葜葑葚葙葳蒇蒈葺蒉葸萼葆葩葶蒌蒎萱葭蓁蓍蓐蓦蒽蓓蓊蒿蒺蓠蒡蒹蒴蒗蓥蓣蔌甍蔸蓰蔹蔟蔺",
+        "蕖蔻蓿蓼蕙蕈蕨蕤蕞蕺瞢蕃蕲蕻薤薨薇薏蕹薮薜薅薹薷薰藓藁藜藿蘧蘅蘩蘖蘼廾弈夼奁耷奕奚奘匏尢尥尬尴扌扪抟抻拊拚拗拮挢拶挹捋捃掭揶捱捺掎掴捭掬掊捩掮掼揲揸揠揿揄揞揎摒揆掾摅摁搋搛搠搌搦搡摞撄摭撖",
+        "摺撷撸撙撺擀擐擗擤擢攉攥攮弋忒甙弑卟叱叽叩叨叻吒吖吆呋呒呓呔呖呃吡呗呙吣吲咂咔呷呱呤咚咛咄呶呦咝哐咭哂咴哒咧咦哓哔呲咣哕咻咿哌哙哚哜咩咪咤哝哏哞唛哧唠哽唔哳唢唣唏唑唧唪啧喏喵啉啭啁啕唿啐唼",
+        "唷啖啵啶啷唳唰啜喋嗒喃喱喹喈喁喟啾嗖喑啻嗟喽喾喔喙嗪嗷嗉嘟嗑嗫嗬嗔嗦嗝嗄嗯嗥嗲嗳嗌嗍嗨嗵嗤辔嘞嘈嘌嘁嘤嘣嗾嘀嘧嘭噘嘹噗嘬噍噢噙噜噌噔嚆噤噱噫噻噼嚅嚓嚯囔囗囝囡囵囫囹囿圄圊圉圜帏帙帔帑帱帻帼",
+        "帷幄幔幛幞幡岌屺岍岐岖岈岘岙岑岚岜岵岢岽岬岫岱岣峁岷峄峒峤峋峥崂崃崧崦崮崤崞崆崛嵘崾崴崽嵬嵛嵯嵝嵫嵋嵊嵩嵴嶂嶙嶝豳嶷巅彳彷徂徇徉後徕徙徜徨徭徵徼衢彡犭犰犴犷犸狃狁狎狍狒狨狯狩狲狴狷猁狳猃狺",
+        "狻猗猓猡猊猞猝猕猢猹猥猬猸猱獐獍獗獠獬獯獾舛夥飧夤夂饣饧饨饩饪饫饬饴饷饽馀馄馇馊馍馐馑馓馔馕庀庑庋庖庥庠庹庵庾庳赓廒廑廛廨廪膺忄忉忖忏怃忮怄忡忤忾怅怆忪忭忸怙怵怦怛怏怍怩怫怊怿怡恸恹恻恺恂",
+        "恪恽悖悚悭悝悃悒悌悛惬悻悱惝惘惆惚悴愠愦愕愣惴愀愎愫慊慵憬憔憧憷懔懵忝隳闩闫闱闳闵闶闼闾阃阄阆阈阊阋阌阍阏阒阕阖阗阙阚丬爿戕氵汔汜汊沣沅沐沔沌汨汩汴汶沆沩泐泔沭泷泸泱泗沲泠泖泺泫泮沱泓泯泾",
+        "洹洧洌浃浈洇洄洙洎洫浍洮洵洚浏浒浔洳涑浯涞涠浞涓涔浜浠浼浣渚淇淅淞渎涿淠渑淦淝淙渖涫渌涮渫湮湎湫溲湟溆湓湔渲渥湄滟溱溘滠漭滢溥溧溽溻溷滗溴滏溏滂溟潢潆潇漤漕滹漯漶潋潴漪漉漩澉澍澌潸潲潼潺濑",
+        "濉澧澹澶濂濡濮濞濠濯瀚瀣瀛瀹瀵灏灞宀宄宕宓宥宸甯骞搴寤寮褰寰蹇謇辶迓迕迥迮迤迩迦迳迨逅逄逋逦逑逍逖逡逵逶逭逯遄遑遒遐遨遘遢遛暹遴遽邂邈邃邋彐彗彖彘尻咫屐屙孱屣屦羼弪弩弭艴弼鬻屮妁妃妍妩妪妣",
+        "妗姊妫妞妤姒妲妯姗妾娅娆姝娈姣姘姹娌娉娲娴娑娣娓婀婧婊婕娼婢婵胬媪媛婷婺媾嫫媲嫒嫔媸嫠嫣嫱嫖嫦嫘嫜嬉嬗嬖嬲嬷孀尕尜孚孥孳孑孓孢驵驷驸驺驿驽骀骁骅骈骊骐骒骓骖骘骛骜骝骟骠骢骣骥骧纟纡纣纥纨纩",
+        "纭纰纾绀绁绂绉绋绌绐绔绗绛绠绡绨绫绮绯绱绲缍绶绺绻绾缁缂缃缇缈缋缌缏缑缒缗缙缜缛缟缡缢缣缤缥缦缧缪缫缬缭缯缰缱缲缳缵幺畿巛甾邕玎玑玮玢玟珏珂珑玷玳珀珉珈珥珙顼琊珩珧珞玺珲琏琪瑛琦琥琨琰琮琬",
+        "琛琚瑁瑜瑗瑕瑙瑷瑭瑾璜璎璀璁璇璋璞璨璩璐璧瓒璺韪韫韬杌杓杞杈杩枥枇杪杳枘枧杵枨枞枭枋杷杼柰栉柘栊柩枰栌柙枵柚枳柝栀柃枸柢栎柁柽栲栳桠桡桎桢桄桤梃栝桕桦桁桧桀栾桊桉栩梵梏桴桷梓桫棂楮棼椟椠棹",
+        "椤棰椋椁楗棣椐楱椹楠楂楝榄楫榀榘楸椴槌榇榈槎榉楦楣楹榛榧榻榫榭槔榱槁槊槟榕槠榍槿樯槭樗樘橥槲橄樾檠橐橛樵檎橹樽樨橘橼檑檐檩檗檫猷獒殁殂殇殄殒殓殍殚殛殡殪轫轭轱轲轳轵轶轸轷轹轺轼轾辁辂辄辇辋",
+        "辍辎辏辘辚軎戋戗戛戟戢戡戥戤戬臧瓯瓴瓿甏甑甓攴旮旯旰昊昙杲昃昕昀炅曷昝昴昱昶昵耆晟晔晁晏晖晡晗晷暄暌暧暝暾曛曜曦曩贲贳贶贻贽赀赅赆赈赉赇赍赕赙觇觊觋觌觎觏觐觑牮犟牝牦牯牾牿犄犋犍犏犒挈挲掰",
+        "搿擘耄毪毳毽毵毹氅氇氆氍氕氘氙氚氡氩氤氪氲攵敕敫牍牒牖爰虢刖肟肜肓肼朊肽肱肫肭肴肷胧胨胩胪胛胂胄胙胍胗朐胝胫胱胴胭脍脎胲胼朕脒豚脶脞脬脘脲腈腌腓腴腙腚腱腠腩腼腽腭腧塍媵膈膂膑滕膣膪臌朦臊膻",
+        "臁膦欤欷欹歃歆歙飑飒飓飕飙飚殳彀毂觳斐齑斓於旆旄旃旌旎旒旖炀炜炖炝炻烀炷炫炱烨烊焐焓焖焯焱煳煜煨煅煲煊煸煺熘熳熵熨熠燠燔燧燹爝爨灬焘煦熹戾戽扃扈扉礻祀祆祉祛祜祓祚祢祗祠祯祧祺禅禊禚禧禳忑忐",
+        "怼恝恚恧恁恙恣悫愆愍慝憩憝懋懑戆肀聿沓泶淼矶矸砀砉砗砘砑斫砭砜砝砹砺砻砟砼砥砬砣砩硎硭硖硗砦硐硇硌硪碛碓碚碇碜碡碣碲碹碥磔磙磉磬磲礅磴礓礤礞礴龛黹黻黼盱眄眍盹眇眈眚眢眙眭眦眵眸睐睑睇睃睚睨",
+        "睢睥睿瞍睽瞀瞌瞑瞟瞠瞰瞵瞽町畀畎畋畈畛畲畹疃罘罡罟詈罨罴罱罹羁罾盍盥蠲钅钆钇钋钊钌钍钏钐钔钗钕钚钛钜钣钤钫钪钭钬钯钰钲钴钶钷钸钹钺钼钽钿铄铈铉铊铋铌铍铎铐铑铒铕铖铗铙铘铛铞铟铠铢铤铥铧铨铪",
+        "铩铫铮铯铳铴铵铷铹铼铽铿锃锂锆锇锉锊锍锎锏锒锓锔锕锖锘锛锝锞锟锢锪锫锩锬锱锲锴锶锷锸锼锾锿镂锵镄镅镆镉镌镎镏镒镓镔镖镗镘镙镛镞镟镝镡镢镤镥镦镧镨镩镪镫镬镯镱镲镳锺矧矬雉秕秭秣秫稆嵇稃稂稞稔",
+        "稹稷穑黏馥穰皈皎皓皙皤瓞瓠甬鸠鸢鸨鸩鸪鸫鸬鸲鸱鸶鸸鸷鸹鸺鸾鹁鹂鹄鹆鹇鹈鹉鹋鹌鹎鹑鹕鹗鹚鹛鹜鹞鹣鹦鹧鹨鹩鹪鹫鹬鹱鹭鹳疒疔疖疠疝疬疣疳疴疸痄疱疰痃痂痖痍痣痨痦痤痫痧瘃痱痼痿瘐瘀瘅瘌瘗瘊瘥瘘瘕瘙",
+        "瘛瘼瘢瘠癀瘭瘰瘿瘵癃瘾瘳癍癞癔癜癖癫癯翊竦穸穹窀窆窈窕窦窠窬窨窭窳衤衩衲衽衿袂袢裆袷袼裉裢裎裣裥裱褚裼裨裾裰褡褙褓褛褊褴褫褶襁襦襻疋胥皲皴矜耒耔耖耜耠耢耥耦耧耩耨耱耋耵聃聆聍聒聩聱覃顸颀颃",
+        "颉颌颍颏颔颚颛颞颟颡颢颥颦虍虔虬虮虿虺虼虻蚨蚍蚋蚬蚝蚧蚣蚪蚓蚩蚶蛄蚵蛎蚰蚺蚱蚯蛉蛏蚴蛩蛱蛲蛭蛳蛐蜓蛞蛴蛟蛘蛑蜃蜇蛸蜈蜊蜍蜉蜣蜻蜞蜥蜮蜚蜾蝈蜴蜱蜩蜷蜿螂蜢蝽蝾蝻蝠蝰蝌蝮螋蝓蝣蝼蝤蝙蝥螓螯螨蟒",
+        "蟆螈螅螭螗螃螫蟥螬螵螳蟋蟓螽蟑蟀蟊蟛蟪蟠蟮蠖蠓蟾蠊蠛蠡蠹蠼缶罂罄罅舐竺竽笈笃笄笕笊笫笏筇笸笪笙笮笱笠笥笤笳笾笞筘筚筅筵筌筝筠筮筻筢筲筱箐箦箧箸箬箝箨箅箪箜箢箫箴篑篁篌篝篚篥篦篪簌篾篼簏簖簋",
+        "簟簪簦簸籁籀臾舁舂舄臬衄舡舢舣舭舯舨舫舸舻舳舴舾艄艉艋艏艚艟艨衾袅袈裘裟襞羝羟羧羯羰羲籼敉粑粝粜粞粢粲粼粽糁糇糌糍糈糅糗糨艮暨羿翎翕翥翡翦翩翮翳糸絷綦綮繇纛麸麴赳趄趔趑趱赧赭豇豉酊酐酎酏酤",
+        "酢酡酰酩酯酽酾酲酴酹醌醅醐醍醑醢醣醪醭醮醯醵醴醺豕鹾趸跫踅蹙蹩趵趿趼趺跄跖跗跚跞跎跏跛跆跬跷跸跣跹跻跤踉跽踔踝踟踬踮踣踯踺蹀踹踵踽踱蹉蹁蹂蹑蹒蹊蹰蹶蹼蹯蹴躅躏躔躐躜躞豸貂貊貅貘貔斛觖觞觚觜",
+        "觥觫觯訾謦靓雩雳雯霆霁霈霏霎霪霭霰霾龀龃龅龆龇龈龉龊龌黾鼋鼍隹隼隽雎雒瞿雠銎銮鋈錾鍪鏊鎏鐾鑫鱿鲂鲅鲆鲇鲈稣鲋鲎鲐鲑鲒鲔鲕鲚鲛鲞鲟鲠鲡鲢鲣鲥鲦鲧鲨鲩鲫鲭鲮鲰鲱鲲鲳鲴鲵鲶鲷鲺鲻鲼鲽鳄鳅鳆鳇鳊鳋",
+        "鳌鳍鳎鳏鳐鳓鳔鳕鳗鳘鳙鳜鳝鳟鳢靼鞅鞑鞒鞔鞯鞫鞣鞲鞴骱骰骷鹘骶骺骼髁髀髅髂髋髌髑魅魃魇魉魈魍魑飨餍餮饕饔髟髡髦髯髫髻髭髹鬈鬏鬓鬟鬣麽麾縻麂麇麈麋麒鏖麝麟黛黜黝黠黟黢黩黧黥黪黯鼢鼬鼯鼹鼷鼽鼾齄",
+    ),
+);
+
+/// GBK, leads `0x81..=0xFE` and trails `0x40..=0xFE`; in the GB 2312 area it differs from
+/// [`GB2312`] on `0xA1A4` and `0xA1AA` only.
+pub(crate) static GBK: Table = Table::new(
+    0x8140,
+    191,
+    concat!(
+        "丂丄丅丆丏丒丗丟丠両丣並丩丮丯丱丳丵丷丼乀乁乂乄乆乊乑乕乗乚乛乢乣乤乥乧乨乪乫乬乭乮乯乲乴乵乶乷乸乹乺乻乼乽乿亀亁亂亃亄亅亇亊\0亐亖亗亙亜亝亞亣亪亯亰亱亴亶亷亸亹亼亽亾仈仌仏仐仒仚仛仜仠仢仦仧仩仭仮仯仱仴仸仹仺仼仾伀伂伃伄伅伆伇伈伋伌伒伓伔伕伖伜伝伡伣伨伩伬伭伮伱伳伵伷伹伻伾伿佀佁佂佄佅佇佈佉佊佋佌佒佔佖佡佢佦佨佪佫佭佮佱佲併佷佸佹佺佽侀侁侂侅來侇侊侌侎侐侒侓侕侖侘侙侚侜侞侟価侢",
+        "侤侫侭侰侱侲侳侴侶侷侸侹侺侻侼侽侾俀俁係俆俇俈俉俋俌俍俒俓俔俕俖俙俛俠俢俤俥俧俫俬俰俲俴俵俶俷俹俻俼俽俿倀倁倂倃倄倅倆倇倈倉倊\0個倎倐們倓倕倖倗倛倝倞倠倢倣値倧倫倯倰倱倲倳倴倵倶倷倸倹倻倽倿偀偁偂偄偅偆偉偊偋偍偐偑偒偓偔偖偗偘偙偛偝偞偟偠偡偢偣偤偦偧偨偩偪偫偭偮偯偰偱偲偳側偵偸偹偺偼偽傁傂傃傄傆傇傉傊傋傌傎傏傐傑傒傓傔傕傖傗傘備傚傛傜傝傞傟傠傡傢傤傦傪傫傭傮傯傰傱傳傴債傶傷傸傹傼",
+        "傽傾傿僀僁僂僃僄僅僆僇僈僉僊僋僌働僎僐僑僒僓僔僕僗僘僙僛僜僝僞僟僠僡僢僣僤僥僨僩僪僫僯僰僱僲僴僶僷僸價僺僼僽僾僿儀儁儂儃億儅儈\0儉儊儌儍儎儏儐儑儓儔儕儖儗儘儙儚儛儜儝儞償儠儢儣儤儥儦儧儨儩優儫儬儭儮儯儰儱儲儳儴儵儶儷儸儹儺儻儼儽儾兂兇兊兌兎兏児兒兓兗兘兙兛兝兞兟兠兡兣兤兦內兩兪兯兲兺兾兿冃冄円冇冊冋冎冏冐冑冓冔冘冚冝冞冟冡冣冦冧冨冩冪冭冮冴冸冹冺冾冿凁凂凃凅凈凊凍凎凐凒凓凔凕凖凗",
+        "凘凙凚凜凞凟凢凣凥処凧凨凩凪凬凮凱凲凴凷凾刄刅刉刋刌刏刐刓刔刕刜刞刟刡刢刣別刦刧刪刬刯刱刲刴刵刼刾剄剅剆則剈剉剋剎剏剒剓剕剗剘\0剙剚剛剝剟剠剢剣剤剦剨剫剬剭剮剰剱剳剴創剶剷剸剹剺剻剼剾劀劃劄劅劆劇劉劊劋劌劍劎劏劑劒劔劕劖劗劘劙劚劜劤劥劦劧劮劯劰労劵劶劷劸効劺劻劼劽勀勁勂勄勅勆勈勊勌勍勎勏勑勓勔動勗務勚勛勜勝勞勠勡勢勣勥勦勧勨勩勪勫勬勭勮勯勱勲勳勴勵勶勷勸勻勼勽匁匂匃匄匇匉匊匋匌匎",
+        "匑匒匓匔匘匛匜匞匟匢匤匥匧匨匩匫匬匭匯匰匱匲匳匴匵匶匷匸匼匽區卂卄卆卋卌卍卐協単卙卛卝卥卨卪卬卭卲卶卹卻卼卽卾厀厁厃厇厈厊厎厏\0厐厑厒厓厔厖厗厙厛厜厞厠厡厤厧厪厫厬厭厯厰厱厲厳厴厵厷厸厹厺厼厽厾叀參叄叅叆叇収叏叐叒叓叕叚叜叝叞叡叢叧叴叺叾叿吀吂吅吇吋吔吘吙吚吜吢吤吥吪吰吳吶吷吺吽吿呁呂呄呅呇呉呌呍呎呏呑呚呝呞呟呠呡呣呥呧呩呪呫呬呭呮呯呰呴呹呺呾呿咁咃咅咇咈咉咊咍咑咓咗咘咜咞咟咠咡",
+        "咢咥咮咰咲咵咶咷咹咺咼咾哃哅哊哋哖哘哛哠員哢哣哤哫哬哯哰哱哴哵哶哷哸哹哻哾唀唂唃唄唅唈唊唋唌唍唎唒唓唕唖唗唘唙唚唜唝唞唟唡唥唦\0唨唩唫唭唲唴唵唶唸唹唺唻唽啀啂啅啇啈啋啌啍啎問啑啒啓啔啗啘啙啚啛啝啞啟啠啢啣啨啩啫啯啰啱啲啳啴啹啺啽啿喅喆喌喍喎喐喒喓喕喖喗喚喛喞喠喡喢喣喤喥喦喨喩喪喫喬喭單喯喰喲喴営喸喺喼喿嗀嗁嗂嗃嗆嗇嗈嗊嗋嗎嗏嗐嗕嗗嗘嗙嗚嗛嗞嗠嗢嗧嗩嗭嗮嗰嗱嗴嗶嗸嗹嗺嗻嗼嗿嘂嘃嘄嘅",
+        "嘆嘇嘊嘋嘍嘐嘑嘒嘓嘔嘕嘖嘗嘙嘚嘜嘝嘠嘡嘢嘥嘦嘨嘩嘪嘫嘮嘯嘰嘳嘵嘷嘸嘺嘼嘽嘾噀噁噂噃噄噅噆噇噈噉噊噋噏噐噑噒噓噕噖噚噛噝噞噟噠噡\0噣噥噦噧噭噮噯噰噲噳噴噵噷噸噹噺噽噾噿嚀嚁嚂嚃嚄嚇嚈嚉嚊嚋嚌嚍嚐嚑嚒嚔嚕嚖嚗嚘嚙嚚嚛嚜嚝嚞嚟嚠嚡嚢嚤嚥嚦嚧嚨嚩嚪嚫嚬嚭嚮嚰嚱嚲嚳嚴嚵嚶嚸嚹嚺嚻嚽嚾嚿囀囁囂囃囄囅囆囇囈囉囋囌囍囎囏囐囑囒囓囕囖囘囙囜団囥囦囧囨囩囪囬囮囯囲図囶囷囸囻囼圀圁圂圅圇國圌圍圎圏圐圑",
+        "園圓圔圕圖圗團圙圚圛圝圞圠圡圢圤圥圦圧圫圱圲圴圵圶圷圸圼圽圿坁坃坄坅坆坈坉坋坒坓坔坕坖坘坙坢坣坥坧坬坮坰坱坲坴坵坸坹坺坽坾坿垀\0垁垇垈垉垊垍垎垏垐垑垔垕垖垗垘垙垚垜垝垞垟垥垨垪垬垯垰垱垳垵垶垷垹垺垻垼垽垾垿埀埁埄埅埆埇埈埉埊埌埍埐埑埓埖埗埛埜埞埡埢埣埥埦埧埨埩埪埫埬埮埰埱埲埳埵埶執埻埼埾埿堁堃堄堅堈堉堊堌堎堏堐堒堓堔堖堗堘堚堛堜堝堟堢堣堥堦堧堨堩堫堬堭堮堯報堲堳場堶堷堸堹堺堻堼堽",
+        "堾堿塀塁塂塃塅塆塇塈塉塊塋塎塏塐塒塓塕塖塗塙塚塛塜塝塟塠塡塢塣塤塦塧塨塩塪塭塮塯塰塱塲塳塴塵塶塷塸塹塺塻塼塽塿墂墄墆墇墈墊墋墌\0墍墎墏墐墑墔墕墖増墘墛墜墝墠墡墢墣墤墥墦墧墪墫墬墭墮墯墰墱墲墳墴墵墶墷墸墹墺墻墽墾墿壀壂壃壄壆壇壈壉壊壋壌壍壎壏壐壒壓壔壖壗壘壙壚壛壜壝壞壟壠壡壢壣壥壦壧壨壩壪壭壯壱売壴壵壷壸壺壻壼壽壾壿夀夁夃夅夆夈変夊夋夌夎夐夑夒夓夗夘夛夝夞夠夡夢夣夦夨夬夰夲夳夵夶夻",
+        "夽夾夿奀奃奅奆奊奌奍奐奒奓奙奛奜奝奞奟奡奣奤奦奧奨奩奪奫奬奭奮奯奰奱奲奵奷奺奻奼奾奿妀妅妉妋妌妎妏妐妑妔妕妘妚妛妜妝妟妠妡妢妦\0妧妬妭妰妱妳妴妵妶妷妸妺妼妽妿姀姁姂姃姄姅姇姈姉姌姍姎姏姕姖姙姛姞姟姠姡姢姤姦姧姩姪姫姭姮姯姰姱姲姳姴姵姶姷姸姺姼姽姾娀娂娊娋娍娎娏娐娒娔娕娖娗娙娚娛娝娞娡娢娤娦娧娨娪娫娬娭娮娯娰娳娵娷娸娹娺娻娽娾娿婁婂婃婄婅婇婈婋婌婍婎婏婐婑婒婓婔婖婗婘婙婛婜婝婞婟婠",
+        "婡婣婤婥婦婨婩婫婬婭婮婯婰婱婲婳婸婹婻婼婽婾媀媁媂媃媄媅媆媇媈媉媊媋媌媍媎媏媐媑媓媔媕媖媗媘媙媜媝媞媟媠媡媢媣媤媥媦媧媨媩媫媬\0媭媮媯媰媱媴媶媷媹媺媻媼媽媿嫀嫃嫄嫅嫆嫇嫈嫊嫋嫍嫎嫏嫐嫑嫓嫕嫗嫙嫚嫛嫝嫞嫟嫢嫤嫥嫧嫨嫪嫬嫭嫮嫯嫰嫲嫳嫴嫵嫶嫷嫸嫹嫺嫻嫼嫽嫾嫿嬀嬁嬂嬃嬄嬅嬆嬇嬈嬊嬋嬌嬍嬎嬏嬐嬑嬒嬓嬔嬕嬘嬙嬚嬛嬜嬝嬞嬟嬠嬡嬢嬣嬤嬥嬦嬧嬨嬩嬪嬫嬬嬭嬮嬯嬰嬱嬳嬵嬶嬸嬹嬺嬻嬼嬽嬾嬿孁孂孃孄孅孆孇",
+        "孈孉孊孋孌孍孎孏孒孖孞孠孡孧孨孫孭孮孯孲孴孶孷學孹孻孼孾孿宂宆宊宍宎宐宑宒宔宖実宧宨宩宬宭宮宯宱宲宷宺宻宼寀寁寃寈寉寊寋寍寎寏\0寑寔寕寖寗寘寙寚寛寜寠寢寣實寧審寪寫寬寭寯寱寲寳寴寵寶寷寽対尀専尃尅將專尋尌對導尐尒尓尗尙尛尞尟尠尡尣尦尨尩尪尫尭尮尯尰尲尳尵尶尷屃屄屆屇屌屍屒屓屔屖屗屘屚屛屜屝屟屢層屧屨屩屪屫屬屭屰屲屳屴屵屶屷屸屻屼屽屾岀岃岄岅岆岇岉岊岋岎岏岒岓岕岝岞岟岠岡岤岥岦岧岨",
+        "岪岮岯岰岲岴岶岹岺岻岼岾峀峂峃峅峆峇峈峉峊峌峍峎峏峐峑峓峔峕峖峗峘峚峛峜峝峞峟峠峢峣峧峩峫峬峮峯峱峲峳峴峵島峷峸峹峺峼峽峾峿崀\0崁崄崅崈崉崊崋崌崍崏崐崑崒崓崕崗崘崙崚崜崝崟崠崡崢崣崥崨崪崫崬崯崰崱崲崳崵崶崷崸崹崺崻崼崿嵀嵁嵂嵃嵄嵅嵆嵈嵉嵍嵎嵏嵐嵑嵒嵓嵔嵕嵖嵗嵙嵚嵜嵞嵟嵠嵡嵢嵣嵤嵥嵦嵧嵨嵪嵭嵮嵰嵱嵲嵳嵵嵶嵷嵸嵹嵺嵻嵼嵽嵾嵿嶀嶁嶃嶄嶅嶆嶇嶈嶉嶊嶋嶌嶍嶎嶏嶐嶑嶒嶓嶔嶕嶖嶗嶘嶚嶛嶜嶞嶟嶠",
+        "嶡嶢嶣嶤嶥嶦嶧嶨嶩嶪嶫嶬嶭嶮嶯嶰嶱嶲嶳嶴嶵嶶嶸嶹嶺嶻嶼嶽嶾嶿巀巁巂巃巄巆巇巈巉巊巋巌巎巏巐巑巒巓巔巕巖巗巘巙巚巜巟巠巣巤巪巬巭\0巰巵巶巸巹巺巻巼巿帀帄帇帉帊帋帍帎帒帓帗帞帟帠帡帢帣帤帥帨帩帪師帬帯帰帲帳帴帵帶帹帺帾帿幀幁幃幆幇幈幉幊幋幍幎幏幐幑幒幓幖幗幘幙幚幜幝幟幠幣幤幥幦幧幨幩幪幫幬幭幮幯幰幱幵幷幹幾庁庂広庅庈庉庌庍庎庒庘庛庝庡庢庣庤庨庩庪庫庬庮庯庰庱庲庴庺庻庼庽庿廀廁廂廃廄廅",
+        "廆廇廈廋廌廍廎廏廐廔廕廗廘廙廚廜廝廞廟廠廡廢廣廤廥廦廧廩廫廬廭廮廯廰廱廲廳廵廸廹廻廼廽弅弆弇弉弌弍弎弐弒弔弖弙弚弜弝弞弡弢弣弤\0弨弫弬弮弰弲弳弴張弶強弸弻弽弾弿彁彂彃彄彅彆彇彈彉彊彋彌彍彎彏彑彔彙彚彛彜彞彟彠彣彥彧彨彫彮彯彲彴彵彶彸彺彽彾彿徃徆徍徎徏徑従徔徖徚徛徝從徟徠徢徣徤徥徦徧復徫徬徯徰徱徲徳徴徶徸徹徺徻徾徿忀忁忂忇忈忊忋忎忓忔忕忚忛応忞忟忢忣忥忦忨忩忬忯忰忲忳忴忶忷忹忺忼怇",
+        "怈怉怋怌怐怑怓怗怘怚怞怟怢怣怤怬怭怮怰怱怲怳怴怶怷怸怹怺怽怾恀恄恅恆恇恈恉恊恌恎恏恑恓恔恖恗恘恛恜恞恟恠恡恥恦恮恱恲恴恵恷恾悀\0悁悂悅悆悇悈悊悋悎悏悐悑悓悕悗悘悙悜悞悡悢悤悥悧悩悪悮悰悳悵悶悷悹悺悽悾悿惀惁惂惃惄惇惈惉惌惍惎惏惐惒惓惔惖惗惙惛惞惡惢惣惤惥惪惱惲惵惷惸惻惼惽惾惿愂愃愄愅愇愊愋愌愐愑愒愓愔愖愗愘愙愛愜愝愞愡愢愥愨愩愪愬愭愮愯愰愱愲愳愴愵愶愷愸愹愺愻愼愽愾慀慁慂慃慄慅慆",
+        "慇慉態慍慏慐慒慓慔慖慗慘慙慚慛慜慞慟慠慡慣慤慥慦慩慪慫慬慭慮慯慱慲慳慴慶慸慹慺慻慼慽慾慿憀憁憂憃憄憅憆憇憈憉憊憌憍憏憐憑憒憓憕\0憖憗憘憙憚憛憜憞憟憠憡憢憣憤憥憦憪憫憭憮憯憰憱憲憳憴憵憶憸憹憺憻憼憽憿懀懁懃懄懅懆懇應懌懍懎懏懐懓懕懖懗懘懙懚懛懜懝懞懟懠懡懢懣懤懥懧懨懩懪懫懬懭懮懯懰懱懲懳懴懶懷懸懹懺懻懼懽懾戀戁戂戃戄戅戇戉戓戔戙戜戝戞戠戣戦戧戨戩戫戭戯戰戱戲戵戶戸戹戺戻戼扂扄扅扆扊",
+        "扏扐払扖扗扙扚扜扝扞扟扠扡扢扤扥扨扱扲扴扵扷扸扺扻扽抁抂抃抅抆抇抈抋抌抍抎抏抐抔抙抜抝択抣抦抧抩抪抭抮抯抰抲抳抴抶抷抸抺抾拀拁\0拃拋拏拑拕拝拞拠拡拤拪拫拰拲拵拸拹拺拻挀挃挄挅挆挊挋挌挍挏挐挒挓挔挕挗挘挙挜挦挧挩挬挭挮挰挱挳挴挵挶挷挸挻挼挾挿捀捁捄捇捈捊捑捒捓捔捖捗捘捙捚捛捜捝捠捤捥捦捨捪捫捬捯捰捲捳捴捵捸捹捼捽捾捿掁掃掄掅掆掋掍掑掓掔掕掗掙掚掛掜掝掞掟採掤掦掫掯掱掲掵掶掹掻掽掿揀",
+        "揁揂揃揅揇揈揊揋揌揑揓揔揕揗揘揙揚換揜揝揟揢揤揥揦揧揨揫揬揮揯揰揱揳揵揷揹揺揻揼揾搃搄搆搇搈搉搊損搎搑搒搕搖搗搘搙搚搝搟搢搣搤\0搥搧搨搩搫搮搯搰搱搲搳搵搶搷搸搹搻搼搾摀摂摃摉摋摌摍摎摏摐摑摓摕摖摗摙摚摛摜摝摟摠摡摢摣摤摥摦摨摪摫摬摮摯摰摱摲摳摴摵摶摷摻摼摽摾摿撀撁撃撆撈撉撊撋撌撍撎撏撐撓撔撗撘撚撛撜撝撟撠撡撢撣撥撦撧撨撪撫撯撱撲撳撴撶撹撻撽撾撿擁擃擄擆擇擈擉擊擋擌擏擑擓擔擕擖擙據",
+        "擛擜擝擟擠擡擣擥擧擨擩擪擫擬擭擮擯擰擱擲擳擴擵擶擷擸擹擺擻擼擽擾擿攁攂攃攄攅攆攇攈攊攋攌攍攎攏攐攑攓攔攕攖攗攙攚攛攜攝攞攟攠攡\0攢攣攤攦攧攨攩攪攬攭攰攱攲攳攷攺攼攽敀敁敂敃敄敆敇敊敋敍敎敐敒敓敔敗敘敚敜敟敠敡敤敥敧敨敩敪敭敮敯敱敳敵敶數敹敺敻敼敽敾敿斀斁斂斃斄斅斆斈斉斊斍斎斏斒斔斕斖斘斚斝斞斠斢斣斦斨斪斬斮斱斲斳斴斵斶斷斸斺斻斾斿旀旂旇旈旉旊旍旐旑旓旔旕旘旙旚旛旜旝旞旟旡旣旤旪旫",
+        "旲旳旴旵旸旹旻旼旽旾旿昁昄昅昇昈昉昋昍昐昑昒昖昗昘昚昛昜昞昡昢昣昤昦昩昪昫昬昮昰昲昳昷昸昹昺昻昽昿晀時晄晅晆晇晈晉晊晍晎晐晑晘\0晙晛晜晝晞晠晢晣晥晧晩晪晫晬晭晱晲晳晵晸晹晻晼晽晿暀暁暃暅暆暈暉暊暋暍暎暏暐暒暓暔暕暘暙暚暛暜暞暟暠暡暢暣暤暥暦暩暪暫暬暭暯暰暱暲暳暵暶暷暸暺暻暼暽暿曀曁曂曃曄曅曆曇曈曉曊曋曌曍曎曏曐曑曒曓曔曕曖曗曘曚曞曟曠曡曢曣曤曥曧曨曪曫曬曭曮曯曱曵曶書曺曻曽朁朂會",
+        "朄朅朆朇朌朎朏朑朒朓朖朘朙朚朜朞朠朡朢朣朤朥朧朩朮朰朲朳朶朷朸朹朻朼朾朿杁杄杅杇杊杋杍杒杔杕杗杘杙杚杛杝杢杣杤杦杧杫杬杮東杴杶\0杸杹杺杻杽枀枂枃枅枆枈枊枌枍枎枏枑枒枓枔枖枙枛枟枠枡枤枦枩枬枮枱枲枴枹枺枻枼枽枾枿柀柂柅柆柇柈柉柊柋柌柍柎柕柖柗柛柟柡柣柤柦柧柨柪柫柭柮柲柵柶柷柸柹柺査柼柾栁栂栃栄栆栍栐栒栔栕栘栙栚栛栜栞栟栠栢栣栤栥栦栧栨栫栬栭栮栯栰栱栴栵栶栺栻栿桇桋桍桏桒桖桗桘桙桚桛",
+        "桜桝桞桟桪桬桭桮桯桰桱桲桳桵桸桹桺桻桼桽桾桿梀梂梄梇梈梉梊梋梌梍梎梐梑梒梔梕梖梘梙梚梛梜條梞梟梠梡梣梤梥梩梪梫梬梮梱梲梴梶梷梸\0梹梺梻梼梽梾梿棁棃棄棅棆棇棈棊棌棎棏棐棑棓棔棖棗棙棛棜棝棞棟棡棢棤棥棦棧棨棩棪棫棬棭棯棲棳棴棶棷棸棻棽棾棿椀椂椃椄椆椇椈椉椊椌椏椑椓椔椕椖椗椘椙椚椛検椝椞椡椢椣椥椦椧椨椩椪椫椬椮椯椱椲椳椵椶椷椸椺椻椼椾楀楁楃楄楅楆楇楈楉楊楋楌楍楎楏楐楑楒楓楕楖楘楙楛楜楟",
+        "楡楢楤楥楧楨楩楪楬業楯楰楲楳楴極楶楺楻楽楾楿榁榃榅榊榋榌榎榏榐榑榒榓榖榗榙榚榝榞榟榠榡榢榣榤榥榦榩榪榬榮榯榰榲榳榵榶榸榹榺榼榽\0榾榿槀槂槃槄槅槆槇槈槉構槍槏槑槒槓槕槖槗様槙槚槜槝槞槡槢槣槤槥槦槧槨槩槪槫槬槮槯槰槱槳槴槵槶槷槸槹槺槻槼槾樀樁樂樃樄樅樆樇樈樉樋樌樍樎樏樐樑樒樓樔樕樖標樚樛樜樝樞樠樢樣樤樥樦樧権樫樬樭樮樰樲樳樴樶樷樸樹樺樻樼樿橀橁橂橃橅橆橈橉橊橋橌橍橎橏橑橒橓橔橕橖橗橚",
+        "橜橝橞機橠橢橣橤橦橧橨橩橪橫橬橭橮橯橰橲橳橴橵橶橷橸橺橻橽橾橿檁檂檃檅檆檇檈檉檊檋檌檍檏檒檓檔檕檖檘檙檚檛檜檝檞檟檡檢檣檤檥檦\0檧檨檪檭檮檯檰檱檲檳檴檵檶檷檸檹檺檻檼檽檾檿櫀櫁櫂櫃櫄櫅櫆櫇櫈櫉櫊櫋櫌櫍櫎櫏櫐櫑櫒櫓櫔櫕櫖櫗櫘櫙櫚櫛櫜櫝櫞櫟櫠櫡櫢櫣櫤櫥櫦櫧櫨櫩櫪櫫櫬櫭櫮櫯櫰櫱櫲櫳櫴櫵櫶櫷櫸櫹櫺櫻櫼櫽櫾櫿欀欁欂欃欄欅欆欇欈欉權欋欌欍欎欏欐欑欒欓欔欕欖欗欘欙欚欛欜欝欞欟欥欦欨欩欪欫欬欭欮",
+        "欯欰欱欳欴欵欶欸欻欼欽欿歀歁歂歄歅歈歊歋歍歎歏歐歑歒歓歔歕歖歗歘歚歛歜歝歞歟歠歡歨歩歫歬歭歮歯歰歱歲歳歴歵歶歷歸歺歽歾歿殀殅殈\0殌殎殏殐殑殔殕殗殘殙殜殝殞殟殠殢殣殤殥殦殧殨殩殫殬殭殮殯殰殱殲殶殸殹殺殻殼殽殾毀毃毄毆毇毈毉毊毌毎毐毑毘毚毜毝毞毟毠毢毣毤毥毦毧毨毩毬毭毮毰毱毲毴毶毷毸毺毻毼毾毿氀氁氂氃氄氈氉氊氋氌氎氒気氜氝氞氠氣氥氫氬氭氱氳氶氷氹氺氻氼氾氿汃汄汅汈汋汌汍汎汏汑汒汓汖汘",
+        "汙汚汢汣汥汦汧汫汬汭汮汯汱汳汵汷汸決汻汼汿沀沄沇沊沋沍沎沑沒沕沖沗沘沚沜沝沞沠沢沨沬沯沰沴沵沶沷沺泀況泂泃泆泇泈泋泍泎泏泑泒泘\0泙泚泜泝泟泤泦泧泩泬泭泲泴泹泿洀洂洃洅洆洈洉洊洍洏洐洑洓洔洕洖洘洜洝洟洠洡洢洣洤洦洨洩洬洭洯洰洴洶洷洸洺洿浀浂浄浉浌浐浕浖浗浘浛浝浟浡浢浤浥浧浨浫浬浭浰浱浲浳浵浶浹浺浻浽浾浿涀涁涃涄涆涇涊涋涍涏涐涒涖涗涘涙涚涜涢涥涬涭涰涱涳涴涶涷涹涺涻涼涽涾淁淂淃淈淉淊",
+        "淍淎淏淐淒淓淔淕淗淚淛淜淟淢淣淥淧淨淩淪淭淯淰淲淴淵淶淸淺淽淾淿渀渁渂渃渄渆渇済渉渋渏渒渓渕渘渙減渜渞渟渢渦渧渨渪測渮渰渱渳渵\0渶渷渹渻渼渽渾渿湀湁湂湅湆湇湈湉湊湋湌湏湐湑湒湕湗湙湚湜湝湞湠湡湢湣湤湥湦湧湨湩湪湬湭湯湰湱湲湳湴湵湶湷湸湹湺湻湼湽満溁溂溄溇溈溊溋溌溍溎溑溒溓溔溕準溗溙溚溛溝溞溠溡溣溤溦溨溩溫溬溭溮溰溳溵溸溹溼溾溿滀滃滄滅滆滈滉滊滌滍滎滐滒滖滘滙滛滜滝滣滧滪滫滬滭滮滯",
+        "滰滱滲滳滵滶滷滸滺滻滼滽滾滿漀漁漃漄漅漇漈漊漋漌漍漎漐漑漒漖漗漘漙漚漛漜漝漞漟漡漢漣漥漦漧漨漬漮漰漲漴漵漷漸漹漺漻漼漽漿潀潁潂\0潃潄潅潈潉潊潌潎潏潐潑潒潓潔潕潖潗潙潚潛潝潟潠潡潣潤潥潧潨潩潪潫潬潯潰潱潳潵潶潷潹潻潽潾潿澀澁澂澃澅澆澇澊澋澏澐澑澒澓澔澕澖澗澘澙澚澛澝澞澟澠澢澣澤澥澦澨澩澪澫澬澭澮澯澰澱澲澴澵澷澸澺澻澼澽澾澿濁濃濄濅濆濇濈濊濋濌濍濎濏濐濓濔濕濖濗濘濙濚濛濜濝濟濢濣濤濥",
+        "濦濧濨濩濪濫濬濭濰濱濲濳濴濵濶濷濸濹濺濻濼濽濾濿瀀瀁瀂瀃瀄瀅瀆瀇瀈瀉瀊瀋瀌瀍瀎瀏瀐瀒瀓瀔瀕瀖瀗瀘瀙瀜瀝瀞瀟瀠瀡瀢瀤瀥瀦瀧瀨瀩瀪\0瀫瀬瀭瀮瀯瀰瀱瀲瀳瀴瀶瀷瀸瀺瀻瀼瀽瀾瀿灀灁灂灃灄灅灆灇灈灉灊灋灍灎灐灑灒灓灔灕灖灗灘灙灚灛灜灝灟灠灡灢灣灤灥灦灧灨灩灪灮灱灲灳灴灷灹灺灻災炁炂炃炄炆炇炈炋炌炍炏炐炑炓炗炘炚炛炞炟炠炡炢炣炤炥炦炧炨炩炪炰炲炴炵炶為炾炿烄烅烆烇烉烋烌烍烎烏烐烑烒烓烔烕烖烗烚",
+        "烜烝烞烠烡烢烣烥烪烮烰烱烲烳烴烵烶烸烺烻烼烾烿焀焁焂焃焄焅焆焇焈焋焌焍焎焏焑焒焔焗焛焜焝焞焟焠無焢焣焤焥焧焨焩焪焫焬焭焮焲焳焴\0焵焷焸焹焺焻焼焽焾焿煀煁煂煃煄煆煇煈煉煋煍煏煐煑煒煓煔煕煖煗煘煙煚煛煝煟煠煡煢煣煥煩煪煫煬煭煯煰煱煴煵煶煷煹煻煼煾煿熀熁熂熃熅熆熇熈熉熋熌熍熎熐熑熒熓熕熖熗熚熛熜熝熞熡熢熣熤熥熦熧熩熪熫熭熮熯熰熱熲熴熶熷熸熺熻熼熽熾熿燀燁燂燄燅燆燇燈燉燊燋燌燍燏燐燑燒燓",
+        "燖燗燘燙燚燛燜燝燞營燡燢燣燤燦燨燩燪燫燬燭燯燰燱燲燳燴燵燶燷燸燺燻燼燽燾燿爀爁爂爃爄爅爇爈爉爊爋爌爍爎爏爐爑爒爓爔爕爖爗爘爙爚\0爛爜爞爟爠爡爢爣爤爥爦爧爩爫爭爮爯爲爳爴爺爼爾牀牁牂牃牄牅牆牉牊牋牎牏牐牑牓牔牕牗牘牚牜牞牠牣牤牥牨牪牫牬牭牰牱牳牴牶牷牸牻牼牽犂犃犅犆犇犈犉犌犎犐犑犓犔犕犖犗犘犙犚犛犜犝犞犠犡犢犣犤犥犦犧犨犩犪犫犮犱犲犳犵犺犻犼犽犾犿狀狅狆狇狉狊狋狌狏狑狓狔狕狖狘狚狛",
+        "\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\u{3000}、。·ˉˇ¨〃々—～‖…‘’“”〔〕〈〉《》「」『』〖〗【】±×÷∶∧∨∑∏∪∩∈∷√⊥∥∠⌒⊙∫∮≡≌≈∽∝≠≮≯≤≥∞∵∴♂♀°′″℃＄¤￠￡‰§№☆★○●◎◇◆□■△▲※→←↑↓〓",
+        "\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0ⅰⅱⅲⅳⅴⅵⅶⅷⅸⅹ\0\0\0\0\0\0⒈⒉⒊⒋⒌⒍⒎⒏⒐⒑⒒⒓⒔⒕⒖⒗⒘⒙⒚⒛⑴⑵⑶⑷⑸⑹⑺⑻⑼⑽⑾⑿⒀⒁⒂⒃⒄⒅⒆⒇①②③④⑤⑥⑦⑧⑨⑩\0\0㈠㈡㈢㈣㈤㈥㈦㈧㈨㈩\0\0ⅠⅡⅢⅣⅤⅥⅦⅧⅨⅩⅪⅫ\0\0",
+        "\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0！＂＃￥％＆＇（）＊＋，－．／０１２３４５６７８９：；＜＝＞？＠ＡＢＣＤＥＦＧＨＩＪＫＬＭＮＯＰＱＲＳＴＵＶＷＸＹＺ［＼］＾＿｀ａｂｃｄｅｆｇｈｉｊｋｌｍｎｏｐｑｒｓｔｕｖｗｘｙｚ｛｜｝￣",
+        "\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0ぁあぃいぅうぇえぉおかがきぎくぐけげこごさざしじすずせぜそぞただちぢっつづてでとどなにぬねのはばぱひびぴふぶぷへべぺほぼぽまみむめもゃやゅゆょよらりるれろゎわゐゑをん\0\0\0\0\0\0\0\0\0\0\0",
+        "\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0ァアィイゥウェエォオカガキギクグケゲコゴサザシジスズセゼソゾタダチヂッツヅテデトドナニヌネノハバパヒビピフブプヘベペホボポマミムメモャヤュユョヨラリルレロヮワヰヱヲンヴヵヶ\0\0\0\0\0\0\0\0",
+        "\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0ΑΒΓΔΕΖΗΘΙΚΛΜΝΞΟΠΡΣΤΥΦΧΨΩ\0\0\0\0\0\0\0\0αβγδεζηθικλμνξοπρστυφχψω\0\0\0\0\0\0\0︵︶︹︺︿﹀︽︾﹁﹂﹃﹄\0\0︻︼︷︸︱\0︳︴\0\0\0\0\0\0\0\0\0",
+        "\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0АБВГДЕЁЖЗИЙКЛМНОПРСТУФХЦЧШЩЪЫЬЭЮЯ\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0абвгдеёжзийклмнопрстуфхцчшщъыьэюя\0\0\0\0\0\0\0\0\0\0\0\0\0",
+        "ˊˋ˙–―‥‵℅℉↖↗↘↙∕∟∣≒≦≧⊿═║╒╓╔╕╖╗╘╙╚╛╜╝╞╟╠╡╢╣╤╥╦╧╨╩╪╫╬╭╮╯╰╱╲╳▁▂▃▄▅▆▇\0█▉▊▋▌▍▎▏▓▔▕▼▽◢◣◤◥☉⊕〒〝〞\0\0\0\0\0\0\0\0\0\0\0āáǎàēéěèīíǐìōóǒòūúǔùǖǘǚǜüêɑ\0ńň\0ɡ\0\0\0\0ㄅㄆㄇㄈㄉㄊㄋㄌㄍㄎㄏㄐㄑㄒㄓㄔㄕㄖㄗㄘㄙㄚㄛㄜㄝㄞㄟㄠㄡㄢㄣㄤㄥㄦㄧㄨㄩ\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0",
+        "〡〢〣〤〥〦〧〨〩㊣㎎㎏㎜㎝㎞㎡㏄㏎㏑㏒㏕︰￢￤\0℡㈱\0‐\0\0\0ー゛゜ヽヾ〆ゝゞ﹉﹊﹋﹌﹍﹎﹏﹐﹑﹒﹔﹕﹖﹗﹙﹚﹛﹜﹝﹞﹟﹠﹡\0﹢﹣﹤﹥﹦﹨﹩﹪﹫\0\0\0\0\0\0\0\0\0\0\0\0\0〇\0\0\0\0\0\0\0\0\0\0\0\0\0─━│┃┄┅┆┇┈┉┊┋┌┍┎┏┐┑┒┓└┕┖┗┘┙┚┛├┝┞┟┠┡┢┣┤┥┦┧┨┩┪┫┬┭┮┯┰┱┲┳┴┵┶┷┸┹┺┻┼┽┾┿╀╁╂╃╄╅╆╇╈╉╊╋\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0",
+        "狜狝狟狢狣狤狥狦狧狪狫狵狶狹狽狾狿猀猂猄猅猆猇猈猉猋猌猍猏猐猑猒猔猘猙猚猟猠猣猤猦猧猨猭猯猰猲猳猵猶猺猻猼猽獀獁獂獃獄獅獆獇獈\0獉獊獋獌獎獏獑獓獔獕獖獘獙獚獛獜獝獞獟獡獢獣獤獥獦獧獨獩獪獫獮獰獱\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0",
+        "獲獳獴獵獶獷獸獹獺獻獼獽獿玀玁玂玃玅玆玈玊玌玍玏玐玒玓玔玕玗玘玙玚玜玝玞玠玡玣玤玥玦玧玨玪玬玭玱玴玵玶玸玹玼玽玾玿珁珃珄珅珆珇\0珋珌珎珒珓珔珕珖珗珘珚珛珜珝珟珡珢珣珤珦珨珪珫珬珮珯珰珱珳珴珵珶珷\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0",
+        "珸珹珺珻珼珽現珿琀琁琂琄琇琈琋琌琍琎琑琒琓琔琕琖琗琘琙琜琝琞琟琠琡琣琤琧琩琫琭琯琱琲琷琸琹琺琻琽琾琿瑀瑂瑃瑄瑅瑆瑇瑈瑉瑊瑋瑌瑍\0瑎瑏瑐瑑瑒瑓瑔瑖瑘瑝瑠瑡瑢瑣瑤瑥瑦瑧瑨瑩瑪瑫瑬瑮瑯瑱瑲瑳瑴瑵瑸瑹瑺\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0",
+        "瑻瑼瑽瑿璂璄璅璆璈璉璊璌璍璏璑璒璓璔璕璖璗璘璙璚璛璝璟璠璡璢璣璤璥璦璪璫璬璭璮璯環璱璲璳璴璵璶璷璸璹璻璼璽璾璿瓀瓁瓂瓃瓄瓅瓆瓇\0瓈瓉瓊瓋瓌瓍瓎瓏瓐瓑瓓瓔瓕瓖瓗瓘瓙瓚瓛瓝瓟瓡瓥瓧瓨瓩瓪瓫瓬瓭瓰瓱瓲\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0",
+        "瓳瓵瓸瓹瓺瓻瓼瓽瓾甀甁甂甃甅甆甇甈甉甊甋甌甎甐甒甔甕甖甗甛甝甞甠甡產産甤甦甧甪甮甴甶甹甼甽甿畁畂畃畄畆畇畉畊畍畐畑畒畓畕畖畗畘\0畝畞畟畠畡畢畣畤畧畨畩畫畬畭畮畯異畱畳畵當畷畺畻畼畽畾疀疁疂疄疅疇\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0",
+        "疈疉疊疌疍疎疐疓疕疘疛疜疞疢疦疧疨疩疪疭疶疷疺疻疿痀痁痆痋痌痎痏痐痑痓痗痙痚痜痝痟痠痡痥痩痬痭痮痯痲痳痵痶痷痸痺痻痽痾瘂瘄瘆瘇\0瘈瘉瘋瘍瘎瘏瘑瘒瘓瘔瘖瘚瘜瘝瘞瘡瘣瘧瘨瘬瘮瘯瘱瘲瘶瘷瘹瘺瘻瘽癁療癄\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0",
+        "癅癆癇癈癉癊癋癎癏癐癑癒癓癕癗癘癙癚癛癝癟癠癡癢癤癥癦癧癨癩癪癬癭癮癰癱癲癳癴癵癶癷癹発發癿皀皁皃皅皉皊皌皍皏皐皒皔皕皗皘皚皛\0皜皝皞皟皠皡皢皣皥皦皧皨皩皪皫皬皭皯皰皳皵皶皷皸皹皺皻皼皽皾盀盁盃啊阿埃挨哎唉哀皑癌蔼矮艾碍爱隘鞍氨安俺按暗岸胺案肮昂盎凹敖熬翱袄傲奥懊澳芭捌扒叭吧笆八疤巴拔跋靶把耙坝霸罢爸白柏百摆佰败拜稗斑班搬扳般颁板版扮拌伴瓣半办绊邦帮梆榜膀绑棒磅蚌镑傍谤苞胞包褒剥",
+        "盄盇盉盋盌盓盕盙盚盜盝盞盠盡盢監盤盦盧盨盩盪盫盬盭盰盳盵盶盷盺盻盽盿眀眂眃眅眆眊県眎眏眐眑眒眓眔眕眖眗眘眛眜眝眞眡眣眤眥眧眪眫\0眬眮眰眱眲眳眴眹眻眽眾眿睂睄睅睆睈睉睊睋睌睍睎睏睒睓睔睕睖睗睘睙睜薄雹保堡饱宝抱报暴豹鲍爆杯碑悲卑北辈背贝钡倍狈备惫焙被奔苯本笨崩绷甭泵蹦迸逼鼻比鄙笔彼碧蓖蔽毕毙毖币庇痹闭敝弊必辟壁臂避陛鞭边编贬扁便变卞辨辩辫遍标彪膘表鳖憋别瘪彬斌濒滨宾摈兵冰柄丙秉饼炳",
+        "睝睞睟睠睤睧睩睪睭睮睯睰睱睲睳睴睵睶睷睸睺睻睼瞁瞂瞃瞆瞇瞈瞉瞊瞋瞏瞐瞓瞔瞕瞖瞗瞘瞙瞚瞛瞜瞝瞞瞡瞣瞤瞦瞨瞫瞭瞮瞯瞱瞲瞴瞶瞷瞸瞹瞺\0瞼瞾矀矁矂矃矄矅矆矇矈矉矊矋矌矎矏矐矑矒矓矔矕矖矘矙矚矝矞矟矠矡矤病并玻菠播拨钵波博勃搏铂箔伯帛舶脖膊渤泊驳捕卜哺补埠不布步簿部怖擦猜裁材才财睬踩采彩菜蔡餐参蚕残惭惨灿苍舱仓沧藏操糙槽曹草厕策侧册测层蹭插叉茬茶查碴搽察岔差诧拆柴豺搀掺蝉馋谗缠铲产阐颤昌猖",
+        "矦矨矪矯矰矱矲矴矵矷矹矺矻矼砃砄砅砆砇砈砊砋砎砏砐砓砕砙砛砞砠砡砢砤砨砪砫砮砯砱砲砳砵砶砽砿硁硂硃硄硆硈硉硊硋硍硏硑硓硔硘硙硚\0硛硜硞硟硠硡硢硣硤硥硦硧硨硩硯硰硱硲硳硴硵硶硸硹硺硻硽硾硿碀碁碂碃场尝常长偿肠厂敞畅唱倡超抄钞朝嘲潮巢吵炒车扯撤掣彻澈郴臣辰尘晨忱沉陈趁衬撑称城橙成呈乘程惩澄诚承逞骋秤吃痴持匙池迟弛驰耻齿侈尺赤翅斥炽充冲虫崇宠抽酬畴踌稠愁筹仇绸瞅丑臭初出橱厨躇锄雏滁除楚",
+        "碄碅碆碈碊碋碏碐碒碔碕碖碙碝碞碠碢碤碦碨碩碪碫碬碭碮碯碵碶碷碸確碻碼碽碿磀磂磃磄磆磇磈磌磍磎磏磑磒磓磖磗磘磚磛磜磝磞磟磠磡磢磣\0磤磥磦磧磩磪磫磭磮磯磰磱磳磵磶磸磹磻磼磽磾磿礀礂礃礄礆礇礈礉礊礋礌础储矗搐触处揣川穿椽传船喘串疮窗幢床闯创吹炊捶锤垂春椿醇唇淳纯蠢戳绰疵茨磁雌辞慈瓷词此刺赐次聪葱囱匆从丛凑粗醋簇促蹿篡窜摧崔催脆瘁粹淬翠村存寸磋撮搓措挫错搭达答瘩打大呆歹傣戴带殆代贷袋待逮",
+        "礍礎礏礐礑礒礔礕礖礗礘礙礚礛礜礝礟礠礡礢礣礥礦礧礨礩礪礫礬礭礮礯礰礱礲礳礵礶礷礸礹礽礿祂祃祄祅祇祊祋祌祍祎祏祐祑祒祔祕祘祙祡祣\0祤祦祩祪祫祬祮祰祱祲祳祴祵祶祹祻祼祽祾祿禂禃禆禇禈禉禋禌禍禎禐禑禒怠耽担丹单郸掸胆旦氮但惮淡诞弹蛋当挡党荡档刀捣蹈倒岛祷导到稻悼道盗德得的蹬灯登等瞪凳邓堤低滴迪敌笛狄涤翟嫡抵底地蒂第帝弟递缔颠掂滇碘点典靛垫电佃甸店惦奠淀殿碉叼雕凋刁掉吊钓调跌爹碟蝶迭谍叠",
+        "禓禔禕禖禗禘禙禛禜禝禞禟禠禡禢禣禤禥禦禨禩禪禫禬禭禮禯禰禱禲禴禵禶禷禸禼禿秂秄秅秇秈秊秌秎秏秐秓秔秖秗秙秚秛秜秝秞秠秡秢秥秨秪\0秬秮秱秲秳秴秵秶秷秹秺秼秾秿稁稄稅稇稈稉稊稌稏稐稑稒稓稕稖稘稙稛稜丁盯叮钉顶鼎锭定订丢东冬董懂动栋侗恫冻洞兜抖斗陡豆逗痘都督毒犊独读堵睹赌杜镀肚度渡妒端短锻段断缎堆兑队对墩吨蹲敦顿囤钝盾遁掇哆多夺垛躲朵跺舵剁惰堕蛾峨鹅俄额讹娥恶厄扼遏鄂饿恩而儿耳尔饵洱二",
+        "稝稟稡稢稤稥稦稧稨稩稪稫稬稭種稯稰稱稲稴稵稶稸稺稾穀穁穂穃穄穅穇穈穉穊穋穌積穎穏穐穒穓穔穕穖穘穙穚穛穜穝穞穟穠穡穢穣穤穥穦穧穨\0穩穪穫穬穭穮穯穱穲穳穵穻穼穽穾窂窅窇窉窊窋窌窎窏窐窓窔窙窚窛窞窡窢贰发罚筏伐乏阀法珐藩帆番翻樊矾钒繁凡烦反返范贩犯饭泛坊芳方肪房防妨仿访纺放菲非啡飞肥匪诽吠肺废沸费芬酚吩氛分纷坟焚汾粉奋份忿愤粪丰封枫蜂峰锋风疯烽逢冯缝讽奉凤佛否夫敷肤孵扶拂辐幅氟符伏俘服",
+        "窣窤窧窩窪窫窮窯窰窱窲窴窵窶窷窸窹窺窻窼窽窾竀竁竂竃竄竅竆竇竈竉竊竌竍竎竏竐竑竒竓竔竕竗竘竚竛竜竝竡竢竤竧竨竩竪竫竬竮竰竱竲竳\0竴竵競竷竸竻竼竾笀笁笂笅笇笉笌笍笎笐笒笓笖笗笘笚笜笝笟笡笢笣笧笩笭浮涪福袱弗甫抚辅俯釜斧脯腑府腐赴副覆赋复傅付阜父腹负富讣附妇缚咐噶嘎该改概钙盖溉干甘杆柑竿肝赶感秆敢赣冈刚钢缸肛纲岗港杠篙皋高膏羔糕搞镐稿告哥歌搁戈鸽胳疙割革葛格蛤阁隔铬个各给根跟耕更庚羹",
+        "笯笰笲笴笵笶笷笹笻笽笿筀筁筂筃筄筆筈筊筍筎筓筕筗筙筜筞筟筡筣筤筥筦筧筨筩筪筫筬筭筯筰筳筴筶筸筺筼筽筿箁箂箃箄箆箇箈箉箊箋箌箎箏\0箑箒箓箖箘箙箚箛箞箟箠箣箤箥箮箯箰箲箳箵箶箷箹箺箻箼箽箾箿節篂篃範埂耿梗工攻功恭龚供躬公宫弓巩汞拱贡共钩勾沟苟狗垢构购够辜菇咕箍估沽孤姑鼓古蛊骨谷股故顾固雇刮瓜剐寡挂褂乖拐怪棺关官冠观管馆罐惯灌贯光广逛瑰规圭硅归龟闺轨鬼诡癸桂柜跪贵刽辊滚棍锅郭国果裹过哈",
+        "篅篈築篊篋篍篎篏篐篒篔篕篖篗篘篛篜篞篟篠篢篣篤篧篨篩篫篬篭篯篰篲篳篴篵篶篸篹篺篻篽篿簀簁簂簃簄簅簆簈簉簊簍簎簐簑簒簓簔簕簗簘簙\0簚簛簜簝簞簠簡簢簣簤簥簨簩簫簬簭簮簯簰簱簲簳簴簵簶簷簹簺簻簼簽簾籂骸孩海氦亥害骇酣憨邯韩含涵寒函喊罕翰撼捍旱憾悍焊汗汉夯杭航壕嚎豪毫郝好耗号浩呵喝荷菏核禾和何合盒貉阂河涸赫褐鹤贺嘿黑痕很狠恨哼亨横衡恒轰哄烘虹鸿洪宏弘红喉侯猴吼厚候后呼乎忽瑚壶葫胡蝴狐糊湖",
+        "籃籄籅籆籇籈籉籊籋籌籎籏籐籑籒籓籔籕籖籗籘籙籚籛籜籝籞籟籠籡籢籣籤籥籦籧籨籩籪籫籬籭籮籯籰籱籲籵籶籷籸籹籺籾籿粀粁粂粃粄粅粆粇\0粈粊粋粌粍粎粏粐粓粔粖粙粚粛粠粡粣粦粧粨粩粫粬粭粯粰粴粵粶粷粸粺粻弧虎唬护互沪户花哗华猾滑画划化话槐徊怀淮坏欢环桓还缓换患唤痪豢焕涣宦幻荒慌黄磺蝗簧皇凰惶煌晃幌恍谎灰挥辉徽恢蛔回毁悔慧卉惠晦贿秽会烩汇讳诲绘荤昏婚魂浑混豁活伙火获或惑霍货祸击圾基机畸稽积箕",
+        "粿糀糂糃糄糆糉糋糎糏糐糑糒糓糔糘糚糛糝糞糡糢糣糤糥糦糧糩糪糫糬糭糮糰糱糲糳糴糵糶糷糹糺糼糽糾糿紀紁紂紃約紅紆紇紈紉紋紌納紎紏紐\0紑紒紓純紕紖紗紘紙級紛紜紝紞紟紡紣紤紥紦紨紩紪紬紭紮細紱紲紳紴紵紶肌饥迹激讥鸡姬绩缉吉极棘辑籍集及急疾汲即嫉级挤几脊己蓟技冀季伎祭剂悸济寄寂计记既忌际妓继纪嘉枷夹佳家加荚颊贾甲钾假稼价架驾嫁歼监坚尖笺间煎兼肩艰奸缄茧检柬碱硷拣捡简俭剪减荐槛鉴践贱见键箭件",
+        "紷紸紹紺紻紼紽紾紿絀絁終絃組絅絆絇絈絉絊絋経絍絎絏結絑絒絓絔絕絖絗絘絙絚絛絜絝絞絟絠絡絢絣絤絥給絧絨絩絪絫絬絭絯絰統絲絳絴絵絶\0絸絹絺絻絼絽絾絿綀綁綂綃綄綅綆綇綈綉綊綋綌綍綎綏綐綑綒經綔綕綖綗綘健舰剑饯渐溅涧建僵姜将浆江疆蒋桨奖讲匠酱降蕉椒礁焦胶交郊浇骄娇嚼搅铰矫侥脚狡角饺缴绞剿教酵轿较叫窖揭接皆秸街阶截劫节桔杰捷睫竭洁结解姐戒藉芥界借介疥诫届巾筋斤金今津襟紧锦仅谨进靳晋禁近烬浸",
+        "継続綛綜綝綞綟綠綡綢綣綤綥綧綨綩綪綫綬維綯綰綱網綳綴綵綶綷綸綹綺綻綼綽綾綿緀緁緂緃緄緅緆緇緈緉緊緋緌緍緎総緐緑緒緓緔緕緖緗緘緙\0線緛緜緝緞緟締緡緢緣緤緥緦緧編緩緪緫緬緭緮緯緰緱緲緳練緵緶緷緸緹緺尽劲荆兢茎睛晶鲸京惊精粳经井警景颈静境敬镜径痉靖竟竞净炯窘揪究纠玖韭久灸九酒厩救旧臼舅咎就疚鞠拘狙疽居驹菊局咀矩举沮聚拒据巨具距踞锯俱句惧炬剧捐鹃娟倦眷卷绢撅攫抉掘倔爵觉决诀绝均菌钧军君峻",
+        "緻緼緽緾緿縀縁縂縃縄縅縆縇縈縉縊縋縌縍縎縏縐縑縒縓縔縕縖縗縘縙縚縛縜縝縞縟縠縡縢縣縤縥縦縧縨縩縪縫縬縭縮縯縰縱縲縳縴縵縶縷縸縹\0縺縼總績縿繀繂繃繄繅繆繈繉繊繋繌繍繎繏繐繑繒繓織繕繖繗繘繙繚繛繜繝俊竣浚郡骏喀咖卡咯开揩楷凯慨刊堪勘坎砍看康慷糠扛抗亢炕考拷烤靠坷苛柯棵磕颗科壳咳可渴克刻客课肯啃垦恳坑吭空恐孔控抠口扣寇枯哭窟苦酷库裤夸垮挎跨胯块筷侩快宽款匡筐狂框矿眶旷况亏盔岿窥葵奎魁傀",
+        "繞繟繠繡繢繣繤繥繦繧繨繩繪繫繬繭繮繯繰繱繲繳繴繵繶繷繸繹繺繻繼繽繾繿纀纁纃纄纅纆纇纈纉纊纋續纍纎纏纐纑纒纓纔纕纖纗纘纙纚纜纝纞\0纮纴纻纼绖绤绬绹缊缐缞缷缹缻缼缽缾缿罀罁罃罆罇罈罉罊罋罌罍罎罏罒罓馈愧溃坤昆捆困括扩廓阔垃拉喇蜡腊辣啦莱来赖蓝婪栏拦篮阑兰澜谰揽览懒缆烂滥琅榔狼廊郎朗浪捞劳牢老佬姥酪烙涝勒乐雷镭蕾磊累儡垒擂肋类泪棱楞冷厘梨犁黎篱狸离漓理李里鲤礼莉荔吏栗丽厉励砾历利傈例俐",
+        "罖罙罛罜罝罞罠罣罤罥罦罧罫罬罭罯罰罳罵罶罷罸罺罻罼罽罿羀羂羃羄羅羆羇羈羉羋羍羏羐羑羒羓羕羖羗羘羙羛羜羠羢羣羥羦羨義羪羫羬羭羮羱\0羳羴羵羶羷羺羻羾翀翂翃翄翆翇翈翉翋翍翏翐翑習翓翖翗翙翚翛翜翝翞翢翣痢立粒沥隶力璃哩俩联莲连镰廉怜涟帘敛脸链恋炼练粮凉梁粱良两辆量晾亮谅撩聊僚疗燎寥辽潦了撂镣廖料列裂烈劣猎琳林磷霖临邻鳞淋凛赁吝拎玲菱零龄铃伶羚凌灵陵岭领另令溜琉榴硫馏留刘瘤流柳六龙聋咙笼窿",
+        "翤翧翨翪翫翬翭翯翲翴翵翶翷翸翹翺翽翾翿耂耇耈耉耊耎耏耑耓耚耛耝耞耟耡耣耤耫耬耭耮耯耰耲耴耹耺耼耾聀聁聄聅聇聈聉聎聏聐聑聓聕聖聗\0聙聛聜聝聞聟聠聡聢聣聤聥聦聧聨聫聬聭聮聯聰聲聳聴聵聶職聸聹聺聻聼聽隆垄拢陇楼娄搂篓漏陋芦卢颅庐炉掳卤虏鲁麓碌露路赂鹿潞禄录陆戮驴吕铝侣旅履屡缕虑氯律率滤绿峦挛孪滦卵乱掠略抡轮伦仑沦纶论萝螺罗逻锣箩骡裸落洛骆络妈麻玛码蚂马骂嘛吗埋买麦卖迈脉瞒馒蛮满蔓曼慢漫",
+        "聾肁肂肅肈肊肍肎肏肐肑肒肔肕肗肙肞肣肦肧肨肬肰肳肵肶肸肹肻胅胇胈胉胊胋胏胐胑胒胓胔胕胘胟胠胢胣胦胮胵胷胹胻胾胿脀脁脃脄脅脇脈脋\0脌脕脗脙脛脜脝脟脠脡脢脣脤脥脦脧脨脩脪脫脭脮脰脳脴脵脷脹脺脻脼脽脿谩芒茫盲氓忙莽猫茅锚毛矛铆卯茂冒帽貌贸么玫枚梅酶霉煤没眉媒镁每美昧寐妹媚门闷们萌蒙檬盟锰猛梦孟眯醚靡糜迷谜弥米秘觅泌蜜密幂棉眠绵冕免勉娩缅面苗描瞄藐秒渺庙妙蔑灭民抿皿敏悯闽明螟鸣铭名命谬摸",
+        "腀腁腂腃腄腅腇腉腍腎腏腒腖腗腘腛腜腝腞腟腡腢腣腤腦腨腪腫腬腯腲腳腵腶腷腸膁膃膄膅膆膇膉膋膌膍膎膐膒膓膔膕膖膗膙膚膞膟膠膡膢膤膥\0膧膩膫膬膭膮膯膰膱膲膴膵膶膷膸膹膼膽膾膿臄臅臇臈臉臋臍臎臏臐臑臒臓摹蘑模膜磨摩魔抹末莫墨默沫漠寞陌谋牟某拇牡亩姆母墓暮幕募慕木目睦牧穆拿哪呐钠那娜纳氖乃奶耐奈南男难囊挠脑恼闹淖呢馁内嫩能妮霓倪泥尼拟你匿腻逆溺蔫拈年碾撵捻念娘酿鸟尿捏聂孽啮镊镍涅您柠狞凝宁",
+        "臔臕臖臗臘臙臚臛臜臝臞臟臠臡臢臤臥臦臨臩臫臮臯臰臱臲臵臶臷臸臹臺臽臿舃與興舉舊舋舎舏舑舓舕舖舗舘舙舚舝舠舤舥舦舧舩舮舲舺舼舽舿\0艀艁艂艃艅艆艈艊艌艍艎艐艑艒艓艔艕艖艗艙艛艜艝艞艠艡艢艣艤艥艦艧艩拧泞牛扭钮纽脓浓农弄奴努怒女暖虐疟挪懦糯诺哦欧鸥殴藕呕偶沤啪趴爬帕怕琶拍排牌徘湃派攀潘盘磐盼畔判叛乓庞旁耪胖抛咆刨炮袍跑泡呸胚培裴赔陪配佩沛喷盆砰抨烹澎彭蓬棚硼篷膨朋鹏捧碰坯砒霹批披劈琵毗",
+        "艪艫艬艭艱艵艶艷艸艻艼芀芁芃芅芆芇芉芌芐芓芔芕芖芚芛芞芠芢芣芧芲芵芶芺芻芼芿苀苂苃苅苆苉苐苖苙苚苝苢苧苨苩苪苬苭苮苰苲苳苵苶苸\0苺苼苽苾苿茀茊茋茍茐茒茓茖茘茙茝茞茟茠茡茢茣茤茥茦茩茪茮茰茲茷茻茽啤脾疲皮匹痞僻屁譬篇偏片骗飘漂瓢票撇瞥拼频贫品聘乒坪苹萍平凭瓶评屏坡泼颇婆破魄迫粕剖扑铺仆莆葡菩蒲埔朴圃普浦谱曝瀑期欺栖戚妻七凄漆柒沏其棋奇歧畦崎脐齐旗祈祁骑起岂乞企启契砌器气迄弃汽泣讫掐",
+        "茾茿荁荂荄荅荈荊荋荌荍荎荓荕荖荗荘荙荝荢荰荱荲荳荴荵荶荹荺荾荿莀莁莂莃莄莇莈莊莋莌莍莏莐莑莔莕莖莗莙莚莝莟莡莢莣莤莥莦莧莬莭莮\0莯莵莻莾莿菂菃菄菆菈菉菋菍菎菐菑菒菓菕菗菙菚菛菞菢菣菤菦菧菨菫菬菭恰洽牵扦钎铅千迁签仟谦乾黔钱钳前潜遣浅谴堑嵌欠歉枪呛腔羌墙蔷强抢橇锹敲悄桥瞧乔侨巧鞘撬翘峭俏窍切茄且怯窃钦侵亲秦琴勤芹擒禽寝沁青轻氢倾卿清擎晴氰情顷请庆琼穷秋丘邱球求囚酋泅趋区蛆曲躯屈驱渠",
+        "菮華菳菴菵菶菷菺菻菼菾菿萀萂萅萇萈萉萊萐萒萓萔萕萖萗萙萚萛萞萟萠萡萢萣萩萪萫萬萭萮萯萰萲萳萴萵萶萷萹萺萻萾萿葀葁葂葃葄葅葇葈葉\0葊葋葌葍葎葏葐葒葓葔葕葖葘葝葞葟葠葢葤葥葦葧葨葪葮葯葰葲葴葷葹葻葼取娶龋趣去圈颧权醛泉全痊拳犬券劝缺炔瘸却鹊榷确雀裙群然燃冉染瓤壤攘嚷让饶扰绕惹热壬仁人忍韧任认刃妊纫扔仍日戎茸蓉荣融熔溶容绒冗揉柔肉茹蠕儒孺如辱乳汝入褥软阮蕊瑞锐闰润若弱撒洒萨腮鳃塞赛三叁",
+        "葽葾葿蒀蒁蒃蒄蒅蒆蒊蒍蒏蒐蒑蒒蒓蒔蒕蒖蒘蒚蒛蒝蒞蒟蒠蒢蒣蒤蒥蒦蒧蒨蒩蒪蒫蒬蒭蒮蒰蒱蒳蒵蒶蒷蒻蒼蒾蓀蓂蓃蓅蓆蓇蓈蓋蓌蓎蓏蓒蓔蓕蓗\0蓘蓙蓚蓛蓜蓞蓡蓢蓤蓧蓨蓩蓪蓫蓭蓮蓯蓱蓲蓳蓴蓵蓶蓷蓸蓹蓺蓻蓽蓾蔀蔁蔂伞散桑嗓丧搔骚扫嫂瑟色涩森僧莎砂杀刹沙纱傻啥煞筛晒珊苫杉山删煽衫闪陕擅赡膳善汕扇缮墒伤商赏晌上尚裳梢捎稍烧芍勺韶少哨邵绍奢赊蛇舌舍赦摄射慑涉社设砷申呻伸身深娠绅神沈审婶甚肾慎渗声生甥牲升绳",
+        "蔃蔄蔅蔆蔇蔈蔉蔊蔋蔍蔎蔏蔐蔒蔔蔕蔖蔘蔙蔛蔜蔝蔞蔠蔢蔣蔤蔥蔦蔧蔨蔩蔪蔭蔮蔯蔰蔱蔲蔳蔴蔵蔶蔾蔿蕀蕁蕂蕄蕅蕆蕇蕋蕌蕍蕎蕏蕐蕑蕒蕓蕔蕕\0蕗蕘蕚蕛蕜蕝蕟蕠蕡蕢蕣蕥蕦蕧蕩蕪蕫蕬蕭蕮蕯蕰蕱蕳蕵蕶蕷蕸蕼蕽蕿薀薁省盛剩胜圣师失狮施湿诗尸虱十石拾时什食蚀实识史矢使屎驶始式示士世柿事拭誓逝势是嗜噬适仕侍释饰氏市恃室视试收手首守寿授售受瘦兽蔬枢梳殊抒输叔舒淑疏书赎孰熟薯暑曙署蜀黍鼠属术述树束戍竖墅庶数漱",
+        "薂薃薆薈薉薊薋薌薍薎薐薑薒薓薔薕薖薗薘薙薚薝薞薟薠薡薢薣薥薦薧薩薫薬薭薱薲薳薴薵薶薸薺薻薼薽薾薿藀藂藃藄藅藆藇藈藊藋藌藍藎藑藒\0藔藖藗藘藙藚藛藝藞藟藠藡藢藣藥藦藧藨藪藫藬藭藮藯藰藱藲藳藴藵藶藷藸恕刷耍摔衰甩帅栓拴霜双爽谁水睡税吮瞬顺舜说硕朔烁斯撕嘶思私司丝死肆寺嗣四伺似饲巳松耸怂颂送宋讼诵搜艘擞嗽苏酥俗素速粟僳塑溯宿诉肃酸蒜算虽隋随绥髓碎岁穗遂隧祟孙损笋蓑梭唆缩琐索锁所塌他它她塔",
+        "藹藺藼藽藾蘀蘁蘂蘃蘄蘆蘇蘈蘉蘊蘋蘌蘍蘎蘏蘐蘒蘓蘔蘕蘗蘘蘙蘚蘛蘜蘝蘞蘟蘠蘡蘢蘣蘤蘥蘦蘨蘪蘫蘬蘭蘮蘯蘰蘱蘲蘳蘴蘵蘶蘷蘹蘺蘻蘽蘾蘿虀\0虁虂虃虄虅虆虇虈虉虊虋虌虒虓處虖虗虘虙虛虜虝號虠虡虣虤虥虦虧虨虩虪獭挞蹋踏胎苔抬台泰酞太态汰坍摊贪瘫滩坛檀痰潭谭谈坦毯袒碳探叹炭汤塘搪堂棠膛唐糖倘躺淌趟烫掏涛滔绦萄桃逃淘陶讨套特藤腾疼誊梯剔踢锑提题蹄啼体替嚏惕涕剃屉天添填田甜恬舔腆挑条迢眺跳贴铁帖厅听烃",
+        "虭虯虰虲虳虴虵虶虷虸蚃蚄蚅蚆蚇蚈蚉蚎蚏蚐蚑蚒蚔蚖蚗蚘蚙蚚蚛蚞蚟蚠蚡蚢蚥蚦蚫蚭蚮蚲蚳蚷蚸蚹蚻蚼蚽蚾蚿蛁蛂蛃蛅蛈蛌蛍蛒蛓蛕蛖蛗蛚蛜\0蛝蛠蛡蛢蛣蛥蛦蛧蛨蛪蛫蛬蛯蛵蛶蛷蛺蛻蛼蛽蛿蜁蜄蜅蜆蜋蜌蜎蜏蜐蜑蜔蜖汀廷停亭庭挺艇通桐酮瞳同铜彤童桶捅筒统痛偷投头透凸秃突图徒途涂屠土吐兔湍团推颓腿蜕褪退吞屯臀拖托脱鸵陀驮驼椭妥拓唾挖哇蛙洼娃瓦袜歪外豌弯湾玩顽丸烷完碗挽晚皖惋宛婉万腕汪王亡枉网往旺望忘妄威",
+        "蜙蜛蜝蜟蜠蜤蜦蜧蜨蜪蜫蜬蜭蜯蜰蜲蜳蜵蜶蜸蜹蜺蜼蜽蝀蝁蝂蝃蝄蝅蝆蝊蝋蝍蝏蝐蝑蝒蝔蝕蝖蝘蝚蝛蝜蝝蝞蝟蝡蝢蝦蝧蝨蝩蝪蝫蝬蝭蝯蝱蝲蝳蝵\0蝷蝸蝹蝺蝿螀螁螄螆螇螉螊螌螎螏螐螑螒螔螕螖螘螙螚螛螜螝螞螠螡螢螣螤巍微危韦违桅围唯惟为潍维苇萎委伟伪尾纬未蔚味畏胃喂魏位渭谓尉慰卫瘟温蚊文闻纹吻稳紊问嗡翁瓮挝蜗涡窝我斡卧握沃巫呜钨乌污诬屋无芜梧吾吴毋武五捂午舞伍侮坞戊雾晤物勿务悟误昔熙析西硒矽晰嘻吸锡牺",
+        "螥螦螧螩螪螮螰螱螲螴螶螷螸螹螻螼螾螿蟁蟂蟃蟄蟅蟇蟈蟉蟌蟍蟎蟏蟐蟔蟕蟖蟗蟘蟙蟚蟜蟝蟞蟟蟡蟢蟣蟤蟦蟧蟨蟩蟫蟬蟭蟯蟰蟱蟲蟳蟴蟵蟶蟷蟸\0蟺蟻蟼蟽蟿蠀蠁蠂蠄蠅蠆蠇蠈蠉蠋蠌蠍蠎蠏蠐蠑蠒蠔蠗蠘蠙蠚蠜蠝蠞蠟蠠蠣稀息希悉膝夕惜熄烯溪汐犀檄袭席习媳喜铣洗系隙戏细瞎虾匣霞辖暇峡侠狭下厦夏吓掀锨先仙鲜纤咸贤衔舷闲涎弦嫌显险现献县腺馅羡宪陷限线相厢镶香箱襄湘乡翔祥详想响享项巷橡像向象萧硝霄削哮嚣销消宵淆晓",
+        "蠤蠥蠦蠧蠨蠩蠪蠫蠬蠭蠮蠯蠰蠱蠳蠴蠵蠶蠷蠸蠺蠻蠽蠾蠿衁衂衃衆衇衈衉衊衋衎衏衐衑衒術衕衖衘衚衛衜衝衞衟衠衦衧衪衭衯衱衳衴衵衶衸衹衺\0衻衼袀袃袆袇袉袊袌袎袏袐袑袓袔袕袗袘袙袚袛袝袞袟袠袡袣袥袦袧袨袩袪小孝校肖啸笑效楔些歇蝎鞋协挟携邪斜胁谐写械卸蟹懈泄泻谢屑薪芯锌欣辛新忻心信衅星腥猩惺兴刑型形邢行醒幸杏性姓兄凶胸匈汹雄熊休修羞朽嗅锈秀袖绣墟戌需虚嘘须徐许蓄酗叙旭序畜恤絮婿绪续轩喧宣悬旋玄",
+        "袬袮袯袰袲袳袴袵袶袸袹袺袻袽袾袿裀裃裄裇裈裊裋裌裍裏裐裑裓裖裗裚裛補裝裞裠裡裦裧裩裪裫裬裭裮裯裲裵裶裷裺裻製裿褀褁褃褄褅褆複褈\0褉褋褌褍褎褏褑褔褕褖褗褘褜褝褞褟褠褢褣褤褦褧褨褩褬褭褮褯褱褲褳褵褷选癣眩绚靴薛学穴雪血勋熏循旬询寻驯巡殉汛训讯逊迅压押鸦鸭呀丫芽牙蚜崖衙涯雅哑亚讶焉咽阉烟淹盐严研蜒岩延言颜阎炎沿奄掩眼衍演艳堰燕厌砚雁唁彦焰宴谚验殃央鸯秧杨扬佯疡羊洋阳氧仰痒养样漾邀腰妖瑶",
+        "褸褹褺褻褼褽褾褿襀襂襃襅襆襇襈襉襊襋襌襍襎襏襐襑襒襓襔襕襖襗襘襙襚襛襜襝襠襡襢襣襤襥襧襨襩襪襫襬襭襮襯襰襱襲襳襴襵襶襷襸襹襺襼\0襽襾覀覂覄覅覇覈覉覊見覌覍覎規覐覑覒覓覔覕視覗覘覙覚覛覜覝覞覟覠覡摇尧遥窑谣姚咬舀药要耀椰噎耶爷野冶也页掖业叶曳腋夜液一壹医揖铱依伊衣颐夷遗移仪胰疑沂宜姨彝椅蚁倚已乙矣以艺抑易邑屹亿役臆逸肄疫亦裔意毅忆义益溢诣议谊译异翼翌绎茵荫因殷音阴姻吟银淫寅饮尹引隐",
+        "覢覣覤覥覦覧覨覩親覫覬覭覮覯覰覱覲観覴覵覶覷覸覹覺覻覼覽覾覿觀觃觍觓觔觕觗觘觙觛觝觟觠觡觢觤觧觨觩觪觬觭觮觰觱觲觴觵觶觷觸觹觺\0觻觼觽觾觿訁訂訃訄訅訆計訉訊訋訌訍討訏訐訑訒訓訔訕訖託記訙訚訛訜訝印英樱婴鹰应缨莹萤营荧蝇迎赢盈影颖硬映哟拥佣臃痈庸雍踊蛹咏泳涌永恿勇用幽优悠忧尤由邮铀犹油游酉有友右佑釉诱又幼迂淤于盂榆虞愚舆余俞逾鱼愉渝渔隅予娱雨与屿禹宇语羽玉域芋郁吁遇喻峪御愈欲狱育誉",
+        "訞訟訠訡訢訣訤訥訦訧訨訩訪訫訬設訮訯訰許訲訳訴訵訶訷訸訹診註証訽訿詀詁詂詃詄詅詆詇詉詊詋詌詍詎詏詐詑詒詓詔評詖詗詘詙詚詛詜詝詞\0詟詠詡詢詣詤詥試詧詨詩詪詫詬詭詮詯詰話該詳詴詵詶詷詸詺詻詼詽詾詿誀浴寓裕预豫驭鸳渊冤元垣袁原援辕园员圆猿源缘远苑愿怨院曰约越跃钥岳粤月悦阅耘云郧匀陨允运蕴酝晕韵孕匝砸杂栽哉灾宰载再在咱攒暂赞赃脏葬遭糟凿藻枣早澡蚤躁噪造皂灶燥责择则泽贼怎增憎曾赠扎喳渣札轧",
+        "誁誂誃誄誅誆誇誈誋誌認誎誏誐誑誒誔誕誖誗誘誙誚誛誜誝語誟誠誡誢誣誤誥誦誧誨誩說誫説読誮誯誰誱課誳誴誵誶誷誸誹誺誻誼誽誾調諀諁諂\0諃諄諅諆談諈諉諊請諌諍諎諏諐諑諒諓諔諕論諗諘諙諚諛諜諝諞諟諠諡諢諣铡闸眨栅榨咋乍炸诈摘斋宅窄债寨瞻毡詹粘沾盏斩辗崭展蘸栈占战站湛绽樟章彰漳张掌涨杖丈帐账仗胀瘴障招昭找沼赵照罩兆肇召遮折哲蛰辙者锗蔗这浙珍斟真甄砧臻贞针侦枕疹诊震振镇阵蒸挣睁征狰争怔整拯正政",
+        "諤諥諦諧諨諩諪諫諬諭諮諯諰諱諲諳諴諵諶諷諸諹諺諻諼諽諾諿謀謁謂謃謄謅謆謈謉謊謋謌謍謎謏謐謑謒謓謔謕謖謗謘謙謚講謜謝謞謟謠謡謢謣\0謤謥謧謨謩謪謫謬謭謮謯謰謱謲謳謴謵謶謷謸謹謺謻謼謽謾謿譀譁譂譃譄譅帧症郑证芝枝支吱蜘知肢脂汁之织职直植殖执值侄址指止趾只旨纸志挚掷至致置帜峙制智秩稚质炙痔滞治窒中盅忠钟衷终种肿重仲众舟周州洲诌粥轴肘帚咒皱宙昼骤珠株蛛朱猪诸诛逐竹烛煮拄瞩嘱主著柱助蛀贮铸筑",
+        "譆譇譈證譊譋譌譍譎譏譐譑譒譓譔譕譖譗識譙譚譛譜譝譞譟譠譡譢譣譤譥譧譨譩譪譫譭譮譯議譱譲譳譴譵譶護譸譹譺譻譼譽譾譿讀讁讂讃讄讅讆\0讇讈讉變讋讌讍讎讏讐讑讒讓讔讕讖讗讘讙讚讛讜讝讞讟讬讱讻诇诐诪谉谞住注祝驻抓爪拽专砖转撰赚篆桩庄装妆撞壮状椎锥追赘坠缀谆准捉拙卓桌琢茁酌啄着灼浊兹咨资姿滋淄孜紫仔籽滓子自渍字鬃棕踪宗综总纵邹走奏揍租足卒族祖诅阻组钻纂嘴醉最罪尊遵昨左佐柞做作坐座\0\0\0\0\0",
+        "谸谹谺谻谼谽谾谿豀豂豃豄豅豈豊豋豍豎豏豐豑豒豓豔豖豗豘豙豛豜豝豞豟豠豣豤豥豦豧豨豩豬豭豮豯豰豱豲豴豵豶豷豻豼豽豾豿貀貁貃貄貆貇\0貈貋貍貎貏貐貑貒貓貕貖貗貙貚貛貜貝貞貟負財貢貣貤貥貦貧貨販貪貫責貭亍丌兀丐廿卅丕亘丞鬲孬噩丨禺丿匕乇夭爻卮氐囟胤馗毓睾鼗丶亟鼐乜乩亓芈孛啬嘏仄厍厝厣厥厮靥赝匚叵匦匮匾赜卦卣刂刈刎刭刳刿剀剌剞剡剜蒯剽劂劁劐劓冂罔亻仃仉仂仨仡仫仞伛仳伢佤仵伥伧伉伫佞佧攸佚佝",
+        "貮貯貰貱貲貳貴貵貶買貸貹貺費貼貽貾貿賀賁賂賃賄賅賆資賈賉賊賋賌賍賎賏賐賑賒賓賔賕賖賗賘賙賚賛賜賝賞賟賠賡賢賣賤賥賦賧賨賩質賫賬\0賭賮賯賰賱賲賳賴賵賶賷賸賹賺賻購賽賾賿贀贁贂贃贄贅贆贇贈贉贊贋贌贍佟佗伲伽佶佴侑侉侃侏佾佻侪佼侬侔俦俨俪俅俚俣俜俑俟俸倩偌俳倬倏倮倭俾倜倌倥倨偾偃偕偈偎偬偻傥傧傩傺僖儆僭僬僦僮儇儋仝氽佘佥俎龠汆籴兮巽黉馘冁夔勹匍訇匐凫夙兕亠兖亳衮袤亵脔裒禀嬴蠃羸冫冱冽冼",
+        "贎贏贐贑贒贓贔贕贖贗贘贙贚贛贜贠赑赒赗赟赥赨赩赪赬赮赯赱赲赸赹赺赻赼赽赾赿趀趂趃趆趇趈趉趌趍趎趏趐趒趓趕趖趗趘趙趚趛趜趝趞趠趡\0趢趤趥趦趧趨趩趪趫趬趭趮趯趰趲趶趷趹趻趽跀跁跂跅跇跈跉跊跍跐跒跓跔凇冖冢冥讠讦讧讪讴讵讷诂诃诋诏诎诒诓诔诖诘诙诜诟诠诤诨诩诮诰诳诶诹诼诿谀谂谄谇谌谏谑谒谔谕谖谙谛谘谝谟谠谡谥谧谪谫谮谯谲谳谵谶卩卺阝阢阡阱阪阽阼陂陉陔陟陧陬陲陴隈隍隗隰邗邛邝邙邬邡邴邳邶邺",
+        "跕跘跙跜跠跡跢跥跦跧跩跭跮跰跱跲跴跶跼跾跿踀踁踂踃踄踆踇踈踋踍踎踐踑踒踓踕踖踗踘踙踚踛踜踠踡踤踥踦踧踨踫踭踰踲踳踴踶踷踸踻踼踾\0踿蹃蹅蹆蹌蹍蹎蹏蹐蹓蹔蹕蹖蹗蹘蹚蹛蹜蹝蹞蹟蹠蹡蹢蹣蹤蹥蹧蹨蹪蹫蹮蹱邸邰郏郅邾郐郄郇郓郦郢郜郗郛郫郯郾鄄鄢鄞鄣鄱鄯鄹酃酆刍奂劢劬劭劾哿勐勖勰叟燮矍廴凵凼鬯厶弁畚巯坌垩垡塾墼壅壑圩圬圪圳圹圮圯坜圻坂坩垅坫垆坼坻坨坭坶坳垭垤垌垲埏垧垴垓垠埕埘埚埙埒垸埴埯埸埤埝",
+        "蹳蹵蹷蹸蹹蹺蹻蹽蹾躀躂躃躄躆躈躉躊躋躌躍躎躑躒躓躕躖躗躘躙躚躛躝躟躠躡躢躣躤躥躦躧躨躩躪躭躮躰躱躳躴躵躶躷躸躹躻躼躽躾躿軀軁軂\0軃軄軅軆軇軈軉車軋軌軍軏軐軑軒軓軔軕軖軗軘軙軚軛軜軝軞軟軠軡転軣軤堋堍埽埭堀堞堙塄堠塥塬墁墉墚墀馨鼙懿艹艽艿芏芊芨芄芎芑芗芙芫芸芾芰苈苊苣芘芷芮苋苌苁芩芴芡芪芟苄苎芤苡茉苷苤茏茇苜苴苒苘茌苻苓茑茚茆茔茕苠苕茜荑荛荜茈莒茼茴茱莛荞茯荏荇荃荟荀茗荠茭茺茳荦荥",
+        "軥軦軧軨軩軪軫軬軭軮軯軰軱軲軳軴軵軶軷軸軹軺軻軼軽軾軿輀輁輂較輄輅輆輇輈載輊輋輌輍輎輏輐輑輒輓輔輕輖輗輘輙輚輛輜輝輞輟輠輡輢輣\0輤輥輦輧輨輩輪輫輬輭輮輯輰輱輲輳輴輵輶輷輸輹輺輻輼輽輾輿轀轁轂轃轄荨茛荩荬荪荭荮莰荸莳莴莠莪莓莜莅荼莶莩荽莸荻莘莞莨莺莼菁萁菥菘堇萘萋菝菽菖萜萸萑萆菔菟萏萃菸菹菪菅菀萦菰菡葜葑葚葙葳蒇蒈葺蒉葸萼葆葩葶蒌蒎萱葭蓁蓍蓐蓦蒽蓓蓊蒿蒺蓠蒡蒹蒴蒗蓥蓣蔌甍蔸蓰蔹蔟蔺",
+        "轅轆轇轈轉轊轋轌轍轎轏轐轑轒轓轔轕轖轗轘轙轚轛轜轝轞轟轠轡轢轣轤轥轪辀辌辒辝辠辡辢辤辥辦辧辪辬辭辮辯農辳辴辵辷辸辺辻込辿迀迃迆\0迉迊迋迌迍迏迒迖迗迚迠迡迣迧迬迯迱迲迴迵迶迺迻迼迾迿逇逈逌逎逓逕逘蕖蔻蓿蓼蕙蕈蕨蕤蕞蕺瞢蕃蕲蕻薤薨薇薏蕹薮薜薅薹薷薰藓藁藜藿蘧蘅蘩蘖蘼廾弈夼奁耷奕奚奘匏尢尥尬尴扌扪抟抻拊拚拗拮挢拶挹捋捃掭揶捱捺掎掴捭掬掊捩掮掼揲揸揠揿揄揞揎摒揆掾摅摁搋搛搠搌搦搡摞撄摭撖",
+        "這逜連逤逥逧逨逩逪逫逬逰週進逳逴逷逹逺逽逿遀遃遅遆遈遉遊運遌過達違遖遙遚遜遝遞遟遠遡遤遦遧適遪遫遬遯遰遱遲遳遶遷選遹遺遻遼遾邁\0還邅邆邇邉邊邌邍邎邏邐邒邔邖邘邚邜邞邟邠邤邥邧邨邩邫邭邲邷邼邽邿郀摺撷撸撙撺擀擐擗擤擢攉攥攮弋忒甙弑卟叱叽叩叨叻吒吖吆呋呒呓呔呖呃吡呗呙吣吲咂咔呷呱呤咚咛咄呶呦咝哐咭哂咴哒咧咦哓哔呲咣哕咻咿哌哙哚哜咩咪咤哝哏哞唛哧唠哽唔哳唢唣唏唑唧唪啧喏喵啉啭啁啕唿啐唼",
+        "郂郃郆郈郉郋郌郍郒郔郕郖郘郙郚郞郟郠郣郤郥郩郪郬郮郰郱郲郳郵郶郷郹郺郻郼郿鄀鄁鄃鄅鄆鄇鄈鄉鄊鄋鄌鄍鄎鄏鄐鄑鄒鄓鄔鄕鄖鄗鄘鄚鄛鄜\0鄝鄟鄠鄡鄤鄥鄦鄧鄨鄩鄪鄫鄬鄭鄮鄰鄲鄳鄴鄵鄶鄷鄸鄺鄻鄼鄽鄾鄿酀酁酂酄唷啖啵啶啷唳唰啜喋嗒喃喱喹喈喁喟啾嗖喑啻嗟喽喾喔喙嗪嗷嗉嘟嗑嗫嗬嗔嗦嗝嗄嗯嗥嗲嗳嗌嗍嗨嗵嗤辔嘞嘈嘌嘁嘤嘣嗾嘀嘧嘭噘嘹噗嘬噍噢噙噜噌噔嚆噤噱噫噻噼嚅嚓嚯囔囗囝囡囵囫囹囿圄圊圉圜帏帙帔帑帱帻帼",
+        "酅酇酈酑酓酔酕酖酘酙酛酜酟酠酦酧酨酫酭酳酺酻酼醀醁醂醃醄醆醈醊醎醏醓醔醕醖醗醘醙醜醝醞醟醠醡醤醥醦醧醨醩醫醬醰醱醲醳醶醷醸醹醻\0醼醽醾醿釀釁釂釃釄釅釆釈釋釐釒釓釔釕釖釗釘釙釚釛針釞釟釠釡釢釣釤釥帷幄幔幛幞幡岌屺岍岐岖岈岘岙岑岚岜岵岢岽岬岫岱岣峁岷峄峒峤峋峥崂崃崧崦崮崤崞崆崛嵘崾崴崽嵬嵛嵯嵝嵫嵋嵊嵩嵴嶂嶙嶝豳嶷巅彳彷徂徇徉後徕徙徜徨徭徵徼衢彡犭犰犴犷犸狃狁狎狍狒狨狯狩狲狴狷猁狳猃狺",
+        "釦釧釨釩釪釫釬釭釮釯釰釱釲釳釴釵釶釷釸釹釺釻釼釽釾釿鈀鈁鈂鈃鈄鈅鈆鈇鈈鈉鈊鈋鈌鈍鈎鈏鈐鈑鈒鈓鈔鈕鈖鈗鈘鈙鈚鈛鈜鈝鈞鈟鈠鈡鈢鈣鈤\0鈥鈦鈧鈨鈩鈪鈫鈬鈭鈮鈯鈰鈱鈲鈳鈴鈵鈶鈷鈸鈹鈺鈻鈼鈽鈾鈿鉀鉁鉂鉃鉄鉅狻猗猓猡猊猞猝猕猢猹猥猬猸猱獐獍獗獠獬獯獾舛夥飧夤夂饣饧饨饩饪饫饬饴饷饽馀馄馇馊馍馐馑馓馔馕庀庑庋庖庥庠庹庵庾庳赓廒廑廛廨廪膺忄忉忖忏怃忮怄忡忤忾怅怆忪忭忸怙怵怦怛怏怍怩怫怊怿怡恸恹恻恺恂",
+        "鉆鉇鉈鉉鉊鉋鉌鉍鉎鉏鉐鉑鉒鉓鉔鉕鉖鉗鉘鉙鉚鉛鉜鉝鉞鉟鉠鉡鉢鉣鉤鉥鉦鉧鉨鉩鉪鉫鉬鉭鉮鉯鉰鉱鉲鉳鉵鉶鉷鉸鉹鉺鉻鉼鉽鉾鉿銀銁銂銃銄銅\0銆銇銈銉銊銋銌銍銏銐銑銒銓銔銕銖銗銘銙銚銛銜銝銞銟銠銡銢銣銤銥銦銧恪恽悖悚悭悝悃悒悌悛惬悻悱惝惘惆惚悴愠愦愕愣惴愀愎愫慊慵憬憔憧憷懔懵忝隳闩闫闱闳闵闶闼闾阃阄阆阈阊阋阌阍阏阒阕阖阗阙阚丬爿戕氵汔汜汊沣沅沐沔沌汨汩汴汶沆沩泐泔沭泷泸泱泗沲泠泖泺泫泮沱泓泯泾",
+        "銨銩銪銫銬銭銯銰銱銲銳銴銵銶銷銸銹銺銻銼銽銾銿鋀鋁鋂鋃鋄鋅鋆鋇鋉鋊鋋鋌鋍鋎鋏鋐鋑鋒鋓鋔鋕鋖鋗鋘鋙鋚鋛鋜鋝鋞鋟鋠鋡鋢鋣鋤鋥鋦鋧鋨\0鋩鋪鋫鋬鋭鋮鋯鋰鋱鋲鋳鋴鋵鋶鋷鋸鋹鋺鋻鋼鋽鋾鋿錀錁錂錃錄錅錆錇錈錉洹洧洌浃浈洇洄洙洎洫浍洮洵洚浏浒浔洳涑浯涞涠浞涓涔浜浠浼浣渚淇淅淞渎涿淠渑淦淝淙渖涫渌涮渫湮湎湫溲湟溆湓湔渲渥湄滟溱溘滠漭滢溥溧溽溻溷滗溴滏溏滂溟潢潆潇漤漕滹漯漶潋潴漪漉漩澉澍澌潸潲潼潺濑",
+        "錊錋錌錍錎錏錐錑錒錓錔錕錖錗錘錙錚錛錜錝錞錟錠錡錢錣錤錥錦錧錨錩錪錫錬錭錮錯錰錱録錳錴錵錶錷錸錹錺錻錼錽錿鍀鍁鍂鍃鍄鍅鍆鍇鍈鍉\0鍊鍋鍌鍍鍎鍏鍐鍑鍒鍓鍔鍕鍖鍗鍘鍙鍚鍛鍜鍝鍞鍟鍠鍡鍢鍣鍤鍥鍦鍧鍨鍩鍫濉澧澹澶濂濡濮濞濠濯瀚瀣瀛瀹瀵灏灞宀宄宕宓宥宸甯骞搴寤寮褰寰蹇謇辶迓迕迥迮迤迩迦迳迨逅逄逋逦逑逍逖逡逵逶逭逯遄遑遒遐遨遘遢遛暹遴遽邂邈邃邋彐彗彖彘尻咫屐屙孱屣屦羼弪弩弭艴弼鬻屮妁妃妍妩妪妣",
+        "鍬鍭鍮鍯鍰鍱鍲鍳鍴鍵鍶鍷鍸鍹鍺鍻鍼鍽鍾鍿鎀鎁鎂鎃鎄鎅鎆鎇鎈鎉鎊鎋鎌鎍鎎鎐鎑鎒鎓鎔鎕鎖鎗鎘鎙鎚鎛鎜鎝鎞鎟鎠鎡鎢鎣鎤鎥鎦鎧鎨鎩鎪鎫\0鎬鎭鎮鎯鎰鎱鎲鎳鎴鎵鎶鎷鎸鎹鎺鎻鎼鎽鎾鎿鏀鏁鏂鏃鏄鏅鏆鏇鏈鏉鏋鏌鏍妗姊妫妞妤姒妲妯姗妾娅娆姝娈姣姘姹娌娉娲娴娑娣娓婀婧婊婕娼婢婵胬媪媛婷婺媾嫫媲嫒嫔媸嫠嫣嫱嫖嫦嫘嫜嬉嬗嬖嬲嬷孀尕尜孚孥孳孑孓孢驵驷驸驺驿驽骀骁骅骈骊骐骒骓骖骘骛骜骝骟骠骢骣骥骧纟纡纣纥纨纩",
+        "鏎鏏鏐鏑鏒鏓鏔鏕鏗鏘鏙鏚鏛鏜鏝鏞鏟鏠鏡鏢鏣鏤鏥鏦鏧鏨鏩鏪鏫鏬鏭鏮鏯鏰鏱鏲鏳鏴鏵鏶鏷鏸鏹鏺鏻鏼鏽鏾鏿鐀鐁鐂鐃鐄鐅鐆鐇鐈鐉鐊鐋鐌鐍\0鐎鐏鐐鐑鐒鐓鐔鐕鐖鐗鐘鐙鐚鐛鐜鐝鐞鐟鐠鐡鐢鐣鐤鐥鐦鐧鐨鐩鐪鐫鐬鐭鐮纭纰纾绀绁绂绉绋绌绐绔绗绛绠绡绨绫绮绯绱绲缍绶绺绻绾缁缂缃缇缈缋缌缏缑缒缗缙缜缛缟缡缢缣缤缥缦缧缪缫缬缭缯缰缱缲缳缵幺畿巛甾邕玎玑玮玢玟珏珂珑玷玳珀珉珈珥珙顼琊珩珧珞玺珲琏琪瑛琦琥琨琰琮琬",
+        "鐯鐰鐱鐲鐳鐴鐵鐶鐷鐸鐹鐺鐻鐼鐽鐿鑀鑁鑂鑃鑄鑅鑆鑇鑈鑉鑊鑋鑌鑍鑎鑏鑐鑑鑒鑓鑔鑕鑖鑗鑘鑙鑚鑛鑜鑝鑞鑟鑠鑡鑢鑣鑤鑥鑦鑧鑨鑩鑪鑬鑭鑮鑯\0鑰鑱鑲鑳鑴鑵鑶鑷鑸鑹鑺鑻鑼鑽鑾鑿钀钁钂钃钄钑钖钘铇铏铓铔铚铦铻锜锠琛琚瑁瑜瑗瑕瑙瑷瑭瑾璜璎璀璁璇璋璞璨璩璐璧瓒璺韪韫韬杌杓杞杈杩枥枇杪杳枘枧杵枨枞枭枋杷杼柰栉柘栊柩枰栌柙枵柚枳柝栀柃枸柢栎柁柽栲栳桠桡桎桢桄桤梃栝桕桦桁桧桀栾桊桉栩梵梏桴桷梓桫棂楮棼椟椠棹",
+        "锧锳锽镃镈镋镕镚镠镮镴镵長镸镹镺镻镼镽镾門閁閂閃閄閅閆閇閈閉閊開閌閍閎閏閐閑閒間閔閕閖閗閘閙閚閛閜閝閞閟閠閡関閣閤閥閦閧閨閩閪\0閫閬閭閮閯閰閱閲閳閴閵閶閷閸閹閺閻閼閽閾閿闀闁闂闃闄闅闆闇闈闉闊闋椤棰椋椁楗棣椐楱椹楠楂楝榄楫榀榘楸椴槌榇榈槎榉楦楣楹榛榧榻榫榭槔榱槁槊槟榕槠榍槿樯槭樗樘橥槲橄樾檠橐橛樵檎橹樽樨橘橼檑檐檩檗檫猷獒殁殂殇殄殒殓殍殚殛殡殪轫轭轱轲轳轵轶轸轷轹轺轼轾辁辂辄辇辋",
+        "闌闍闎闏闐闑闒闓闔闕闖闗闘闙闚闛關闝闞闟闠闡闢闣闤闥闦闧闬闿阇阓阘阛阞阠阣阤阥阦阧阨阩阫阬阭阯阰阷阸阹阺阾陁陃陊陎陏陑陒陓陖陗\0陘陙陚陜陝陞陠陣陥陦陫陭陮陯陰陱陳陸陹険陻陼陽陾陿隀隁隂隃隄隇隉隊辍辎辏辘辚軎戋戗戛戟戢戡戥戤戬臧瓯瓴瓿甏甑甓攴旮旯旰昊昙杲昃昕昀炅曷昝昴昱昶昵耆晟晔晁晏晖晡晗晷暄暌暧暝暾曛曜曦曩贲贳贶贻贽赀赅赆赈赉赇赍赕赙觇觊觋觌觎觏觐觑牮犟牝牦牯牾牿犄犋犍犏犒挈挲掰",
+        "隌階隑隒隓隕隖隚際隝隞隟隠隡隢隣隤隥隦隨隩險隫隬隭隮隯隱隲隴隵隷隸隺隻隿雂雃雈雊雋雐雑雓雔雖雗雘雙雚雛雜雝雞雟雡離難雤雥雦雧雫\0雬雭雮雰雱雲雴雵雸雺電雼雽雿霂霃霅霊霋霌霐霑霒霔霕霗霘霙霚霛霝霟霠搿擘耄毪毳毽毵毹氅氇氆氍氕氘氙氚氡氩氤氪氲攵敕敫牍牒牖爰虢刖肟肜肓肼朊肽肱肫肭肴肷胧胨胩胪胛胂胄胙胍胗朐胝胫胱胴胭脍脎胲胼朕脒豚脶脞脬脘脲腈腌腓腴腙腚腱腠腩腼腽腭腧塍媵膈膂膑滕膣膪臌朦臊膻",
+        "霡霢霣霤霥霦霧霨霩霫霬霮霯霱霳霴霵霶霷霺霻霼霽霿靀靁靂靃靄靅靆靇靈靉靊靋靌靍靎靏靐靑靔靕靗靘靚靜靝靟靣靤靦靧靨靪靫靬靭靮靯靰靱\0靲靵靷靸靹靺靻靽靾靿鞀鞁鞂鞃鞄鞆鞇鞈鞉鞊鞌鞎鞏鞐鞓鞕鞖鞗鞙鞚鞛鞜鞝臁膦欤欷欹歃歆歙飑飒飓飕飙飚殳彀毂觳斐齑斓於旆旄旃旌旎旒旖炀炜炖炝炻烀炷炫炱烨烊焐焓焖焯焱煳煜煨煅煲煊煸煺熘熳熵熨熠燠燔燧燹爝爨灬焘煦熹戾戽扃扈扉礻祀祆祉祛祜祓祚祢祗祠祯祧祺禅禊禚禧禳忑忐",
+        "鞞鞟鞡鞢鞤鞥鞦鞧鞨鞩鞪鞬鞮鞰鞱鞳鞵鞶鞷鞸鞹鞺鞻鞼鞽鞾鞿韀韁韂韃韄韅韆韇韈韉韊韋韌韍韎韏韐韑韒韓韔韕韖韗韘韙韚韛韜韝韞韟韠韡韢韣\0韤韥韨韮韯韰韱韲韴韷韸韹韺韻韼韽韾響頀頁頂頃頄項順頇須頉頊頋頌頍頎怼恝恚恧恁恙恣悫愆愍慝憩憝懋懑戆肀聿沓泶淼矶矸砀砉砗砘砑斫砭砜砝砹砺砻砟砼砥砬砣砩硎硭硖硗砦硐硇硌硪碛碓碚碇碜碡碣碲碹碥磔磙磉磬磲礅磴礓礤礞礴龛黹黻黼盱眄眍盹眇眈眚眢眙眭眦眵眸睐睑睇睃睚睨",
+        "頏預頑頒頓頔頕頖頗領頙頚頛頜頝頞頟頠頡頢頣頤頥頦頧頨頩頪頫頬頭頮頯頰頱頲頳頴頵頶頷頸頹頺頻頼頽頾頿顀顁顂顃顄顅顆顇顈顉顊顋題額\0顎顏顐顑顒顓顔顕顖顗願顙顚顛顜顝類顟顠顡顢顣顤顥顦顧顨顩顪顫顬顭顮睢睥睿瞍睽瞀瞌瞑瞟瞠瞰瞵瞽町畀畎畋畈畛畲畹疃罘罡罟詈罨罴罱罹羁罾盍盥蠲钅钆钇钋钊钌钍钏钐钔钗钕钚钛钜钣钤钫钪钭钬钯钰钲钴钶钷钸钹钺钼钽钿铄铈铉铊铋铌铍铎铐铑铒铕铖铗铙铘铛铞铟铠铢铤铥铧铨铪",
+        "顯顰顱顲顳顴颋颎颒颕颙颣風颩颪颫颬颭颮颯颰颱颲颳颴颵颶颷颸颹颺颻颼颽颾颿飀飁飂飃飄飅飆飇飈飉飊飋飌飍飏飐飔飖飗飛飜飝飠飡飢飣飤\0飥飦飩飪飫飬飭飮飯飰飱飲飳飴飵飶飷飸飹飺飻飼飽飾飿餀餁餂餃餄餅餆餇铩铫铮铯铳铴铵铷铹铼铽铿锃锂锆锇锉锊锍锎锏锒锓锔锕锖锘锛锝锞锟锢锪锫锩锬锱锲锴锶锷锸锼锾锿镂锵镄镅镆镉镌镎镏镒镓镔镖镗镘镙镛镞镟镝镡镢镤镥镦镧镨镩镪镫镬镯镱镲镳锺矧矬雉秕秭秣秫稆嵇稃稂稞稔",
+        "餈餉養餋餌餎餏餑餒餓餔餕餖餗餘餙餚餛餜餝餞餟餠餡餢餣餤餥餦餧館餩餪餫餬餭餯餰餱餲餳餴餵餶餷餸餹餺餻餼餽餾餿饀饁饂饃饄饅饆饇饈饉\0饊饋饌饍饎饏饐饑饒饓饖饗饘饙饚饛饜饝饞饟饠饡饢饤饦饳饸饹饻饾馂馃馉稹稷穑黏馥穰皈皎皓皙皤瓞瓠甬鸠鸢鸨鸩鸪鸫鸬鸲鸱鸶鸸鸷鸹鸺鸾鹁鹂鹄鹆鹇鹈鹉鹋鹌鹎鹑鹕鹗鹚鹛鹜鹞鹣鹦鹧鹨鹩鹪鹫鹬鹱鹭鹳疒疔疖疠疝疬疣疳疴疸痄疱疰痃痂痖痍痣痨痦痤痫痧瘃痱痼痿瘐瘀瘅瘌瘗瘊瘥瘘瘕瘙",
+        "馌馎馚馛馜馝馞馟馠馡馢馣馤馦馧馩馪馫馬馭馮馯馰馱馲馳馴馵馶馷馸馹馺馻馼馽馾馿駀駁駂駃駄駅駆駇駈駉駊駋駌駍駎駏駐駑駒駓駔駕駖駗駘\0駙駚駛駜駝駞駟駠駡駢駣駤駥駦駧駨駩駪駫駬駭駮駯駰駱駲駳駴駵駶駷駸駹瘛瘼瘢瘠癀瘭瘰瘿瘵癃瘾瘳癍癞癔癜癖癫癯翊竦穸穹窀窆窈窕窦窠窬窨窭窳衤衩衲衽衿袂袢裆袷袼裉裢裎裣裥裱褚裼裨裾裰褡褙褓褛褊褴褫褶襁襦襻疋胥皲皴矜耒耔耖耜耠耢耥耦耧耩耨耱耋耵聃聆聍聒聩聱覃顸颀颃",
+        "駺駻駼駽駾駿騀騁騂騃騄騅騆騇騈騉騊騋騌騍騎騏騐騑騒験騔騕騖騗騘騙騚騛騜騝騞騟騠騡騢騣騤騥騦騧騨騩騪騫騬騭騮騯騰騱騲騳騴騵騶騷騸\0騹騺騻騼騽騾騿驀驁驂驃驄驅驆驇驈驉驊驋驌驍驎驏驐驑驒驓驔驕驖驗驘驙颉颌颍颏颔颚颛颞颟颡颢颥颦虍虔虬虮虿虺虼虻蚨蚍蚋蚬蚝蚧蚣蚪蚓蚩蚶蛄蚵蛎蚰蚺蚱蚯蛉蛏蚴蛩蛱蛲蛭蛳蛐蜓蛞蛴蛟蛘蛑蜃蜇蛸蜈蜊蜍蜉蜣蜻蜞蜥蜮蜚蜾蝈蜴蜱蜩蜷蜿螂蜢蝽蝾蝻蝠蝰蝌蝮螋蝓蝣蝼蝤蝙蝥螓螯螨蟒",
+        "驚驛驜驝驞驟驠驡驢驣驤驥驦驧驨驩驪驫驲骃骉骍骎骔骕骙骦骩骪骫骬骭骮骯骲骳骴骵骹骻骽骾骿髃髄髆髇髈髉髊髍髎髏髐髒體髕髖髗髙髚髛髜\0髝髞髠髢髣髤髥髧髨髩髪髬髮髰髱髲髳髴髵髶髷髸髺髼髽髾髿鬀鬁鬂鬄鬅鬆蟆螈螅螭螗螃螫蟥螬螵螳蟋蟓螽蟑蟀蟊蟛蟪蟠蟮蠖蠓蟾蠊蠛蠡蠹蠼缶罂罄罅舐竺竽笈笃笄笕笊笫笏筇笸笪笙笮笱笠笥笤笳笾笞筘筚筅筵筌筝筠筮筻筢筲筱箐箦箧箸箬箝箨箅箪箜箢箫箴篑篁篌篝篚篥篦篪簌篾篼簏簖簋",
+        "鬇鬉鬊鬋鬌鬍鬎鬐鬑鬒鬔鬕鬖鬗鬘鬙鬚鬛鬜鬝鬞鬠鬡鬢鬤鬥鬦鬧鬨鬩鬪鬫鬬鬭鬮鬰鬱鬳鬴鬵鬶鬷鬸鬹鬺鬽鬾鬿魀魆魊魋魌魎魐魒魓魕魖魗魘魙魚\0魛魜魝魞魟魠魡魢魣魤魥魦魧魨魩魪魫魬魭魮魯魰魱魲魳魴魵魶魷魸魹魺魻簟簪簦簸籁籀臾舁舂舄臬衄舡舢舣舭舯舨舫舸舻舳舴舾艄艉艋艏艚艟艨衾袅袈裘裟襞羝羟羧羯羰羲籼敉粑粝粜粞粢粲粼粽糁糇糌糍糈糅糗糨艮暨羿翎翕翥翡翦翩翮翳糸絷綦綮繇纛麸麴赳趄趔趑趱赧赭豇豉酊酐酎酏酤",
+        "魼魽魾魿鮀鮁鮂鮃鮄鮅鮆鮇鮈鮉鮊鮋鮌鮍鮎鮏鮐鮑鮒鮓鮔鮕鮖鮗鮘鮙鮚鮛鮜鮝鮞鮟鮠鮡鮢鮣鮤鮥鮦鮧鮨鮩鮪鮫鮬鮭鮮鮯鮰鮱鮲鮳鮴鮵鮶鮷鮸鮹鮺\0鮻鮼鮽鮾鮿鯀鯁鯂鯃鯄鯅鯆鯇鯈鯉鯊鯋鯌鯍鯎鯏鯐鯑鯒鯓鯔鯕鯖鯗鯘鯙鯚鯛酢酡酰酩酯酽酾酲酴酹醌醅醐醍醑醢醣醪醭醮醯醵醴醺豕鹾趸跫踅蹙蹩趵趿趼趺跄跖跗跚跞跎跏跛跆跬跷跸跣跹跻跤踉跽踔踝踟踬踮踣踯踺蹀踹踵踽踱蹉蹁蹂蹑蹒蹊蹰蹶蹼蹯蹴躅躏躔躐躜躞豸貂貊貅貘貔斛觖觞觚觜",
+        "鯜鯝鯞鯟鯠鯡鯢鯣鯤鯥鯦鯧鯨鯩鯪鯫鯬鯭鯮鯯鯰鯱鯲鯳鯴鯵鯶鯷鯸鯹鯺鯻鯼鯽鯾鯿鰀鰁鰂鰃鰄鰅鰆鰇鰈鰉鰊鰋鰌鰍鰎鰏鰐鰑鰒鰓鰔鰕鰖鰗鰘鰙鰚\0鰛鰜鰝鰞鰟鰠鰡鰢鰣鰤鰥鰦鰧鰨鰩鰪鰫鰬鰭鰮鰯鰰鰱鰲鰳鰴鰵鰶鰷鰸鰹鰺鰻觥觫觯訾謦靓雩雳雯霆霁霈霏霎霪霭霰霾龀龃龅龆龇龈龉龊龌黾鼋鼍隹隼隽雎雒瞿雠銎銮鋈錾鍪鏊鎏鐾鑫鱿鲂鲅鲆鲇鲈稣鲋鲎鲐鲑鲒鲔鲕鲚鲛鲞鲟鲠鲡鲢鲣鲥鲦鲧鲨鲩鲫鲭鲮鲰鲱鲲鲳鲴鲵鲶鲷鲺鲻鲼鲽鳄鳅鳆鳇鳊鳋",
+        "鰼鰽鰾鰿鱀鱁鱂鱃鱄鱅鱆鱇鱈鱉鱊鱋鱌鱍鱎鱏鱐鱑鱒鱓鱔鱕鱖鱗鱘鱙鱚鱛鱜鱝鱞鱟鱠鱡鱢鱣鱤鱥鱦鱧鱨鱩鱪鱫鱬鱭鱮鱯鱰鱱鱲鱳鱴鱵鱶鱷鱸鱹鱺\0鱻鱽鱾鲀鲃鲄鲉鲊鲌鲏鲓鲖鲗鲘鲙鲝鲪鲬鲯鲹鲾鲿鳀鳁鳂鳈鳉鳑鳒鳚鳛鳠鳡鳌鳍鳎鳏鳐鳓鳔鳕鳗鳘鳙鳜鳝鳟鳢靼鞅鞑鞒鞔鞯鞫鞣鞲鞴骱骰骷鹘骶骺骼髁髀髅髂髋髌髑魅魃魇魉魈魍魑飨餍餮饕饔髟髡髦髯髫髻髭髹鬈鬏鬓鬟鬣麽麾縻麂麇麈麋麒鏖麝麟黛黜黝黠黟黢黩黧黥黪黯鼢鼬鼯鼹鼷鼽鼾齄",
+        "鳣鳤鳥鳦鳧鳨鳩鳪鳫鳬鳭鳮鳯鳰鳱鳲鳳鳴鳵鳶鳷鳸鳹鳺鳻鳼鳽鳾鳿鴀鴁鴂鴃鴄鴅鴆鴇鴈鴉鴊鴋鴌鴍鴎鴏鴐鴑鴒鴓鴔鴕鴖鴗鴘鴙鴚鴛鴜鴝鴞鴟鴠鴡\0鴢鴣鴤鴥鴦鴧鴨鴩鴪鴫鴬鴭鴮鴯鴰鴱鴲鴳鴴鴵鴶鴷鴸鴹鴺鴻鴼鴽鴾鴿鵀鵁鵂\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0",
+        "鵃鵄鵅鵆鵇鵈鵉鵊鵋鵌鵍鵎鵏鵐鵑鵒鵓鵔鵕鵖鵗鵘鵙鵚鵛鵜鵝鵞鵟鵠鵡鵢鵣鵤鵥鵦鵧鵨鵩鵪鵫鵬鵭鵮鵯鵰鵱鵲鵳鵴鵵鵶鵷鵸鵹鵺鵻鵼鵽鵾鵿鶀鶁\0鶂鶃鶄鶅鶆鶇鶈鶉鶊鶋鶌鶍鶎鶏鶐鶑鶒鶓鶔鶕鶖鶗鶘鶙鶚鶛鶜鶝鶞鶟鶠鶡鶢\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0",
+        "鶣鶤鶥鶦鶧鶨鶩鶪鶫鶬鶭鶮鶯鶰鶱鶲鶳鶴鶵鶶鶷鶸鶹鶺鶻鶼鶽鶾鶿鷀鷁鷂鷃鷄鷅鷆鷇鷈鷉鷊鷋鷌鷍鷎鷏鷐鷑鷒鷓鷔鷕鷖鷗鷘鷙鷚鷛鷜鷝鷞鷟鷠鷡\0鷢鷣鷤鷥鷦鷧鷨鷩鷪鷫鷬鷭鷮鷯鷰鷱鷲鷳鷴鷵鷶鷷鷸鷹鷺鷻鷼鷽鷾鷿鸀鸁鸂\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0",
+        "鸃鸄鸅鸆鸇鸈鸉鸊鸋鸌鸍鸎鸏鸐鸑鸒鸓鸔鸕鸖鸗鸘鸙鸚鸛鸜鸝鸞鸤鸧鸮鸰鸴鸻鸼鹀鹍鹐鹒鹓鹔鹖鹙鹝鹟鹠鹡鹢鹥鹮鹯鹲鹴鹵鹶鹷鹸鹹鹺鹻鹼鹽麀\0麁麃麄麅麆麉麊麌麍麎麏麐麑麔麕麖麗麘麙麚麛麜麞麠麡麢麣麤麥麧麨麩麪\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0",
+        "麫麬麭麮麯麰麱麲麳麵麶麷麹麺麼麿黀黁黂黃黅黆黇黈黊黋黌黐黒黓黕黖黗黙黚點黡黣黤黦黨黫黬黭黮黰黱黲黳黴黵黶黷黸黺黽黿鼀鼁鼂鼃鼄鼅\0鼆鼇鼈鼉鼊鼌鼏鼑鼒鼔鼕鼖鼘鼚鼛鼜鼝鼞鼟鼡鼣鼤鼥鼦鼧鼨鼩鼪鼫鼭鼮鼰鼱\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0",
+        "鼲鼳鼴鼵鼶鼸鼺鼼鼿齀齁齂齃齅齆齇齈齉齊齋齌齍齎齏齒齓齔齕齖齗齘齙齚齛齜齝齞齟齠齡齢齣齤齥齦齧齨齩齪齫齬齭齮齯齰齱齲齳齴齵齶齷齸\0齹齺齻齼齽齾龁龂龍龎龏龐龑龒龓龔龕龖龗龘龜龝龞龡龢龣龤龥郎凉秊裏隣\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0",
+        "兀嗀﨎﨏﨑﨓﨔礼﨟蘒﨡﨣﨤﨧﨨﨩\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0",
+    ),
+);
+
+/// The two-byte codes GB 18030 adds to GBK.
+pub(crate) static GB18030EXT: Sparse = Sparse::new(&[
+    (0xA140, '\u{e4c6}'),
+    (0xA141, '\u{e4c7}'),
+    (0xA142, '\u{e4c8}'),
+    (0xA143, '\u{e4c9}'),
+    (0xA144, '\u{e4ca}'),
+    (0xA145, '\u{e4cb}'),
+    (0xA146, '\u{e4cc}'),
+    (0xA147, '\u{e4cd}'),
+    (0xA148, '\u{e4ce}'),
+    (0xA149, '\u{e4cf}'),
+    (0xA14A, '\u{e4d0}'),
+    (0xA14B, '\u{e4d1}'),
+    (0xA14C, '\u{e4d2}'),
+    (0xA14D, '\u{e4d3}'),
+    (0xA14E, '\u{e4d4}'),
+    (0xA14F, '\u{e4d5}'),
+    (0xA150, '\u{e4d6}'),
+    (0xA151, '\u{e4d7}'),
+    (0xA152, '\u{e4d8}'),
+    (0xA153, '\u{e4d9}'),
+    (0xA154, '\u{e4da}'),
+    (0xA155, '\u{e4db}'),
+    (0xA156, '\u{e4dc}'),
+    (0xA157, '\u{e4dd}'),
+    (0xA158, '\u{e4de}'),
+    (0xA159, '\u{e4df}'),
+    (0xA15A, '\u{e4e0}'),
+    (0xA15B, '\u{e4e1}'),
+    (0xA15C, '\u{e4e2}'),
+    (0xA15D, '\u{e4e3}'),
+    (0xA15E, '\u{e4e4}'),
+    (0xA15F, '\u{e4e5}'),
+    (0xA160, '\u{e4e6}'),
+    (0xA161, '\u{e4e7}'),
+    (0xA162, '\u{e4e8}'),
+    (0xA163, '\u{e4e9}'),
+    (0xA164, '\u{e4ea}'),
+    (0xA165, '\u{e4eb}'),
+    (0xA166, '\u{e4ec}'),
+    (0xA167, '\u{e4ed}'),
+    (0xA168, '\u{e4ee}'),
+    (0xA169, '\u{e4ef}'),
+    (0xA16A, '\u{e4f0}'),
+    (0xA16B, '\u{e4f1}'),
+    (0xA16C, '\u{e4f2}'),
+    (0xA16D, '\u{e4f3}'),
+    (0xA16E, '\u{e4f4}'),
+    (0xA16F, '\u{e4f5}'),
+    (0xA170, '\u{e4f6}'),
+    (0xA171, '\u{e4f7}'),
+    (0xA172, '\u{e4f8}'),
+    (0xA173, '\u{e4f9}'),
+    (0xA174, '\u{e4fa}'),
+    (0xA175, '\u{e4fb}'),
+    (0xA176, '\u{e4fc}'),
+    (0xA177, '\u{e4fd}'),
+    (0xA178, '\u{e4fe}'),
+    (0xA179, '\u{e4ff}'),
+    (0xA17A, '\u{e500}'),
+    (0xA17B, '\u{e501}'),
+    (0xA17C, '\u{e502}'),
+    (0xA17D, '\u{e503}'),
+    (0xA17E, '\u{e504}'),
+    (0xA180, '\u{e505}'),
+    (0xA181, '\u{e506}'),
+    (0xA182, '\u{e507}'),
+    (0xA183, '\u{e508}'),
+    (0xA184, '\u{e509}'),
+    (0xA185, '\u{e50a}'),
+    (0xA186, '\u{e50b}'),
+    (0xA187, '\u{e50c}'),
+    (0xA188, '\u{e50d}'),
+    (0xA189, '\u{e50e}'),
+    (0xA18A, '\u{e50f}'),
+    (0xA18B, '\u{e510}'),
+    (0xA18C, '\u{e511}'),
+    (0xA18D, '\u{e512}'),
+    (0xA18E, '\u{e513}'),
+    (0xA18F, '\u{e514}'),
+    (0xA190, '\u{e515}'),
+    (0xA191, '\u{e516}'),
+    (0xA192, '\u{e517}'),
+    (0xA193, '\u{e518}'),
+    (0xA194, '\u{e519}'),
+    (0xA195, '\u{e51a}'),
+    (0xA196, '\u{e51b}'),
+    (0xA197, '\u{e51c}'),
+    (0xA198, '\u{e51d}'),
+    (0xA199, '\u{e51e}'),
+    (0xA19A, '\u{e51f}'),
+    (0xA19B, '\u{e520}'),
+    (0xA19C, '\u{e521}'),
+    (0xA19D, '\u{e522}'),
+    (0xA19E, '\u{e523}'),
+    (0xA19F, '\u{e524}'),
+    (0xA1A0, '\u{e525}'),
+    (0xA240, '\u{e526}'),
+    (0xA241, '\u{e527}'),
+    (0xA242, '\u{e528}'),
+    (0xA243, '\u{e529}'),
+    (0xA244, '\u{e52a}'),
+    (0xA245, '\u{e52b}'),
+    (0xA246, '\u{e52c}'),
+    (0xA247, '\u{e52d}'),
+    (0xA248, '\u{e52e}'),
+    (0xA249, '\u{e52f}'),
+    (0xA24A, '\u{e530}'),
+    (0xA24B, '\u{e531}'),
+    (0xA24C, '\u{e532}'),
+    (0xA24D, '\u{e533}'),
+    (0xA24E, '\u{e534}'),
+    (0xA24F, '\u{e535}'),
+    (0xA250, '\u{e536}'),
+    (0xA251, '\u{e537}'),
+    (0xA252, '\u{e538}'),
+    (0xA253, '\u{e539}'),
+    (0xA254, '\u{e53a}'),
+    (0xA255, '\u{e53b}'),
+    (0xA256, '\u{e53c}'),
+    (0xA257, '\u{e53d}'),
+    (0xA258, '\u{e53e}'),
+    (0xA259, '\u{e53f}'),
+    (0xA25A, '\u{e540}'),
+    (0xA25B, '\u{e541}'),
+    (0xA25C, '\u{e542}'),
+    (0xA25D, '\u{e543}'),
+    (0xA25E, '\u{e544}'),
+    (0xA25F, '\u{e545}'),
+    (0xA260, '\u{e546}'),
+    (0xA261, '\u{e547}'),
+    (0xA262, '\u{e548}'),
+    (0xA263, '\u{e549}'),
+    (0xA264, '\u{e54a}'),
+    (0xA265, '\u{e54b}'),
+    (0xA266, '\u{e54c}'),
+    (0xA267, '\u{e54d}'),
+    (0xA268, '\u{e54e}'),
+    (0xA269, '\u{e54f}'),
+    (0xA26A, '\u{e550}'),
+    (0xA26B, '\u{e551}'),
+    (0xA26C, '\u{e552}'),
+    (0xA26D, '\u{e553}'),
+    (0xA26E, '\u{e554}'),
+    (0xA26F, '\u{e555}'),
+    (0xA270, '\u{e556}'),
+    (0xA271, '\u{e557}'),
+    (0xA272, '\u{e558}'),
+    (0xA273, '\u{e559}'),
+    (0xA274, '\u{e55a}'),
+    (0xA275, '\u{e55b}'),
+    (0xA276, '\u{e55c}'),
+    (0xA277, '\u{e55d}'),
+    (0xA278, '\u{e55e}'),
+    (0xA279, '\u{e55f}'),
+    (0xA27A, '\u{e560}'),
+    (0xA27B, '\u{e561}'),
+    (0xA27C, '\u{e562}'),
+    (0xA27D, '\u{e563}'),
+    (0xA27E, '\u{e564}'),
+    (0xA280, '\u{e565}'),
+    (0xA281, '\u{e566}'),
+    (0xA282, '\u{e567}'),
+    (0xA283, '\u{e568}'),
+    (0xA284, '\u{e569}'),
+    (0xA285, '\u{e56a}'),
+    (0xA286, '\u{e56b}'),
+    (0xA287, '\u{e56c}'),
+    (0xA288, '\u{e56d}'),
+    (0xA289, '\u{e56e}'),
+    (0xA28A, '\u{e56f}'),
+    (0xA28B, '\u{e570}'),
+    (0xA28C, '\u{e571}'),
+    (0xA28D, '\u{e572}'),
+    (0xA28E, '\u{e573}'),
+    (0xA28F, '\u{e574}'),
+    (0xA290, '\u{e575}'),
+    (0xA291, '\u{e576}'),
+    (0xA292, '\u{e577}'),
+    (0xA293, '\u{e578}'),
+    (0xA294, '\u{e579}'),
+    (0xA295, '\u{e57a}'),
+    (0xA296, '\u{e57b}'),
+    (0xA297, '\u{e57c}'),
+    (0xA298, '\u{e57d}'),
+    (0xA299, '\u{e57e}'),
+    (0xA29A, '\u{e57f}'),
+    (0xA29B, '\u{e580}'),
+    (0xA29C, '\u{e581}'),
+    (0xA29D, '\u{e582}'),
+    (0xA29E, '\u{e583}'),
+    (0xA29F, '\u{e584}'),
+    (0xA2A0, '\u{e585}'),
+    (0xA2AB, '\u{e766}'),
+    (0xA2AC, '\u{e767}'),
+    (0xA2AD, '\u{e768}'),
+    (0xA2AE, '\u{e769}'),
+    (0xA2AF, '\u{e76a}'),
+    (0xA2B0, '\u{e76b}'),
+    (0xA2E3, '€'),
+    (0xA2E4, '\u{e76d}'),
+    (0xA2EF, '\u{e76e}'),
+    (0xA2F0, '\u{e76f}'),
+    (0xA2FD, '\u{e770}'),
+    (0xA2FE, '\u{e771}'),
+    (0xA340, '\u{e586}'),
+    (0xA341, '\u{e587}'),
+    (0xA342, '\u{e588}'),
+    (0xA343, '\u{e589}'),
+    (0xA344, '\u{e58a}'),
+    (0xA345, '\u{e58b}'),
+    (0xA346, '\u{e58c}'),
+    (0xA347, '\u{e58d}'),
+    (0xA348, '\u{e58e}'),
+    (0xA349, '\u{e58f}'),
+    (0xA34A, '\u{e590}'),
+    (0xA34B, '\u{e591}'),
+    (0xA34C, '\u{e592}'),
+    (0xA34D, '\u{e593}'),
+    (0xA34E, '\u{e594}'),
+    (0xA34F, '\u{e595}'),
+    (0xA350, '\u{e596}'),
+    (0xA351, '\u{e597}'),
+    (0xA352, '\u{e598}'),
+    (0xA353, '\u{e599}'),
+    (0xA354, '\u{e59a}'),
+    (0xA355, '\u{e59b}'),
+    (0xA356, '\u{e59c}'),
+    (0xA357, '\u{e59d}'),
+    (0xA358, '\u{e59e}'),
+    (0xA359, '\u{e59f}'),
+    (0xA35A, '\u{e5a0}'),
+    (0xA35B, '\u{e5a1}'),
+    (0xA35C, '\u{e5a2}'),
+    (0xA35D, '\u{e5a3}'),
+    (0xA35E, '\u{e5a4}'),
+    (0xA35F, '\u{e5a5}'),
+    (0xA360, '\u{e5a6}'),
+    (0xA361, '\u{e5a7}'),
+    (0xA362, '\u{e5a8}'),
+    (0xA363, '\u{e5a9}'),
+    (0xA364, '\u{e5aa}'),
+    (0xA365, '\u{e5ab}'),
+    (0xA366, '\u{e5ac}'),
+    (0xA367, '\u{e5ad}'),
+    (0xA368, '\u{e5ae}'),
+    (0xA369, '\u{e5af}'),
+    (0xA36A, '\u{e5b0}'),
+    (0xA36B, '\u{e5b1}'),
+    (0xA36C, '\u{e5b2}'),
+    (0xA36D, '\u{e5b3}'),
+    (0xA36E, '\u{e5b4}'),
+    (0xA36F, '\u{e5b5}'),
+    (0xA370, '\u{e5b6}'),
+    (0xA371, '\u{e5b7}'),
+    (0xA372, '\u{e5b8}'),
+    (0xA373, '\u{e5b9}'),
+    (0xA374, '\u{e5ba}'),
+    (0xA375, '\u{e5bb}'),
+    (0xA376, '\u{e5bc}'),
+    (0xA377, '\u{e5bd}'),
+    (0xA378, '\u{e5be}'),
+    (0xA379, '\u{e5bf}'),
+    (0xA37A, '\u{e5c0}'),
+    (0xA37B, '\u{e5c1}'),
+    (0xA37C, '\u{e5c2}'),
+    (0xA37D, '\u{e5c3}'),
+    (0xA37E, '\u{e5c4}'),
+    (0xA380, '\u{e5c5}'),
+    (0xA381, '\u{e5c6}'),
+    (0xA382, '\u{e5c7}'),
+    (0xA383, '\u{e5c8}'),
+    (0xA384, '\u{e5c9}'),
+    (0xA385, '\u{e5ca}'),
+    (0xA386, '\u{e5cb}'),
+    (0xA387, '\u{e5cc}'),
+    (0xA388, '\u{e5cd}'),
+    (0xA389, '\u{e5ce}'),
+    (0xA38A, '\u{e5cf}'),
+    (0xA38B, '\u{e5d0}'),
+    (0xA38C, '\u{e5d1}'),
+    (0xA38D, '\u{e5d2}'),
+    (0xA38E, '\u{e5d3}'),
+    (0xA38F, '\u{e5d4}'),
+    (0xA390, '\u{e5d5}'),
+    (0xA391, '\u{e5d6}'),
+    (0xA392, '\u{e5d7}'),
+    (0xA393, '\u{e5d8}'),
+    (0xA394, '\u{e5d9}'),
+    (0xA395, '\u{e5da}'),
+    (0xA396, '\u{e5db}'),
+    (0xA397, '\u{e5dc}'),
+    (0xA398, '\u{e5dd}'),
+    (0xA399, '\u{e5de}'),
+    (0xA39A, '\u{e5df}'),
+    (0xA39B, '\u{e5e0}'),
+    (0xA39C, '\u{e5e1}'),
+    (0xA39D, '\u{e5e2}'),
+    (0xA39E, '\u{e5e3}'),
+    (0xA39F, '\u{e5e4}'),
+    (0xA3A0, '\u{e5e5}'),
+    (0xA440, '\u{e5e6}'),
+    (0xA441, '\u{e5e7}'),
+    (0xA442, '\u{e5e8}'),
+    (0xA443, '\u{e5e9}'),
+    (0xA444, '\u{e5ea}'),
+    (0xA445, '\u{e5eb}'),
+    (0xA446, '\u{e5ec}'),
+    (0xA447, '\u{e5ed}'),
+    (0xA448, '\u{e5ee}'),
+    (0xA449, '\u{e5ef}'),
+    (0xA44A, '\u{e5f0}'),
+    (0xA44B, '\u{e5f1}'),
+    (0xA44C, '\u{e5f2}'),
+    (0xA44D, '\u{e5f3}'),
+    (0xA44E, '\u{e5f4}'),
+    (0xA44F, '\u{e5f5}'),
+    (0xA450, '\u{e5f6}'),
+    (0xA451, '\u{e5f7}'),
+    (0xA452, '\u{e5f8}'),
+    (0xA453, '\u{e5f9}'),
+    (0xA454, '\u{e5fa}'),
+    (0xA455, '\u{e5fb}'),
+    (0xA456, '\u{e5fc}'),
+    (0xA457, '\u{e5fd}'),
+    (0xA458, '\u{e5fe}'),
+    (0xA459, '\u{e5ff}'),
+    (0xA45A, '\u{e600}'),
+    (0xA45B, '\u{e601}'),
+    (0xA45C, '\u{e602}'),
+    (0xA45D, '\u{e603}'),
+    (0xA45E, '\u{e604}'),
+    (0xA45F, '\u{e605}'),
+    (0xA460, '\u{e606}'),
+    (0xA461, '\u{e607}'),
+    (0xA462, '\u{e608}'),
+    (0xA463, '\u{e609}'),
+    (0xA464, '\u{e60a}'),
+    (0xA465, '\u{e60b}'),
+    (0xA466, '\u{e60c}'),
+    (0xA467, '\u{e60d}'),
+    (0xA468, '\u{e60e}'),
+    (0xA469, '\u{e60f}'),
+    (0xA46A, '\u{e610}'),
+    (0xA46B, '\u{e611}'),
+    (0xA46C, '\u{e612}'),
+    (0xA46D, '\u{e613}'),
+    (0xA46E, '\u{e614}'),
+    (0xA46F, '\u{e615}'),
+    (0xA470, '\u{e616}'),
+    (0xA471, '\u{e617}'),
+    (0xA472, '\u{e618}'),
+    (0xA473, '\u{e619}'),
+    (0xA474, '\u{e61a}'),
+    (0xA475, '\u{e61b}'),
+    (0xA476, '\u{e61c}'),
+    (0xA477, '\u{e61d}'),
+    (0xA478, '\u{e61e}'),
+    (0xA479, '\u{e61f}'),
+    (0xA47A, '\u{e620}'),
+    (0xA47B, '\u{e621}'),
+    (0xA47C, '\u{e622}'),
+    (0xA47D, '\u{e623}'),
+    (0xA47E, '\u{e624}'),
+    (0xA480, '\u{e625}'),
+    (0xA481, '\u{e626}'),
+    (0xA482, '\u{e627}'),
+    (0xA483, '\u{e628}'),
+    (0xA484, '\u{e629}'),
+    (0xA485, '\u{e62a}'),
+    (0xA486, '\u{e62b}'),
+    (0xA487, '\u{e62c}'),
+    (0xA488, '\u{e62d}'),
+    (0xA489, '\u{e62e}'),
+    (0xA48A, '\u{e62f}'),
+    (0xA48B, '\u{e630}'),
+    (0xA48C, '\u{e631}'),
+    (0xA48D, '\u{e632}'),
+    (0xA48E, '\u{e633}'),
+    (0xA48F, '\u{e634}'),
+    (0xA490, '\u{e635}'),
+    (0xA491, '\u{e636}'),
+    (0xA492, '\u{e637}'),
+    (0xA493, '\u{e638}'),
+    (0xA494, '\u{e639}'),
+    (0xA495, '\u{e63a}'),
+    (0xA496, '\u{e63b}'),
+    (0xA497, '\u{e63c}'),
+    (0xA498, '\u{e63d}'),
+    (0xA499, '\u{e63e}'),
+    (0xA49A, '\u{e63f}'),
+    (0xA49B, '\u{e640}'),
+    (0xA49C, '\u{e641}'),
+    (0xA49D, '\u{e642}'),
+    (0xA49E, '\u{e643}'),
+    (0xA49F, '\u{e644}'),
+    (0xA4A0, '\u{e645}'),
+    (0xA4F4, '\u{e772}'),
+    (0xA4F5, '\u{e773}'),
+    (0xA4F6, '\u{e774}'),
+    (0xA4F7, '\u{e775}'),
+    (0xA4F8, '\u{e776}'),
+    (0xA4F9, '\u{e777}'),
+    (0xA4FA, '\u{e778}'),
+    (0xA4FB, '\u{e779}'),
+    (0xA4FC, '\u{e77a}'),
+    (0xA4FD, '\u{e77b}'),
+    (0xA4FE, '\u{e77c}'),
+    (0xA540, '\u{e646}'),
+    (0xA541, '\u{e647}'),
+    (0xA542, '\u{e648}'),
+    (0xA543, '\u{e649}'),
+    (0xA544, '\u{e64a}'),
+    (0xA545, '\u{e64b}'),
+    (0xA546, '\u{e64c}'),
+    (0xA547, '\u{e64d}'),
+    (0xA548, '\u{e64e}'),
+    (0xA549, '\u{e64f}'),
+    (0xA54A, '\u{e650}'),
+    (0xA54B, '\u{e651}'),
+    (0xA54C, '\u{e652}'),
+    (0xA54D, '\u{e653}'),
+    (0xA54E, '\u{e654}'),
+    (0xA54F, '\u{e655}'),
+    (0xA550, '\u{e656}'),
+    (0xA551, '\u{e657}'),
+    (0xA552, '\u{e658}'),
+    (0xA553, '\u{e659}'),
+    (0xA554, '\u{e65a}'),
+    (0xA555, '\u{e65b}'),
+    (0xA556, '\u{e65c}'),
+    (0xA557, '\u{e65d}'),
+    (0xA558, '\u{e65e}'),
+    (0xA559, '\u{e65f}'),
+    (0xA55A, '\u{e660}'),
+    (0xA55B, '\u{e661}'),
+    (0xA55C, '\u{e662}'),
+    (0xA55D, '\u{e663}'),
+    (0xA55E, '\u{e664}'),
+    (0xA55F, '\u{e665}'),
+    (0xA560, '\u{e666}'),
+    (0xA561, '\u{e667}'),
+    (0xA562, '\u{e668}'),
+    (0xA563, '\u{e669}'),
+    (0xA564, '\u{e66a}'),
+    (0xA565, '\u{e66b}'),
+    (0xA566, '\u{e66c}'),
+    (0xA567, '\u{e66d}'),
+    (0xA568, '\u{e66e}'),
+    (0xA569, '\u{e66f}'),
+    (0xA56A, '\u{e670}'),
+    (0xA56B, '\u{e671}'),
+    (0xA56C, '\u{e672}'),
+    (0xA56D, '\u{e673}'),
+    (0xA56E, '\u{e674}'),
+    (0xA56F, '\u{e675}'),
+    (0xA570, '\u{e676}'),
+    (0xA571, '\u{e677}'),
+    (0xA572, '\u{e678}'),
+    (0xA573, '\u{e679}'),
+    (0xA574, '\u{e67a}'),
+    (0xA575, '\u{e67b}'),
+    (0xA576, '\u{e67c}'),
+    (0xA577, '\u{e67d}'),
+    (0xA578, '\u{e67e}'),
+    (0xA579, '\u{e67f}'),
+    (0xA57A, '\u{e680}'),
+    (0xA57B, '\u{e681}'),
+    (0xA57C, '\u{e682}'),
+    (0xA57D, '\u{e683}'),
+    (0xA57E, '\u{e684}'),
+    (0xA580, '\u{e685}'),
+    (0xA581, '\u{e686}'),
+    (0xA582, '\u{e687}'),
+    (0xA583, '\u{e688}'),
+    (0xA584, '\u{e689}'),
+    (0xA585, '\u{e68a}'),
+    (0xA586, '\u{e68b}'),
+    (0xA587, '\u{e68c}'),
+    (0xA588, '\u{e68d}'),
+    (0xA589, '\u{e68e}'),
+    (0xA58A, '\u{e68f}'),
+    (0xA58B, '\u{e690}'),
+    (0xA58C, '\u{e691}'),
+    (0xA58D, '\u{e692}'),
+    (0xA58E, '\u{e693}'),
+    (0xA58F, '\u{e694}'),
+    (0xA590, '\u{e695}'),
+    (0xA591, '\u{e696}'),
+    (0xA592, '\u{e697}'),
+    (0xA593, '\u{e698}'),
+    (0xA594, '\u{e699}'),
+    (0xA595, '\u{e69a}'),
+    (0xA596, '\u{e69b}'),
+    (0xA597, '\u{e69c}'),
+    (0xA598, '\u{e69d}'),
+    (0xA599, '\u{e69e}'),
+    (0xA59A, '\u{e69f}'),
+    (0xA59B, '\u{e6a0}'),
+    (0xA59C, '\u{e6a1}'),
+    (0xA59D, '\u{e6a2}'),
+    (0xA59E, '\u{e6a3}'),
+    (0xA59F, '\u{e6a4}'),
+    (0xA5A0, '\u{e6a5}'),
+    (0xA5F7, '\u{e77d}'),
+    (0xA5F8, '\u{e77e}'),
+    (0xA5F9, '\u{e77f}'),
+    (0xA5FA, '\u{e780}'),
+    (0xA5FB, '\u{e781}'),
+    (0xA5FC, '\u{e782}'),
+    (0xA5FD, '\u{e783}'),
+    (0xA5FE, '\u{e784}'),
+    (0xA640, '\u{e6a6}'),
+    (0xA641, '\u{e6a7}'),
+    (0xA642, '\u{e6a8}'),
+    (0xA643, '\u{e6a9}'),
+    (0xA644, '\u{e6aa}'),
+    (0xA645, '\u{e6ab}'),
+    (0xA646, '\u{e6ac}'),
+    (0xA647, '\u{e6ad}'),
+    (0xA648, '\u{e6ae}'),
+    (0xA649, '\u{e6af}'),
+    (0xA64A, '\u{e6b0}'),
+    (0xA64B, '\u{e6b1}'),
+    (0xA64C, '\u{e6b2}'),
+    (0xA64D, '\u{e6b3}'),
+    (0xA64E, '\u{e6b4}'),
+    (0xA64F, '\u{e6b5}'),
+    (0xA650, '\u{e6b6}'),
+    (0xA651, '\u{e6b7}'),
+    (0xA652, '\u{e6b8}'),
+    (0xA653, '\u{e6b9}'),
+    (0xA654, '\u{e6ba}'),
+    (0xA655, '\u{e6bb}'),
+    (0xA656, '\u{e6bc}'),
+    (0xA657, '\u{e6bd}'),
+    (0xA658, '\u{e6be}'),
+    (0xA659, '\u{e6bf}'),
+    (0xA65A, '\u{e6c0}'),
+    (0xA65B, '\u{e6c1}'),
+    (0xA65C, '\u{e6c2}'),
+    (0xA65D, '\u{e6c3}'),
+    (0xA65E, '\u{e6c4}'),
+    (0xA65F, '\u{e6c5}'),
+    (0xA660, '\u{e6c6}'),
+    (0xA661, '\u{e6c7}'),
+    (0xA662, '\u{e6c8}'),
+    (0xA663, '\u{e6c9}'),
+    (0xA664, '\u{e6ca}'),
+    (0xA665, '\u{e6cb}'),
+    (0xA666, '\u{e6cc}'),
+    (0xA667, '\u{e6cd}'),
+    (0xA668, '\u{e6ce}'),
+    (0xA669, '\u{e6cf}'),
+    (0xA66A, '\u{e6d0}'),
+    (0xA66B, '\u{e6d1}'),
+    (0xA66C, '\u{e6d2}'),
+    (0xA66D, '\u{e6d3}'),
+    (0xA66E, '\u{e6d4}'),
+    (0xA66F, '\u{e6d5}'),
+    (0xA670, '\u{e6d6}'),
+    (0xA671, '\u{e6d7}'),
+    (0xA672, '\u{e6d8}'),
+    (0xA673, '\u{e6d9}'),
+    (0xA674, '\u{e6da}'),
+    (0xA675, '\u{e6db}'),
+    (0xA676, '\u{e6dc}'),
+    (0xA677, '\u{e6dd}'),
+    (0xA678, '\u{e6de}'),
+    (0xA679, '\u{e6df}'),
+    (0xA67A, '\u{e6e0}'),
+    (0xA67B, '\u{e6e1}'),
+    (0xA67C, '\u{e6e2}'),
+    (0xA67D, '\u{e6e3}'),
+    (0xA67E, '\u{e6e4}'),
+    (0xA680, '\u{e6e5}'),
+    (0xA681, '\u{e6e6}'),
+    (0xA682, '\u{e6e7}'),
+    (0xA683, '\u{e6e8}'),
+    (0xA684, '\u{e6e9}'),
+    (0xA685, '\u{e6ea}'),
+    (0xA686, '\u{e6eb}'),
+    (0xA687, '\u{e6ec}'),
+    (0xA688, '\u{e6ed}'),
+    (0xA689, '\u{e6ee}'),
+    (0xA68A, '\u{e6ef}'),
+    (0xA68B, '\u{e6f0}'),
+    (0xA68C, '\u{e6f1}'),
+    (0xA68D, '\u{e6f2}'),
+    (0xA68E, '\u{e6f3}'),
+    (0xA68F, '\u{e6f4}'),
+    (0xA690, '\u{e6f5}'),
+    (0xA691, '\u{e6f6}'),
+    (0xA692, '\u{e6f7}'),
+    (0xA693, '\u{e6f8}'),
+    (0xA694, '\u{e6f9}'),
+    (0xA695, '\u{e6fa}'),
+    (0xA696, '\u{e6fb}'),
+    (0xA697, '\u{e6fc}'),
+    (0xA698, '\u{e6fd}'),
+    (0xA699, '\u{e6fe}'),
+    (0xA69A, '\u{e6ff}'),
+    (0xA69B, '\u{e700}'),
+    (0xA69C, '\u{e701}'),
+    (0xA69D, '\u{e702}'),
+    (0xA69E, '\u{e703}'),
+    (0xA69F, '\u{e704}'),
+    (0xA6A0, '\u{e705}'),
+    (0xA6B9, '\u{e785}'),
+    (0xA6BA, '\u{e786}'),
+    (0xA6BB, '\u{e787}'),
+    (0xA6BC, '\u{e788}'),
+    (0xA6BD, '\u{e789}'),
+    (0xA6BE, '\u{e78a}'),
+    (0xA6BF, '\u{e78b}'),
+    (0xA6C0, '\u{e78c}'),
+    (0xA6D9, '\u{e78d}'),
+    (0xA6DA, '\u{e78e}'),
+    (0xA6DB, '\u{e78f}'),
+    (0xA6DC, '\u{e790}'),
+    (0xA6DD, '\u{e791}'),
+    (0xA6DE, '\u{e792}'),
+    (0xA6DF, '\u{e793}'),
+    (0xA6EC, '\u{e794}'),
+    (0xA6ED, '\u{e795}'),
+    (0xA6F3, '\u{e796}'),
+    (0xA6F6, '\u{e797}'),
+    (0xA6F7, '\u{e798}'),
+    (0xA6F8, '\u{e799}'),
+    (0xA6F9, '\u{e79a}'),
+    (0xA6FA, '\u{e79b}'),
+    (0xA6FB, '\u{e79c}'),
+    (0xA6FC, '\u{e79d}'),
+    (0xA6FD, '\u{e79e}'),
+    (0xA6FE, '\u{e79f}'),
+    (0xA740, '\u{e706}'),
+    (0xA741, '\u{e707}'),
+    (0xA742, '\u{e708}'),
+    (0xA743, '\u{e709}'),
+    (0xA744, '\u{e70a}'),
+    (0xA745, '\u{e70b}'),
+    (0xA746, '\u{e70c}'),
+    (0xA747, '\u{e70d}'),
+    (0xA748, '\u{e70e}'),
+    (0xA749, '\u{e70f}'),
+    (0xA74A, '\u{e710}'),
+    (0xA74B, '\u{e711}'),
+    (0xA74C, '\u{e712}'),
+    (0xA74D, '\u{e713}'),
+    (0xA74E, '\u{e714}'),
+    (0xA74F, '\u{e715}'),
+    (0xA750, '\u{e716}'),
+    (0xA751, '\u{e717}'),
+    (0xA752, '\u{e718}'),
+    (0xA753, '\u{e719}'),
+    (0xA754, '\u{e71a}'),
+    (0xA755, '\u{e71b}'),
+    (0xA756, '\u{e71c}'),
+    (0xA757, '\u{e71d}'),
+    (0xA758, '\u{e71e}'),
+    (0xA759, '\u{e71f}'),
+    (0xA75A, '\u{e720}'),
+    (0xA75B, '\u{e721}'),
+    (0xA75C, '\u{e722}'),
+    (0xA75D, '\u{e723}'),
+    (0xA75E, '\u{e724}'),
+    (0xA75F, '\u{e725}'),
+    (0xA760, '\u{e726}'),
+    (0xA761, '\u{e727}'),
+    (0xA762, '\u{e728}'),
+    (0xA763, '\u{e729}'),
+    (0xA764, '\u{e72a}'),
+    (0xA765, '\u{e72b}'),
+    (0xA766, '\u{e72c}'),
+    (0xA767, '\u{e72d}'),
+    (0xA768, '\u{e72e}'),
+    (0xA769, '\u{e72f}'),
+    (0xA76A, '\u{e730}'),
+    (0xA76B, '\u{e731}'),
+    (0xA76C, '\u{e732}'),
+    (0xA76D, '\u{e733}'),
+    (0xA76E, '\u{e734}'),
+    (0xA76F, '\u{e735}'),
+    (0xA770, '\u{e736}'),
+    (0xA771, '\u{e737}'),
+    (0xA772, '\u{e738}'),
+    (0xA773, '\u{e739}'),
+    (0xA774, '\u{e73a}'),
+    (0xA775, '\u{e73b}'),
+    (0xA776, '\u{e73c}'),
+    (0xA777, '\u{e73d}'),
+    (0xA778, '\u{e73e}'),
+    (0xA779, '\u{e73f}'),
+    (0xA77A, '\u{e740}'),
+    (0xA77B, '\u{e741}'),
+    (0xA77C, '\u{e742}'),
+    (0xA77D, '\u{e743}'),
+    (0xA77E, '\u{e744}'),
+    (0xA780, '\u{e745}'),
+    (0xA781, '\u{e746}'),
+    (0xA782, '\u{e747}'),
+    (0xA783, '\u{e748}'),
+    (0xA784, '\u{e749}'),
+    (0xA785, '\u{e74a}'),
+    (0xA786, '\u{e74b}'),
+    (0xA787, '\u{e74c}'),
+    (0xA788, '\u{e74d}'),
+    (0xA789, '\u{e74e}'),
+    (0xA78A, '\u{e74f}'),
+    (0xA78B, '\u{e750}'),
+    (0xA78C, '\u{e751}'),
+    (0xA78D, '\u{e752}'),
+    (0xA78E, '\u{e753}'),
+    (0xA78F, '\u{e754}'),
+    (0xA790, '\u{e755}'),
+    (0xA791, '\u{e756}'),
+    (0xA792, '\u{e757}'),
+    (0xA793, '\u{e758}'),
+    (0xA794, '\u{e759}'),
+    (0xA795, '\u{e75a}'),
+    (0xA796, '\u{e75b}'),
+    (0xA797, '\u{e75c}'),
+    (0xA798, '\u{e75d}'),
+    (0xA799, '\u{e75e}'),
+    (0xA79A, '\u{e75f}'),
+    (0xA79B, '\u{e760}'),
+    (0xA79C, '\u{e761}'),
+    (0xA79D, '\u{e762}'),
+    (0xA79E, '\u{e763}'),
+    (0xA79F, '\u{e764}'),
+    (0xA7A0, '\u{e765}'),
+    (0xA7C2, '\u{e7a0}'),
+    (0xA7C3, '\u{e7a1}'),
+    (0xA7C4, '\u{e7a2}'),
+    (0xA7C5, '\u{e7a3}'),
+    (0xA7C6, '\u{e7a4}'),
+    (0xA7C7, '\u{e7a5}'),
+    (0xA7C8, '\u{e7a6}'),
+    (0xA7C9, '\u{e7a7}'),
+    (0xA7CA, '\u{e7a8}'),
+    (0xA7CB, '\u{e7a9}'),
+    (0xA7CC, '\u{e7aa}'),
+    (0xA7CD, '\u{e7ab}'),
+    (0xA7CE, '\u{e7ac}'),
+    (0xA7CF, '\u{e7ad}'),
+    (0xA7D0, '\u{e7ae}'),
+    (0xA7F2, '\u{e7af}'),
+    (0xA7F3, '\u{e7b0}'),
+    (0xA7F4, '\u{e7b1}'),
+    (0xA7F5, '\u{e7b2}'),
+    (0xA7F6, '\u{e7b3}'),
+    (0xA7F7, '\u{e7b4}'),
+    (0xA7F8, '\u{e7b5}'),
+    (0xA7F9, '\u{e7b6}'),
+    (0xA7FA, '\u{e7b7}'),
+    (0xA7FB, '\u{e7b8}'),
+    (0xA7FC, '\u{e7b9}'),
+    (0xA7FD, '\u{e7ba}'),
+    (0xA7FE, '\u{e7bb}'),
+    (0xA896, '\u{e7bc}'),
+    (0xA897, '\u{e7bd}'),
+    (0xA898, '\u{e7be}'),
+    (0xA899, '\u{e7bf}'),
+    (0xA89A, '\u{e7c0}'),
+    (0xA89B, '\u{e7c1}'),
+    (0xA89C, '\u{e7c2}'),
+    (0xA89D, '\u{e7c3}'),
+    (0xA89E, '\u{e7c4}'),
+    (0xA89F, '\u{e7c5}'),
+    (0xA8A0, '\u{e7c6}'),
+    (0xA8BC, '\u{e7c7}'),
+    (0xA8BF, 'ǹ'),
+    (0xA8C1, '\u{e7c9}'),
+    (0xA8C2, '\u{e7ca}'),
+    (0xA8C3, '\u{e7cb}'),
+    (0xA8C4, '\u{e7cc}'),
+    (0xA8EA, '\u{e7cd}'),
+    (0xA8EB, '\u{e7ce}'),
+    (0xA8EC, '\u{e7cf}'),
+    (0xA8ED, '\u{e7d0}'),
+    (0xA8EE, '\u{e7d1}'),
+    (0xA8EF, '\u{e7d2}'),
+    (0xA8F0, '\u{e7d3}'),
+    (0xA8F1, '\u{e7d4}'),
+    (0xA8F2, '\u{e7d5}'),
+    (0xA8F3, '\u{e7d6}'),
+    (0xA8F4, '\u{e7d7}'),
+    (0xA8F5, '\u{e7d8}'),
+    (0xA8F6, '\u{e7d9}'),
+    (0xA8F7, '\u{e7da}'),
+    (0xA8F8, '\u{e7db}'),
+    (0xA8F9, '\u{e7dc}'),
+    (0xA8FA, '\u{e7dd}'),
+    (0xA8FB, '\u{e7de}'),
+    (0xA8FC, '\u{e7df}'),
+    (0xA8FD, '\u{e7e0}'),
+    (0xA8FE, '\u{e7e1}'),
+    (0xA958, '\u{e7e2}'),
+    (0xA95B, '\u{e7e3}'),
+    (0xA95D, '\u{e7e4}'),
+    (0xA95E, '\u{e7e5}'),
+    (0xA95F, '\u{e7e6}'),
+    (0xA989, '〾'),
+    (0xA98A, '⿰'),
+    (0xA98B, '⿱'),
+    (0xA98C, '⿲'),
+    (0xA98D, '⿳'),
+    (0xA98E, '⿴'),
+    (0xA98F, '⿵'),
+    (0xA990, '⿶'),
+    (0xA991, '⿷'),
+    (0xA992, '⿸'),
+    (0xA993, '⿹'),
+    (0xA994, '⿺'),
+    (0xA995, '⿻'),
+    (0xA997, '\u{e7f4}'),
+    (0xA998, '\u{e7f5}'),
+    (0xA999, '\u{e7f6}'),
+    (0xA99A, '\u{e7f7}'),
+    (0xA99B, '\u{e7f8}'),
+    (0xA99C, '\u{e7f9}'),
+    (0xA99D, '\u{e7fa}'),
+    (0xA99E, '\u{e7fb}'),
+    (0xA99F, '\u{e7fc}'),
+    (0xA9A0, '\u{e7fd}'),
+    (0xA9A1, '\u{e7fe}'),
+    (0xA9A2, '\u{e7ff}'),
+    (0xA9A3, '\u{e800}'),
+    (0xA9F0, '\u{e801}'),
+    (0xA9F1, '\u{e802}'),
+    (0xA9F2, '\u{e803}'),
+    (0xA9F3, '\u{e804}'),
+    (0xA9F4, '\u{e805}'),
+    (0xA9F5, '\u{e806}'),
+    (0xA9F6, '\u{e807}'),
+    (0xA9F7, '\u{e808}'),
+    (0xA9F8, '\u{e809}'),
+    (0xA9F9, '\u{e80a}'),
+    (0xA9FA, '\u{e80b}'),
+    (0xA9FB, '\u{e80c}'),
+    (0xA9FC, '\u{e80d}'),
+    (0xA9FD, '\u{e80e}'),
+    (0xA9FE, '\u{e80f}'),
+    (0xAAA1, '\u{e000}'),
+    (0xAAA2, '\u{e001}'),
+    (0xAAA3, '\u{e002}'),
+    (0xAAA4, '\u{e003}'),
+    (0xAAA5, '\u{e004}'),
+    (0xAAA6, '\u{e005}'),
+    (0xAAA7, '\u{e006}'),
+    (0xAAA8, '\u{e007}'),
+    (0xAAA9, '\u{e008}'),
+    (0xAAAA, '\u{e009}'),
+    (0xAAAB, '\u{e00a}'),
+    (0xAAAC, '\u{e00b}'),
+    (0xAAAD, '\u{e00c}'),
+    (0xAAAE, '\u{e00d}'),
+    (0xAAAF, '\u{e00e}'),
+    (0xAAB0, '\u{e00f}'),
+    (0xAAB1, '\u{e010}'),
+    (0xAAB2, '\u{e011}'),
+    (0xAAB3, '\u{e012}'),
+    (0xAAB4, '\u{e013}'),
+    (0xAAB5, '\u{e014}'),
+    (0xAAB6, '\u{e015}'),
+    (0xAAB7, '\u{e016}'),
+    (0xAAB8, '\u{e017}'),
+    (0xAAB9, '\u{e018}'),
+    (0xAABA, '\u{e019}'),
+    (0xAABB, '\u{e01a}'),
+    (0xAABC, '\u{e01b}'),
+    (0xAABD, '\u{e01c}'),
+    (0xAABE, '\u{e01d}'),
+    (0xAABF, '\u{e01e}'),
+    (0xAAC0, '\u{e01f}'),
+    (0xAAC1, '\u{e020}'),
+    (0xAAC2, '\u{e021}'),
+    (0xAAC3, '\u{e022}'),
+    (0xAAC4, '\u{e023}'),
+    (0xAAC5, '\u{e024}'),
+    (0xAAC6, '\u{e025}'),
+    (0xAAC7, '\u{e026}'),
+    (0xAAC8, '\u{e027}'),
+    (0xAAC9, '\u{e028}'),
+    (0xAACA, '\u{e029}'),
+    (0xAACB, '\u{e02a}'),
+    (0xAACC, '\u{e02b}'),
+    (0xAACD, '\u{e02c}'),
+    (0xAACE, '\u{e02d}'),
+    (0xAACF, '\u{e02e}'),
+    (0xAAD0, '\u{e02f}'),
+    (0xAAD1, '\u{e030}'),
+    (0xAAD2, '\u{e031}'),
+    (0xAAD3, '\u{e032}'),
+    (0xAAD4, '\u{e033}'),
+    (0xAAD5, '\u{e034}'),
+    (0xAAD6, '\u{e035}'),
+    (0xAAD7, '\u{e036}'),
+    (0xAAD8, '\u{e037}'),
+    (0xAAD9, '\u{e038}'),
+    (0xAADA, '\u{e039}'),
+    (0xAADB, '\u{e03a}'),
+    (0xAADC, '\u{e03b}'),
+    (0xAADD, '\u{e03c}'),
+    (0xAADE, '\u{e03d}'),
+    (0xAADF, '\u{e03e}'),
+    (0xAAE0, '\u{e03f}'),
+    (0xAAE1, '\u{e040}'),
+    (0xAAE2, '\u{e041}'),
+    (0xAAE3, '\u{e042}'),
+    (0xAAE4, '\u{e043}'),
+    (0xAAE5, '\u{e044}'),
+    (0xAAE6, '\u{e045}'),
+    (0xAAE7, '\u{e046}'),
+    (0xAAE8, '\u{e047}'),
+    (0xAAE9, '\u{e048}'),
+    (0xAAEA, '\u{e049}'),
+    (0xAAEB, '\u{e04a}'),
+    (0xAAEC, '\u{e04b}'),
+    (0xAAED, '\u{e04c}'),
+    (0xAAEE, '\u{e04d}'),
+    (0xAAEF, '\u{e04e}'),
+    (0xAAF0, '\u{e04f}'),
+    (0xAAF1, '\u{e050}'),
+    (0xAAF2, '\u{e051}'),
+    (0xAAF3, '\u{e052}'),
+    (0xAAF4, '\u{e053}'),
+    (0xAAF5, '\u{e054}'),
+    (0xAAF6, '\u{e055}'),
+    (0xAAF7, '\u{e056}'),
+    (0xAAF8, '\u{e057}'),
+    (0xAAF9, '\u{e058}'),
+    (0xAAFA, '\u{e059}'),
+    (0xAAFB, '\u{e05a}'),
+    (0xAAFC, '\u{e05b}'),
+    (0xAAFD, '\u{e05c}'),
+    (0xAAFE, '\u{e05d}'),
+    (0xABA1, '\u{e05e}'),
+    (0xABA2, '\u{e05f}'),
+    (0xABA3, '\u{e060}'),
+    (0xABA4, '\u{e061}'),
+    (0xABA5, '\u{e062}'),
+    (0xABA6, '\u{e063}'),
+    (0xABA7, '\u{e064}'),
+    (0xABA8, '\u{e065}'),
+    (0xABA9, '\u{e066}'),
+    (0xABAA, '\u{e067}'),
+    (0xABAB, '\u{e068}'),
+    (0xABAC, '\u{e069}'),
+    (0xABAD, '\u{e06a}'),
+    (0xABAE, '\u{e06b}'),
+    (0xABAF, '\u{e06c}'),
+    (0xABB0, '\u{e06d}'),
+    (0xABB1, '\u{e06e}'),
+    (0xABB2, '\u{e06f}'),
+    (0xABB3, '\u{e070}'),
+    (0xABB4, '\u{e071}'),
+    (0xABB5, '\u{e072}'),
+    (0xABB6, '\u{e073}'),
+    (0xABB7, '\u{e074}'),
+    (0xABB8, '\u{e075}'),
+    (0xABB9, '\u{e076}'),
+    (0xABBA, '\u{e077}'),
+    (0xABBB, '\u{e078}'),
+    (0xABBC, '\u{e079}'),
+    (0xABBD, '\u{e07a}'),
+    (0xABBE, '\u{e07b}'),
+    (0xABBF, '\u{e07c}'),
+    (0xABC0, '\u{e07d}'),
+    (0xABC1, '\u{e07e}'),
+    (0xABC2, '\u{e07f}'),
+    (0xABC3, '\u{e080}'),
+    (0xABC4, '\u{e081}'),
+    (0xABC5, '\u{e082}'),
+    (0xABC6, '\u{e083}'),
+    (0xABC7, '\u{e084}'),
+    (0xABC8, '\u{e085}'),
+    (0xABC9, '\u{e086}'),
+    (0xABCA, '\u{e087}'),
+    (0xABCB, '\u{e088}'),
+    (0xABCC, '\u{e089}'),
+    (0xABCD, '\u{e08a}'),
+    (0xABCE, '\u{e08b}'),
+    (0xABCF, '\u{e08c}'),
+    (0xABD0, '\u{e08d}'),
+    (0xABD1, '\u{e08e}'),
+    (0xABD2, '\u{e08f}'),
+    (0xABD3, '\u{e090}'),
+    (0xABD4, '\u{e091}'),
+    (0xABD5, '\u{e092}'),
+    (0xABD6, '\u{e093}'),
+    (0xABD7, '\u{e094}'),
+    (0xABD8, '\u{e095}'),
+    (0xABD9, '\u{e096}'),
+    (0xABDA, '\u{e097}'),
+    (0xABDB, '\u{e098}'),
+    (0xABDC, '\u{e099}'),
+    (0xABDD, '\u{e09a}'),
+    (0xABDE, '\u{e09b}'),
+    (0xABDF, '\u{e09c}'),
+    (0xABE0, '\u{e09d}'),
+    (0xABE1, '\u{e09e}'),
+    (0xABE2, '\u{e09f}'),
+    (0xABE3, '\u{e0a0}'),
+    (0xABE4, '\u{e0a1}'),
+    (0xABE5, '\u{e0a2}'),
+    (0xABE6, '\u{e0a3}'),
+    (0xABE7, '\u{e0a4}'),
+    (0xABE8, '\u{e0a5}'),
+    (0xABE9, '\u{e0a6}'),
+    (0xABEA, '\u{e0a7}'),
+    (0xABEB, '\u{e0a8}'),
+    (0xABEC, '\u{e0a9}'),
+    (0xABED, '\u{e0aa}'),
+    (0xABEE, '\u{e0ab}'),
+    (0xABEF, '\u{e0ac}'),
+    (0xABF0, '\u{e0ad}'),
+    (0xABF1, '\u{e0ae}'),
+    (0xABF2, '\u{e0af}'),
+    (0xABF3, '\u{e0b0}'),
+    (0xABF4, '\u{e0b1}'),
+    (0xABF5, '\u{e0b2}'),
+    (0xABF6, '\u{e0b3}'),
+    (0xABF7, '\u{e0b4}'),
+    (0xABF8, '\u{e0b5}'),
+    (0xABF9, '\u{e0b6}'),
+    (0xABFA, '\u{e0b7}'),
+    (0xABFB, '\u{e0b8}'),
+    (0xABFC, '\u{e0b9}'),
+    (0xABFD, '\u{e0ba}'),
+    (0xABFE, '\u{e0bb}'),
+    (0xACA1, '\u{e0bc}'),
+    (0xACA2, '\u{e0bd}'),
+    (0xACA3, '\u{e0be}'),
+    (0xACA4, '\u{e0bf}'),
+    (0xACA5, '\u{e0c0}'),
+    (0xACA6, '\u{e0c1}'),
+    (0xACA7, '\u{e0c2}'),
+    (0xACA8, '\u{e0c3}'),
+    (0xACA9, '\u{e0c4}'),
+    (0xACAA, '\u{e0c5}'),
+    (0xACAB, '\u{e0c6}'),
+    (0xACAC, '\u{e0c7}'),
+    (0xACAD, '\u{e0c8}'),
+    (0xACAE, '\u{e0c9}'),
+    (0xACAF, '\u{e0ca}'),
+    (0xACB0, '\u{e0cb}'),
+    (0xACB1, '\u{e0cc}'),
+    (0xACB2, '\u{e0cd}'),
+    (0xACB3, '\u{e0ce}'),
+    (0xACB4, '\u{e0cf}'),
+    (0xACB5, '\u{e0d0}'),
+    (0xACB6, '\u{e0d1}'),
+    (0xACB7, '\u{e0d2}'),
+    (0xACB8, '\u{e0d3}'),
+    (0xACB9, '\u{e0d4}'),
+    (0xACBA, '\u{e0d5}'),
+    (0xACBB, '\u{e0d6}'),
+    (0xACBC, '\u{e0d7}'),
+    (0xACBD, '\u{e0d8}'),
+    (0xACBE, '\u{e0d9}'),
+    (0xACBF, '\u{e0da}'),
+    (0xACC0, '\u{e0db}'),
+    (0xACC1, '\u{e0dc}'),
+    (0xACC2, '\u{e0dd}'),
+    (0xACC3, '\u{e0de}'),
+    (0xACC4, '\u{e0df}'),
+    (0xACC5, '\u{e0e0}'),
+    (0xACC6, '\u{e0e1}'),
+    (0xACC7, '\u{e0e2}'),
+    (0xACC8, '\u{e0e3}'),
+    (0xACC9, '\u{e0e4}'),
+    (0xACCA, '\u{e0e5}'),
+    (0xACCB, '\u{e0e6}'),
+    (0xACCC, '\u{e0e7}'),
+    (0xACCD, '\u{e0e8}'),
+    (0xACCE, '\u{e0e9}'),
+    (0xACCF, '\u{e0ea}'),
+    (0xACD0, '\u{e0eb}'),
+    (0xACD1, '\u{e0ec}'),
+    (0xACD2, '\u{e0ed}'),
+    (0xACD3, '\u{e0ee}'),
+    (0xACD4, '\u{e0ef}'),
+    (0xACD5, '\u{e0f0}'),
+    (0xACD6, '\u{e0f1}'),
+    (0xACD7, '\u{e0f2}'),
+    (0xACD8, '\u{e0f3}'),
+    (0xACD9, '\u{e0f4}'),
+    (0xACDA, '\u{e0f5}'),
+    (0xACDB, '\u{e0f6}'),
+    (0xACDC, '\u{e0f7}'),
+    (0xACDD, '\u{e0f8}'),
+    (0xACDE, '\u{e0f9}'),
+    (0xACDF, '\u{e0fa}'),
+    (0xACE0, '\u{e0fb}'),
+    (0xACE1, '\u{e0fc}'),
+    (0xACE2, '\u{e0fd}'),
+    (0xACE3, '\u{e0fe}'),
+    (0xACE4, '\u{e0ff}'),
+    (0xACE5, '\u{e100}'),
+    (0xACE6, '\u{e101}'),
+    (0xACE7, '\u{e102}'),
+    (0xACE8, '\u{e103}'),
+    (0xACE9, '\u{e104}'),
+    (0xACEA, '\u{e105}'),
+    (0xACEB, '\u{e106}'),
+    (0xACEC, '\u{e107}'),
+    (0xACED, '\u{e108}'),
+    (0xACEE, '\u{e109}'),
+    (0xACEF, '\u{e10a}'),
+    (0xACF0, '\u{e10b}'),
+    (0xACF1, '\u{e10c}'),
+    (0xACF2, '\u{e10d}'),
+    (0xACF3, '\u{e10e}'),
+    (0xACF4, '\u{e10f}'),
+    (0xACF5, '\u{e110}'),
+    (0xACF6, '\u{e111}'),
+    (0xACF7, '\u{e112}'),
+    (0xACF8, '\u{e113}'),
+    (0xACF9, '\u{e114}'),
+    (0xACFA, '\u{e115}'),
+    (0xACFB, '\u{e116}'),
+    (0xACFC, '\u{e117}'),
+    (0xACFD, '\u{e118}'),
+    (0xACFE, '\u{e119}'),
+    (0xADA1, '\u{e11a}'),
+    (0xADA2, '\u{e11b}'),
+    (0xADA3, '\u{e11c}'),
+    (0xADA4, '\u{e11d}'),
+    (0xADA5, '\u{e11e}'),
+    (0xADA6, '\u{e11f}'),
+    (0xADA7, '\u{e120}'),
+    (0xADA8, '\u{e121}'),
+    (0xADA9, '\u{e122}'),
+    (0xADAA, '\u{e123}'),
+    (0xADAB, '\u{e124}'),
+    (0xADAC, '\u{e125}'),
+    (0xADAD, '\u{e126}'),
+    (0xADAE, '\u{e127}'),
+    (0xADAF, '\u{e128}'),
+    (0xADB0, '\u{e129}'),
+    (0xADB1, '\u{e12a}'),
+    (0xADB2, '\u{e12b}'),
+    (0xADB3, '\u{e12c}'),
+    (0xADB4, '\u{e12d}'),
+    (0xADB5, '\u{e12e}'),
+    (0xADB6, '\u{e12f}'),
+    (0xADB7, '\u{e130}'),
+    (0xADB8, '\u{e131}'),
+    (0xADB9, '\u{e132}'),
+    (0xADBA, '\u{e133}'),
+    (0xADBB, '\u{e134}'),
+    (0xADBC, '\u{e135}'),
+    (0xADBD, '\u{e136}'),
+    (0xADBE, '\u{e137}'),
+    (0xADBF, '\u{e138}'),
+    (0xADC0, '\u{e139}'),
+    (0xADC1, '\u{e13a}'),
+    (0xADC2, '\u{e13b}'),
+    (0xADC3, '\u{e13c}'),
+    (0xADC4, '\u{e13d}'),
+    (0xADC5, '\u{e13e}'),
+    (0xADC6, '\u{e13f}'),
+    (0xADC7, '\u{e140}'),
+    (0xADC8, '\u{e141}'),
+    (0xADC9, '\u{e142}'),
+    (0xADCA, '\u{e143}'),
+    (0xADCB, '\u{e144}'),
+    (0xADCC, '\u{e145}'),
+    (0xADCD, '\u{e146}'),
+    (0xADCE, '\u{e147}'),
+    (0xADCF, '\u{e148}'),
+    (0xADD0, '\u{e149}'),
+    (0xADD1, '\u{e14a}'),
+    (0xADD2, '\u{e14b}'),
+    (0xADD3, '\u{e14c}'),
+    (0xADD4, '\u{e14d}'),
+    (0xADD5, '\u{e14e}'),
+    (0xADD6, '\u{e14f}'),
+    (0xADD7, '\u{e150}'),
+    (0xADD8, '\u{e151}'),
+    (0xADD9, '\u{e152}'),
+    (0xADDA, '\u{e153}'),
+    (0xADDB, '\u{e154}'),
+    (0xADDC, '\u{e155}'),
+    (0xADDD, '\u{e156}'),
+    (0xADDE, '\u{e157}'),
+    (0xADDF, '\u{e158}'),
+    (0xADE0, '\u{e159}'),
+    (0xADE1, '\u{e15a}'),
+    (0xADE2, '\u{e15b}'),
+    (0xADE3, '\u{e15c}'),
+    (0xADE4, '\u{e15d}'),
+    (0xADE5, '\u{e15e}'),
+    (0xADE6, '\u{e15f}'),
+    (0xADE7, '\u{e160}'),
+    (0xADE8, '\u{e161}'),
+    (0xADE9, '\u{e162}'),
+    (0xADEA, '\u{e163}'),
+    (0xADEB, '\u{e164}'),
+    (0xADEC, '\u{e165}'),
+    (0xADED, '\u{e166}'),
+    (0xADEE, '\u{e167}'),
+    (0xADEF, '\u{e168}'),
+    (0xADF0, '\u{e169}'),
+    (0xADF1, '\u{e16a}'),
+    (0xADF2, '\u{e16b}'),
+    (0xADF3, '\u{e16c}'),
+    (0xADF4, '\u{e16d}'),
+    (0xADF5, '\u{e16e}'),
+    (0xADF6, '\u{e16f}'),
+    (0xADF7, '\u{e170}'),
+    (0xADF8, '\u{e171}'),
+    (0xADF9, '\u{e172}'),
+    (0xADFA, '\u{e173}'),
+    (0xADFB, '\u{e174}'),
+    (0xADFC, '\u{e175}'),
+    (0xADFD, '\u{e176}'),
+    (0xADFE, '\u{e177}'),
+    (0xAEA1, '\u{e178}'),
+    (0xAEA2, '\u{e179}'),
+    (0xAEA3, '\u{e17a}'),
+    (0xAEA4, '\u{e17b}'),
+    (0xAEA5, '\u{e17c}'),
+    (0xAEA6, '\u{e17d}'),
+    (0xAEA7, '\u{e17e}'),
+    (0xAEA8, '\u{e17f}'),
+    (0xAEA9, '\u{e180}'),
+    (0xAEAA, '\u{e181}'),
+    (0xAEAB, '\u{e182}'),
+    (0xAEAC, '\u{e183}'),
+    (0xAEAD, '\u{e184}'),
+    (0xAEAE, '\u{e185}'),
+    (0xAEAF, '\u{e186}'),
+    (0xAEB0, '\u{e187}'),
+    (0xAEB1, '\u{e188}'),
+    (0xAEB2, '\u{e189}'),
+    (0xAEB3, '\u{e18a}'),
+    (0xAEB4, '\u{e18b}'),
+    (0xAEB5, '\u{e18c}'),
+    (0xAEB6, '\u{e18d}'),
+    (0xAEB7, '\u{e18e}'),
+    (0xAEB8, '\u{e18f}'),
+    (0xAEB9, '\u{e190}'),
+    (0xAEBA, '\u{e191}'),
+    (0xAEBB, '\u{e192}'),
+    (0xAEBC, '\u{e193}'),
+    (0xAEBD, '\u{e194}'),
+    (0xAEBE, '\u{e195}'),
+    (0xAEBF, '\u{e196}'),
+    (0xAEC0, '\u{e197}'),
+    (0xAEC1, '\u{e198}'),
+    (0xAEC2, '\u{e199}'),
+    (0xAEC3, '\u{e19a}'),
+    (0xAEC4, '\u{e19b}'),
+    (0xAEC5, '\u{e19c}'),
+    (0xAEC6, '\u{e19d}'),
+    (0xAEC7, '\u{e19e}'),
+    (0xAEC8, '\u{e19f}'),
+    (0xAEC9, '\u{e1a0}'),
+    (0xAECA, '\u{e1a1}'),
+    (0xAECB, '\u{e1a2}'),
+    (0xAECC, '\u{e1a3}'),
+    (0xAECD, '\u{e1a4}'),
+    (0xAECE, '\u{e1a5}'),
+    (0xAECF, '\u{e1a6}'),
+    (0xAED0, '\u{e1a7}'),
+    (0xAED1, '\u{e1a8}'),
+    (0xAED2, '\u{e1a9}'),
+    (0xAED3, '\u{e1aa}'),
+    (0xAED4, '\u{e1ab}'),
+    (0xAED5, '\u{e1ac}'),
+    (0xAED6, '\u{e1ad}'),
+    (0xAED7, '\u{e1ae}'),
+    (0xAED8, '\u{e1af}'),
+    (0xAED9, '\u{e1b0}'),
+    (0xAEDA, '\u{e1b1}'),
+    (0xAEDB, '\u{e1b2}'),
+    (0xAEDC, '\u{e1b3}'),
+    (0xAEDD, '\u{e1b4}'),
+    (0xAEDE, '\u{e1b5}'),
+    (0xAEDF, '\u{e1b6}'),
+    (0xAEE0, '\u{e1b7}'),
+    (0xAEE1, '\u{e1b8}'),
+    (0xAEE2, '\u{e1b9}'),
+    (0xAEE3, '\u{e1ba}'),
+    (0xAEE4, '\u{e1bb}'),
+    (0xAEE5, '\u{e1bc}'),
+    (0xAEE6, '\u{e1bd}'),
+    (0xAEE7, '\u{e1be}'),
+    (0xAEE8, '\u{e1bf}'),
+    (0xAEE9, '\u{e1c0}'),
+    (0xAEEA, '\u{e1c1}'),
+    (0xAEEB, '\u{e1c2}'),
+    (0xAEEC, '\u{e1c3}'),
+    (0xAEED, '\u{e1c4}'),
+    (0xAEEE, '\u{e1c5}'),
+    (0xAEEF, '\u{e1c6}'),
+    (0xAEF0, '\u{e1c7}'),
+    (0xAEF1, '\u{e1c8}'),
+    (0xAEF2, '\u{e1c9}'),
+    (0xAEF3, '\u{e1ca}'),
+    (0xAEF4, '\u{e1cb}'),
+    (0xAEF5, '\u{e1cc}'),
+    (0xAEF6, '\u{e1cd}'),
+    (0xAEF7, '\u{e1ce}'),
+    (0xAEF8, '\u{e1cf}'),
+    (0xAEF9, '\u{e1d0}'),
+    (0xAEFA, '\u{e1d1}'),
+    (0xAEFB, '\u{e1d2}'),
+    (0xAEFC, '\u{e1d3}'),
+    (0xAEFD, '\u{e1d4}'),
+    (0xAEFE, '\u{e1d5}'),
+    (0xAFA1, '\u{e1d6}'),
+    (0xAFA2, '\u{e1d7}'),
+    (0xAFA3, '\u{e1d8}'),
+    (0xAFA4, '\u{e1d9}'),
+    (0xAFA5, '\u{e1da}'),
+    (0xAFA6, '\u{e1db}'),
+    (0xAFA7, '\u{e1dc}'),
+    (0xAFA8, '\u{e1dd}'),
+    (0xAFA9, '\u{e1de}'),
+    (0xAFAA, '\u{e1df}'),
+    (0xAFAB, '\u{e1e0}'),
+    (0xAFAC, '\u{e1e1}'),
+    (0xAFAD, '\u{e1e2}'),
+    (0xAFAE, '\u{e1e3}'),
+    (0xAFAF, '\u{e1e4}'),
+    (0xAFB0, '\u{e1e5}'),
+    (0xAFB1, '\u{e1e6}'),
+    (0xAFB2, '\u{e1e7}'),
+    (0xAFB3, '\u{e1e8}'),
+    (0xAFB4, '\u{e1e9}'),
+    (0xAFB5, '\u{e1ea}'),
+    (0xAFB6, '\u{e1eb}'),
+    (0xAFB7, '\u{e1ec}'),
+    (0xAFB8, '\u{e1ed}'),
+    (0xAFB9, '\u{e1ee}'),
+    (0xAFBA, '\u{e1ef}'),
+    (0xAFBB, '\u{e1f0}'),
+    (0xAFBC, '\u{e1f1}'),
+    (0xAFBD, '\u{e1f2}'),
+    (0xAFBE, '\u{e1f3}'),
+    (0xAFBF, '\u{e1f4}'),
+    (0xAFC0, '\u{e1f5}'),
+    (0xAFC1, '\u{e1f6}'),
+    (0xAFC2, '\u{e1f7}'),
+    (0xAFC3, '\u{e1f8}'),
+    (0xAFC4, '\u{e1f9}'),
+    (0xAFC5, '\u{e1fa}'),
+    (0xAFC6, '\u{e1fb}'),
+    (0xAFC7, '\u{e1fc}'),
+    (0xAFC8, '\u{e1fd}'),
+    (0xAFC9, '\u{e1fe}'),
+    (0xAFCA, '\u{e1ff}'),
+    (0xAFCB, '\u{e200}'),
+    (0xAFCC, '\u{e201}'),
+    (0xAFCD, '\u{e202}'),
+    (0xAFCE, '\u{e203}'),
+    (0xAFCF, '\u{e204}'),
+    (0xAFD0, '\u{e205}'),
+    (0xAFD1, '\u{e206}'),
+    (0xAFD2, '\u{e207}'),
+    (0xAFD3, '\u{e208}'),
+    (0xAFD4, '\u{e209}'),
+    (0xAFD5, '\u{e20a}'),
+    (0xAFD6, '\u{e20b}'),
+    (0xAFD7, '\u{e20c}'),
+    (0xAFD8, '\u{e20d}'),
+    (0xAFD9, '\u{e20e}'),
+    (0xAFDA, '\u{e20f}'),
+    (0xAFDB, '\u{e210}'),
+    (0xAFDC, '\u{e211}'),
+    (0xAFDD, '\u{e212}'),
+    (0xAFDE, '\u{e213}'),
+    (0xAFDF, '\u{e214}'),
+    (0xAFE0, '\u{e215}'),
+    (0xAFE1, '\u{e216}'),
+    (0xAFE2, '\u{e217}'),
+    (0xAFE3, '\u{e218}'),
+    (0xAFE4, '\u{e219}'),
+    (0xAFE5, '\u{e21a}'),
+    (0xAFE6, '\u{e21b}'),
+    (0xAFE7, '\u{e21c}'),
+    (0xAFE8, '\u{e21d}'),
+    (0xAFE9, '\u{e21e}'),
+    (0xAFEA, '\u{e21f}'),
+    (0xAFEB, '\u{e220}'),
+    (0xAFEC, '\u{e221}'),
+    (0xAFED, '\u{e222}'),
+    (0xAFEE, '\u{e223}'),
+    (0xAFEF, '\u{e224}'),
+    (0xAFF0, '\u{e225}'),
+    (0xAFF1, '\u{e226}'),
+    (0xAFF2, '\u{e227}'),
+    (0xAFF3, '\u{e228}'),
+    (0xAFF4, '\u{e229}'),
+    (0xAFF5, '\u{e22a}'),
+    (0xAFF6, '\u{e22b}'),
+    (0xAFF7, '\u{e22c}'),
+    (0xAFF8, '\u{e22d}'),
+    (0xAFF9, '\u{e22e}'),
+    (0xAFFA, '\u{e22f}'),
+    (0xAFFB, '\u{e230}'),
+    (0xAFFC, '\u{e231}'),
+    (0xAFFD, '\u{e232}'),
+    (0xAFFE, '\u{e233}'),
+    (0xD7FA, '\u{e810}'),
+    (0xD7FB, '\u{e811}'),
+    (0xD7FC, '\u{e812}'),
+    (0xD7FD, '\u{e813}'),
+    (0xD7FE, '\u{e814}'),
+    (0xF8A1, '\u{e234}'),
+    (0xF8A2, '\u{e235}'),
+    (0xF8A3, '\u{e236}'),
+    (0xF8A4, '\u{e237}'),
+    (0xF8A5, '\u{e238}'),
+    (0xF8A6, '\u{e239}'),
+    (0xF8A7, '\u{e23a}'),
+    (0xF8A8, '\u{e23b}'),
+    (0xF8A9, '\u{e23c}'),
+    (0xF8AA, '\u{e23d}'),
+    (0xF8AB, '\u{e23e}'),
+    (0xF8AC, '\u{e23f}'),
+    (0xF8AD, '\u{e240}'),
+    (0xF8AE, '\u{e241}'),
+    (0xF8AF, '\u{e242}'),
+    (0xF8B0, '\u{e243}'),
+    (0xF8B1, '\u{e244}'),
+    (0xF8B2, '\u{e245}'),
+    (0xF8B3, '\u{e246}'),
+    (0xF8B4, '\u{e247}'),
+    (0xF8B5, '\u{e248}'),
+    (0xF8B6, '\u{e249}'),
+    (0xF8B7, '\u{e24a}'),
+    (0xF8B8, '\u{e24b}'),
+    (0xF8B9, '\u{e24c}'),
+    (0xF8BA, '\u{e24d}'),
+    (0xF8BB, '\u{e24e}'),
+    (0xF8BC, '\u{e24f}'),
+    (0xF8BD, '\u{e250}'),
+    (0xF8BE, '\u{e251}'),
+    (0xF8BF, '\u{e252}'),
+    (0xF8C0, '\u{e253}'),
+    (0xF8C1, '\u{e254}'),
+    (0xF8C2, '\u{e255}'),
+    (0xF8C3, '\u{e256}'),
+    (0xF8C4, '\u{e257}'),
+    (0xF8C5, '\u{e258}'),
+    (0xF8C6, '\u{e259}'),
+    (0xF8C7, '\u{e25a}'),
+    (0xF8C8, '\u{e25b}'),
+    (0xF8C9, '\u{e25c}'),
+    (0xF8CA, '\u{e25d}'),
+    (0xF8CB, '\u{e25e}'),
+    (0xF8CC, '\u{e25f}'),
+    (0xF8CD, '\u{e260}'),
+    (0xF8CE, '\u{e261}'),
+    (0xF8CF, '\u{e262}'),
+    (0xF8D0, '\u{e263}'),
+    (0xF8D1, '\u{e264}'),
+    (0xF8D2, '\u{e265}'),
+    (0xF8D3, '\u{e266}'),
+    (0xF8D4, '\u{e267}'),
+    (0xF8D5, '\u{e268}'),
+    (0xF8D6, '\u{e269}'),
+    (0xF8D7, '\u{e26a}'),
+    (0xF8D8, '\u{e26b}'),
+    (0xF8D9, '\u{e26c}'),
+    (0xF8DA, '\u{e26d}'),
+    (0xF8DB, '\u{e26e}'),
+    (0xF8DC, '\u{e26f}'),
+    (0xF8DD, '\u{e270}'),
+    (0xF8DE, '\u{e271}'),
+    (0xF8DF, '\u{e272}'),
+    (0xF8E0, '\u{e273}'),
+    (0xF8E1, '\u{e274}'),
+    (0xF8E2, '\u{e275}'),
+    (0xF8E3, '\u{e276}'),
+    (0xF8E4, '\u{e277}'),
+    (0xF8E5, '\u{e278}'),
+    (0xF8E6, '\u{e279}'),
+    (0xF8E7, '\u{e27a}'),
+    (0xF8E8, '\u{e27b}'),
+    (0xF8E9, '\u{e27c}'),
+    (0xF8EA, '\u{e27d}'),
+    (0xF8EB, '\u{e27e}'),
+    (0xF8EC, '\u{e27f}'),
+    (0xF8ED, '\u{e280}'),
+    (0xF8EE, '\u{e281}'),
+    (0xF8EF, '\u{e282}'),
+    (0xF8F0, '\u{e283}'),
+    (0xF8F1, '\u{e284}'),
+    (0xF8F2, '\u{e285}'),
+    (0xF8F3, '\u{e286}'),
+    (0xF8F4, '\u{e287}'),
+    (0xF8F5, '\u{e288}'),
+    (0xF8F6, '\u{e289}'),
+    (0xF8F7, '\u{e28a}'),
+    (0xF8F8, '\u{e28b}'),
+    (0xF8F9, '\u{e28c}'),
+    (0xF8FA, '\u{e28d}'),
+    (0xF8FB, '\u{e28e}'),
+    (0xF8FC, '\u{e28f}'),
+    (0xF8FD, '\u{e290}'),
+    (0xF8FE, '\u{e291}'),
+    (0xF9A1, '\u{e292}'),
+    (0xF9A2, '\u{e293}'),
+    (0xF9A3, '\u{e294}'),
+    (0xF9A4, '\u{e295}'),
+    (0xF9A5, '\u{e296}'),
+    (0xF9A6, '\u{e297}'),
+    (0xF9A7, '\u{e298}'),
+    (0xF9A8, '\u{e299}'),
+    (0xF9A9, '\u{e29a}'),
+    (0xF9AA, '\u{e29b}'),
+    (0xF9AB, '\u{e29c}'),
+    (0xF9AC, '\u{e29d}'),
+    (0xF9AD, '\u{e29e}'),
+    (0xF9AE, '\u{e29f}'),
+    (0xF9AF, '\u{e2a0}'),
+    (0xF9B0, '\u{e2a1}'),
+    (0xF9B1, '\u{e2a2}'),
+    (0xF9B2, '\u{e2a3}'),
+    (0xF9B3, '\u{e2a4}'),
+    (0xF9B4, '\u{e2a5}'),
+    (0xF9B5, '\u{e2a6}'),
+    (0xF9B6, '\u{e2a7}'),
+    (0xF9B7, '\u{e2a8}'),
+    (0xF9B8, '\u{e2a9}'),
+    (0xF9B9, '\u{e2aa}'),
+    (0xF9BA, '\u{e2ab}'),
+    (0xF9BB, '\u{e2ac}'),
+    (0xF9BC, '\u{e2ad}'),
+    (0xF9BD, '\u{e2ae}'),
+    (0xF9BE, '\u{e2af}'),
+    (0xF9BF, '\u{e2b0}'),
+    (0xF9C0, '\u{e2b1}'),
+    (0xF9C1, '\u{e2b2}'),
+    (0xF9C2, '\u{e2b3}'),
+    (0xF9C3, '\u{e2b4}'),
+    (0xF9C4, '\u{e2b5}'),
+    (0xF9C5, '\u{e2b6}'),
+    (0xF9C6, '\u{e2b7}'),
+    (0xF9C7, '\u{e2b8}'),
+    (0xF9C8, '\u{e2b9}'),
+    (0xF9C9, '\u{e2ba}'),
+    (0xF9CA, '\u{e2bb}'),
+    (0xF9CB, '\u{e2bc}'),
+    (0xF9CC, '\u{e2bd}'),
+    (0xF9CD, '\u{e2be}'),
+    (0xF9CE, '\u{e2bf}'),
+    (0xF9CF, '\u{e2c0}'),
+    (0xF9D0, '\u{e2c1}'),
+    (0xF9D1, '\u{e2c2}'),
+    (0xF9D2, '\u{e2c3}'),
+    (0xF9D3, '\u{e2c4}'),
+    (0xF9D4, '\u{e2c5}'),
+    (0xF9D5, '\u{e2c6}'),
+    (0xF9D6, '\u{e2c7}'),
+    (0xF9D7, '\u{e2c8}'),
+    (0xF9D8, '\u{e2c9}'),
+    (0xF9D9, '\u{e2ca}'),
+    (0xF9DA, '\u{e2cb}'),
+    (0xF9DB, '\u{e2cc}'),
+    (0xF9DC, '\u{e2cd}'),
+    (0xF9DD, '\u{e2ce}'),
+    (0xF9DE, '\u{e2cf}'),
+    (0xF9DF, '\u{e2d0}'),
+    (0xF9E0, '\u{e2d1}'),
+    (0xF9E1, '\u{e2d2}'),
+    (0xF9E2, '\u{e2d3}'),
+    (0xF9E3, '\u{e2d4}'),
+    (0xF9E4, '\u{e2d5}'),
+    (0xF9E5, '\u{e2d6}'),
+    (0xF9E6, '\u{e2d7}'),
+    (0xF9E7, '\u{e2d8}'),
+    (0xF9E8, '\u{e2d9}'),
+    (0xF9E9, '\u{e2da}'),
+    (0xF9EA, '\u{e2db}'),
+    (0xF9EB, '\u{e2dc}'),
+    (0xF9EC, '\u{e2dd}'),
+    (0xF9ED, '\u{e2de}'),
+    (0xF9EE, '\u{e2df}'),
+    (0xF9EF, '\u{e2e0}'),
+    (0xF9F0, '\u{e2e1}'),
+    (0xF9F1, '\u{e2e2}'),
+    (0xF9F2, '\u{e2e3}'),
+    (0xF9F3, '\u{e2e4}'),
+    (0xF9F4, '\u{e2e5}'),
+    (0xF9F5, '\u{e2e6}'),
+    (0xF9F6, '\u{e2e7}'),
+    (0xF9F7, '\u{e2e8}'),
+    (0xF9F8, '\u{e2e9}'),
+    (0xF9F9, '\u{e2ea}'),
+    (0xF9FA, '\u{e2eb}'),
+    (0xF9FB, '\u{e2ec}'),
+    (0xF9FC, '\u{e2ed}'),
+    (0xF9FD, '\u{e2ee}'),
+    (0xF9FE, '\u{e2ef}'),
+    (0xFAA1, '\u{e2f0}'),
+    (0xFAA2, '\u{e2f1}'),
+    (0xFAA3, '\u{e2f2}'),
+    (0xFAA4, '\u{e2f3}'),
+    (0xFAA5, '\u{e2f4}'),
+    (0xFAA6, '\u{e2f5}'),
+    (0xFAA7, '\u{e2f6}'),
+    (0xFAA8, '\u{e2f7}'),
+    (0xFAA9, '\u{e2f8}'),
+    (0xFAAA, '\u{e2f9}'),
+    (0xFAAB, '\u{e2fa}'),
+    (0xFAAC, '\u{e2fb}'),
+    (0xFAAD, '\u{e2fc}'),
+    (0xFAAE, '\u{e2fd}'),
+    (0xFAAF, '\u{e2fe}'),
+    (0xFAB0, '\u{e2ff}'),
+    (0xFAB1, '\u{e300}'),
+    (0xFAB2, '\u{e301}'),
+    (0xFAB3, '\u{e302}'),
+    (0xFAB4, '\u{e303}'),
+    (0xFAB5, '\u{e304}'),
+    (0xFAB6, '\u{e305}'),
+    (0xFAB7, '\u{e306}'),
+    (0xFAB8, '\u{e307}'),
+    (0xFAB9, '\u{e308}'),
+    (0xFABA, '\u{e309}'),
+    (0xFABB, '\u{e30a}'),
+    (0xFABC, '\u{e30b}'),
+    (0xFABD, '\u{e30c}'),
+    (0xFABE, '\u{e30d}'),
+    (0xFABF, '\u{e30e}'),
+    (0xFAC0, '\u{e30f}'),
+    (0xFAC1, '\u{e310}'),
+    (0xFAC2, '\u{e311}'),
+    (0xFAC3, '\u{e312}'),
+    (0xFAC4, '\u{e313}'),
+    (0xFAC5, '\u{e314}'),
+    (0xFAC6, '\u{e315}'),
+    (0xFAC7, '\u{e316}'),
+    (0xFAC8, '\u{e317}'),
+    (0xFAC9, '\u{e318}'),
+    (0xFACA, '\u{e319}'),
+    (0xFACB, '\u{e31a}'),
+    (0xFACC, '\u{e31b}'),
+    (0xFACD, '\u{e31c}'),
+    (0xFACE, '\u{e31d}'),
+    (0xFACF, '\u{e31e}'),
+    (0xFAD0, '\u{e31f}'),
+    (0xFAD1, '\u{e320}'),
+    (0xFAD2, '\u{e321}'),
+    (0xFAD3, '\u{e322}'),
+    (0xFAD4, '\u{e323}'),
+    (0xFAD5, '\u{e324}'),
+    (0xFAD6, '\u{e325}'),
+    (0xFAD7, '\u{e326}'),
+    (0xFAD8, '\u{e327}'),
+    (0xFAD9, '\u{e328}'),
+    (0xFADA, '\u{e329}'),
+    (0xFADB, '\u{e32a}'),
+    (0xFADC, '\u{e32b}'),
+    (0xFADD, '\u{e32c}'),
+    (0xFADE, '\u{e32d}'),
+    (0xFADF, '\u{e32e}'),
+    (0xFAE0, '\u{e32f}'),
+    (0xFAE1, '\u{e330}'),
+    (0xFAE2, '\u{e331}'),
+    (0xFAE3, '\u{e332}'),
+    (0xFAE4, '\u{e333}'),
+    (0xFAE5, '\u{e334}'),
+    (0xFAE6, '\u{e335}'),
+    (0xFAE7, '\u{e336}'),
+    (0xFAE8, '\u{e337}'),
+    (0xFAE9, '\u{e338}'),
+    (0xFAEA, '\u{e339}'),
+    (0xFAEB, '\u{e33a}'),
+    (0xFAEC, '\u{e33b}'),
+    (0xFAED, '\u{e33c}'),
+    (0xFAEE, '\u{e33d}'),
+    (0xFAEF, '\u{e33e}'),
+    (0xFAF0, '\u{e33f}'),
+    (0xFAF1, '\u{e340}'),
+    (0xFAF2, '\u{e341}'),
+    (0xFAF3, '\u{e342}'),
+    (0xFAF4, '\u{e343}'),
+    (0xFAF5, '\u{e344}'),
+    (0xFAF6, '\u{e345}'),
+    (0xFAF7, '\u{e346}'),
+    (0xFAF8, '\u{e347}'),
+    (0xFAF9, '\u{e348}'),
+    (0xFAFA, '\u{e349}'),
+    (0xFAFB, '\u{e34a}'),
+    (0xFAFC, '\u{e34b}'),
+    (0xFAFD, '\u{e34c}'),
+    (0xFAFE, '\u{e34d}'),
+    (0xFBA1, '\u{e34e}'),
+    (0xFBA2, '\u{e34f}'),
+    (0xFBA3, '\u{e350}'),
+    (0xFBA4, '\u{e351}'),
+    (0xFBA5, '\u{e352}'),
+    (0xFBA6, '\u{e353}'),
+    (0xFBA7, '\u{e354}'),
+    (0xFBA8, '\u{e355}'),
+    (0xFBA9, '\u{e356}'),
+    (0xFBAA, '\u{e357}'),
+    (0xFBAB, '\u{e358}'),
+    (0xFBAC, '\u{e359}'),
+    (0xFBAD, '\u{e35a}'),
+    (0xFBAE, '\u{e35b}'),
+    (0xFBAF, '\u{e35c}'),
+    (0xFBB0, '\u{e35d}'),
+    (0xFBB1, '\u{e35e}'),
+    (0xFBB2, '\u{e35f}'),
+    (0xFBB3, '\u{e360}'),
+    (0xFBB4, '\u{e361}'),
+    (0xFBB5, '\u{e362}'),
+    (0xFBB6, '\u{e363}'),
+    (0xFBB7, '\u{e364}'),
+    (0xFBB8, '\u{e365}'),
+    (0xFBB9, '\u{e366}'),
+    (0xFBBA, '\u{e367}'),
+    (0xFBBB, '\u{e368}'),
+    (0xFBBC, '\u{e369}'),
+    (0xFBBD, '\u{e36a}'),
+    (0xFBBE, '\u{e36b}'),
+    (0xFBBF, '\u{e36c}'),
+    (0xFBC0, '\u{e36d}'),
+    (0xFBC1, '\u{e36e}'),
+    (0xFBC2, '\u{e36f}'),
+    (0xFBC3, '\u{e370}'),
+    (0xFBC4, '\u{e371}'),
+    (0xFBC5, '\u{e372}'),
+    (0xFBC6, '\u{e373}'),
+    (0xFBC7, '\u{e374}'),
+    (0xFBC8, '\u{e375}'),
+    (0xFBC9, '\u{e376}'),
+    (0xFBCA, '\u{e377}'),
+    (0xFBCB, '\u{e378}'),
+    (0xFBCC, '\u{e379}'),
+    (0xFBCD, '\u{e37a}'),
+    (0xFBCE, '\u{e37b}'),
+    (0xFBCF, '\u{e37c}'),
+    (0xFBD0, '\u{e37d}'),
+    (0xFBD1, '\u{e37e}'),
+    (0xFBD2, '\u{e37f}'),
+    (0xFBD3, '\u{e380}'),
+    (0xFBD4, '\u{e381}'),
+    (0xFBD5, '\u{e382}'),
+    (0xFBD6, '\u{e383}'),
+    (0xFBD7, '\u{e384}'),
+    (0xFBD8, '\u{e385}'),
+    (0xFBD9, '\u{e386}'),
+    (0xFBDA, '\u{e387}'),
+    (0xFBDB, '\u{e388}'),
+    (0xFBDC, '\u{e389}'),
+    (0xFBDD, '\u{e38a}'),
+    (0xFBDE, '\u{e38b}'),
+    (0xFBDF, '\u{e38c}'),
+    (0xFBE0, '\u{e38d}'),
+    (0xFBE1, '\u{e38e}'),
+    (0xFBE2, '\u{e38f}'),
+    (0xFBE3, '\u{e390}'),
+    (0xFBE4, '\u{e391}'),
+    (0xFBE5, '\u{e392}'),
+    (0xFBE6, '\u{e393}'),
+    (0xFBE7, '\u{e394}'),
+    (0xFBE8, '\u{e395}'),
+    (0xFBE9, '\u{e396}'),
+    (0xFBEA, '\u{e397}'),
+    (0xFBEB, '\u{e398}'),
+    (0xFBEC, '\u{e399}'),
+    (0xFBED, '\u{e39a}'),
+    (0xFBEE, '\u{e39b}'),
+    (0xFBEF, '\u{e39c}'),
+    (0xFBF0, '\u{e39d}'),
+    (0xFBF1, '\u{e39e}'),
+    (0xFBF2, '\u{e39f}'),
+    (0xFBF3, '\u{e3a0}'),
+    (0xFBF4, '\u{e3a1}'),
+    (0xFBF5, '\u{e3a2}'),
+    (0xFBF6, '\u{e3a3}'),
+    (0xFBF7, '\u{e3a4}'),
+    (0xFBF8, '\u{e3a5}'),
+    (0xFBF9, '\u{e3a6}'),
+    (0xFBFA, '\u{e3a7}'),
+    (0xFBFB, '\u{e3a8}'),
+    (0xFBFC, '\u{e3a9}'),
+    (0xFBFD, '\u{e3aa}'),
+    (0xFBFE, '\u{e3ab}'),
+    (0xFCA1, '\u{e3ac}'),
+    (0xFCA2, '\u{e3ad}'),
+    (0xFCA3, '\u{e3ae}'),
+    (0xFCA4, '\u{e3af}'),
+    (0xFCA5, '\u{e3b0}'),
+    (0xFCA6, '\u{e3b1}'),
+    (0xFCA7, '\u{e3b2}'),
+    (0xFCA8, '\u{e3b3}'),
+    (0xFCA9, '\u{e3b4}'),
+    (0xFCAA, '\u{e3b5}'),
+    (0xFCAB, '\u{e3b6}'),
+    (0xFCAC, '\u{e3b7}'),
+    (0xFCAD, '\u{e3b8}'),
+    (0xFCAE, '\u{e3b9}'),
+    (0xFCAF, '\u{e3ba}'),
+    (0xFCB0, '\u{e3bb}'),
+    (0xFCB1, '\u{e3bc}'),
+    (0xFCB2, '\u{e3bd}'),
+    (0xFCB3, '\u{e3be}'),
+    (0xFCB4, '\u{e3bf}'),
+    (0xFCB5, '\u{e3c0}'),
+    (0xFCB6, '\u{e3c1}'),
+    (0xFCB7, '\u{e3c2}'),
+    (0xFCB8, '\u{e3c3}'),
+    (0xFCB9, '\u{e3c4}'),
+    (0xFCBA, '\u{e3c5}'),
+    (0xFCBB, '\u{e3c6}'),
+    (0xFCBC, '\u{e3c7}'),
+    (0xFCBD, '\u{e3c8}'),
+    (0xFCBE, '\u{e3c9}'),
+    (0xFCBF, '\u{e3ca}'),
+    (0xFCC0, '\u{e3cb}'),
+    (0xFCC1, '\u{e3cc}'),
+    (0xFCC2, '\u{e3cd}'),
+    (0xFCC3, '\u{e3ce}'),
+    (0xFCC4, '\u{e3cf}'),
+    (0xFCC5, '\u{e3d0}'),
+    (0xFCC6, '\u{e3d1}'),
+    (0xFCC7, '\u{e3d2}'),
+    (0xFCC8, '\u{e3d3}'),
+    (0xFCC9, '\u{e3d4}'),
+    (0xFCCA, '\u{e3d5}'),
+    (0xFCCB, '\u{e3d6}'),
+    (0xFCCC, '\u{e3d7}'),
+    (0xFCCD, '\u{e3d8}'),
+    (0xFCCE, '\u{e3d9}'),
+    (0xFCCF, '\u{e3da}'),
+    (0xFCD0, '\u{e3db}'),
+    (0xFCD1, '\u{e3dc}'),
+    (0xFCD2, '\u{e3dd}'),
+    (0xFCD3, '\u{e3de}'),
+    (0xFCD4, '\u{e3df}'),
+    (0xFCD5, '\u{e3e0}'),
+    (0xFCD6, '\u{e3e1}'),
+    (0xFCD7, '\u{e3e2}'),
+    (0xFCD8, '\u{e3e3}'),
+    (0xFCD9, '\u{e3e4}'),
+    (0xFCDA, '\u{e3e5}'),
+    (0xFCDB, '\u{e3e6}'),
+    (0xFCDC, '\u{e3e7}'),
+    (0xFCDD, '\u{e3e8}'),
+    (0xFCDE, '\u{e3e9}'),
+    (0xFCDF, '\u{e3ea}'),
+    (0xFCE0, '\u{e3eb}'),
+    (0xFCE1, '\u{e3ec}'),
+    (0xFCE2, '\u{e3ed}'),
+    (0xFCE3, '\u{e3ee}'),
+    (0xFCE4, '\u{e3ef}'),
+    (0xFCE5, '\u{e3f0}'),
+    (0xFCE6, '\u{e3f1}'),
+    (0xFCE7, '\u{e3f2}'),
+    (0xFCE8, '\u{e3f3}'),
+    (0xFCE9, '\u{e3f4}'),
+    (0xFCEA, '\u{e3f5}'),
+    (0xFCEB, '\u{e3f6}'),
+    (0xFCEC, '\u{e3f7}'),
+    (0xFCED, '\u{e3f8}'),
+    (0xFCEE, '\u{e3f9}'),
+    (0xFCEF, '\u{e3fa}'),
+    (0xFCF0, '\u{e3fb}'),
+    (0xFCF1, '\u{e3fc}'),
+    (0xFCF2, '\u{e3fd}'),
+    (0xFCF3, '\u{e3fe}'),
+    (0xFCF4, '\u{e3ff}'),
+    (0xFCF5, '\u{e400}'),
+    (0xFCF6, '\u{e401}'),
+    (0xFCF7, '\u{e402}'),
+    (0xFCF8, '\u{e403}'),
+    (0xFCF9, '\u{e404}'),
+    (0xFCFA, '\u{e405}'),
+    (0xFCFB, '\u{e406}'),
+    (0xFCFC, '\u{e407}'),
+    (0xFCFD, '\u{e408}'),
+    (0xFCFE, '\u{e409}'),
+    (0xFDA1, '\u{e40a}'),
+    (0xFDA2, '\u{e40b}'),
+    (0xFDA3, '\u{e40c}'),
+    (0xFDA4, '\u{e40d}'),
+    (0xFDA5, '\u{e40e}'),
+    (0xFDA6, '\u{e40f}'),
+    (0xFDA7, '\u{e410}'),
+    (0xFDA8, '\u{e411}'),
+    (0xFDA9, '\u{e412}'),
+    (0xFDAA, '\u{e413}'),
+    (0xFDAB, '\u{e414}'),
+    (0xFDAC, '\u{e415}'),
+    (0xFDAD, '\u{e416}'),
+    (0xFDAE, '\u{e417}'),
+    (0xFDAF, '\u{e418}'),
+    (0xFDB0, '\u{e419}'),
+    (0xFDB1, '\u{e41a}'),
+    (0xFDB2, '\u{e41b}'),
+    (0xFDB3, '\u{e41c}'),
+    (0xFDB4, '\u{e41d}'),
+    (0xFDB5, '\u{e41e}'),
+    (0xFDB6, '\u{e41f}'),
+    (0xFDB7, '\u{e420}'),
+    (0xFDB8, '\u{e421}'),
+    (0xFDB9, '\u{e422}'),
+    (0xFDBA, '\u{e423}'),
+    (0xFDBB, '\u{e424}'),
+    (0xFDBC, '\u{e425}'),
+    (0xFDBD, '\u{e426}'),
+    (0xFDBE, '\u{e427}'),
+    (0xFDBF, '\u{e428}'),
+    (0xFDC0, '\u{e429}'),
+    (0xFDC1, '\u{e42a}'),
+    (0xFDC2, '\u{e42b}'),
+    (0xFDC3, '\u{e42c}'),
+    (0xFDC4, '\u{e42d}'),
+    (0xFDC5, '\u{e42e}'),
+    (0xFDC6, '\u{e42f}'),
+    (0xFDC7, '\u{e430}'),
+    (0xFDC8, '\u{e431}'),
+    (0xFDC9, '\u{e432}'),
+    (0xFDCA, '\u{e433}'),
+    (0xFDCB, '\u{e434}'),
+    (0xFDCC, '\u{e435}'),
+    (0xFDCD, '\u{e436}'),
+    (0xFDCE, '\u{e437}'),
+    (0xFDCF, '\u{e438}'),
+    (0xFDD0, '\u{e439}'),
+    (0xFDD1, '\u{e43a}'),
+    (0xFDD2, '\u{e43b}'),
+    (0xFDD3, '\u{e43c}'),
+    (0xFDD4, '\u{e43d}'),
+    (0xFDD5, '\u{e43e}'),
+    (0xFDD6, '\u{e43f}'),
+    (0xFDD7, '\u{e440}'),
+    (0xFDD8, '\u{e441}'),
+    (0xFDD9, '\u{e442}'),
+    (0xFDDA, '\u{e443}'),
+    (0xFDDB, '\u{e444}'),
+    (0xFDDC, '\u{e445}'),
+    (0xFDDD, '\u{e446}'),
+    (0xFDDE, '\u{e447}'),
+    (0xFDDF, '\u{e448}'),
+    (0xFDE0, '\u{e449}'),
+    (0xFDE1, '\u{e44a}'),
+    (0xFDE2, '\u{e44b}'),
+    (0xFDE3, '\u{e44c}'),
+    (0xFDE4, '\u{e44d}'),
+    (0xFDE5, '\u{e44e}'),
+    (0xFDE6, '\u{e44f}'),
+    (0xFDE7, '\u{e450}'),
+    (0xFDE8, '\u{e451}'),
+    (0xFDE9, '\u{e452}'),
+    (0xFDEA, '\u{e453}'),
+    (0xFDEB, '\u{e454}'),
+    (0xFDEC, '\u{e455}'),
+    (0xFDED, '\u{e456}'),
+    (0xFDEE, '\u{e457}'),
+    (0xFDEF, '\u{e458}'),
+    (0xFDF0, '\u{e459}'),
+    (0xFDF1, '\u{e45a}'),
+    (0xFDF2, '\u{e45b}'),
+    (0xFDF3, '\u{e45c}'),
+    (0xFDF4, '\u{e45d}'),
+    (0xFDF5, '\u{e45e}'),
+    (0xFDF6, '\u{e45f}'),
+    (0xFDF7, '\u{e460}'),
+    (0xFDF8, '\u{e461}'),
+    (0xFDF9, '\u{e462}'),
+    (0xFDFA, '\u{e463}'),
+    (0xFDFB, '\u{e464}'),
+    (0xFDFC, '\u{e465}'),
+    (0xFDFD, '\u{e466}'),
+    (0xFDFE, '\u{e467}'),
+    (0xFE50, '⺁'),
+    (0xFE51, '\u{e816}'),
+    (0xFE52, '\u{e817}'),
+    (0xFE53, '\u{e818}'),
+    (0xFE54, '⺄'),
+    (0xFE55, '㑳'),
+    (0xFE56, '㑇'),
+    (0xFE57, '⺈'),
+    (0xFE58, '⺋'),
+    (0xFE59, '\u{e81e}'),
+    (0xFE5A, '㖞'),
+    (0xFE5B, '㘚'),
+    (0xFE5C, '㘎'),
+    (0xFE5D, '⺌'),
+    (0xFE5E, '⺗'),
+    (0xFE5F, '㥮'),
+    (0xFE60, '㤘'),
+    (0xFE61, '\u{e826}'),
+    (0xFE62, '㧏'),
+    (0xFE63, '㧟'),
+    (0xFE64, '㩳'),
+    (0xFE65, '㧐'),
+    (0xFE66, '\u{e82b}'),
+    (0xFE67, '\u{e82c}'),
+    (0xFE68, '㭎'),
+    (0xFE69, '㱮'),
+    (0xFE6A, '㳠'),
+    (0xFE6B, '⺧'),
+    (0xFE6C, '\u{e831}'),
+    (0xFE6D, '\u{e832}'),
+    (0xFE6E, '⺪'),
+    (0xFE6F, '䁖'),
+    (0xFE70, '䅟'),
+    (0xFE71, '⺮'),
+    (0xFE72, '䌷'),
+    (0xFE73, '⺳'),
+    (0xFE74, '⺶'),
+    (0xFE75, '⺷'),
+    (0xFE76, '\u{e83b}'),
+    (0xFE77, '䎱'),
+    (0xFE78, '䎬'),
+    (0xFE79, '⺻'),
+    (0xFE7A, '䏝'),
+    (0xFE7B, '䓖'),
+    (0xFE7C, '䙡'),
+    (0xFE7D, '䙌'),
+    (0xFE7E, '\u{e843}'),
+    (0xFE80, '䜣'),
+    (0xFE81, '䜩'),
+    (0xFE82, '䝼'),
+    (0xFE83, '䞍'),
+    (0xFE84, '⻊'),
+    (0xFE85, '䥇'),
+    (0xFE86, '䥺'),
+    (0xFE87, '䥽'),
+    (0xFE88, '䦂'),
+    (0xFE89, '䦃'),
+    (0xFE8A, '䦅'),
+    (0xFE8B, '䦆'),
+    (0xFE8C, '䦟'),
+    (0xFE8D, '䦛'),
+    (0xFE8E, '䦷'),
+    (0xFE8F, '䦶'),
+    (0xFE90, '\u{e854}'),
+    (0xFE91, '\u{e855}'),
+    (0xFE92, '䲣'),
+    (0xFE93, '䲟'),
+    (0xFE94, '䲠'),
+    (0xFE95, '䲡'),
+    (0xFE96, '䱷'),
+    (0xFE97, '䲢'),
+    (0xFE98, '䴓'),
+    (0xFE99, '䴔'),
+    (0xFE9A, '䴕'),
+    (0xFE9B, '䴖'),
+    (0xFE9C, '䴗'),
+    (0xFE9D, '䴘'),
+    (0xFE9E, '䴙'),
+    (0xFE9F, '䶮'),
+    (0xFEA0, '\u{e864}'),
+    (0xFEA1, '\u{e468}'),
+    (0xFEA2, '\u{e469}'),
+    (0xFEA3, '\u{e46a}'),
+    (0xFEA4, '\u{e46b}'),
+    (0xFEA5, '\u{e46c}'),
+    (0xFEA6, '\u{e46d}'),
+    (0xFEA7, '\u{e46e}'),
+    (0xFEA8, '\u{e46f}'),
+    (0xFEA9, '\u{e470}'),
+    (0xFEAA, '\u{e471}'),
+    (0xFEAB, '\u{e472}'),
+    (0xFEAC, '\u{e473}'),
+    (0xFEAD, '\u{e474}'),
+    (0xFEAE, '\u{e475}'),
+    (0xFEAF, '\u{e476}'),
+    (0xFEB0, '\u{e477}'),
+    (0xFEB1, '\u{e478}'),
+    (0xFEB2, '\u{e479}'),
+    (0xFEB3, '\u{e47a}'),
+    (0xFEB4, '\u{e47b}'),
+    (0xFEB5, '\u{e47c}'),
+    (0xFEB6, '\u{e47d}'),
+    (0xFEB7, '\u{e47e}'),
+    (0xFEB8, '\u{e47f}'),
+    (0xFEB9, '\u{e480}'),
+    (0xFEBA, '\u{e481}'),
+    (0xFEBB, '\u{e482}'),
+    (0xFEBC, '\u{e483}'),
+    (0xFEBD, '\u{e484}'),
+    (0xFEBE, '\u{e485}'),
+    (0xFEBF, '\u{e486}'),
+    (0xFEC0, '\u{e487}'),
+    (0xFEC1, '\u{e488}'),
+    (0xFEC2, '\u{e489}'),
+    (0xFEC3, '\u{e48a}'),
+    (0xFEC4, '\u{e48b}'),
+    (0xFEC5, '\u{e48c}'),
+    (0xFEC6, '\u{e48d}'),
+    (0xFEC7, '\u{e48e}'),
+    (0xFEC8, '\u{e48f}'),
+    (0xFEC9, '\u{e490}'),
+    (0xFECA, '\u{e491}'),
+    (0xFECB, '\u{e492}'),
+    (0xFECC, '\u{e493}'),
+    (0xFECD, '\u{e494}'),
+    (0xFECE, '\u{e495}'),
+    (0xFECF, '\u{e496}'),
+    (0xFED0, '\u{e497}'),
+    (0xFED1, '\u{e498}'),
+    (0xFED2, '\u{e499}'),
+    (0xFED3, '\u{e49a}'),
+    (0xFED4, '\u{e49b}'),
+    (0xFED5, '\u{e49c}'),
+    (0xFED6, '\u{e49d}'),
+    (0xFED7, '\u{e49e}'),
+    (0xFED8, '\u{e49f}'),
+    (0xFED9, '\u{e4a0}'),
+    (0xFEDA, '\u{e4a1}'),
+    (0xFEDB, '\u{e4a2}'),
+    (0xFEDC, '\u{e4a3}'),
+    (0xFEDD, '\u{e4a4}'),
+    (0xFEDE, '\u{e4a5}'),
+    (0xFEDF, '\u{e4a6}'),
+    (0xFEE0, '\u{e4a7}'),
+    (0xFEE1, '\u{e4a8}'),
+    (0xFEE2, '\u{e4a9}'),
+    (0xFEE3, '\u{e4aa}'),
+    (0xFEE4, '\u{e4ab}'),
+    (0xFEE5, '\u{e4ac}'),
+    (0xFEE6, '\u{e4ad}'),
+    (0xFEE7, '\u{e4ae}'),
+    (0xFEE8, '\u{e4af}'),
+    (0xFEE9, '\u{e4b0}'),
+    (0xFEEA, '\u{e4b1}'),
+    (0xFEEB, '\u{e4b2}'),
+    (0xFEEC, '\u{e4b3}'),
+    (0xFEED, '\u{e4b4}'),
+    (0xFEEE, '\u{e4b5}'),
+    (0xFEEF, '\u{e4b6}'),
+    (0xFEF0, '\u{e4b7}'),
+    (0xFEF1, '\u{e4b8}'),
+    (0xFEF2, '\u{e4b9}'),
+    (0xFEF3, '\u{e4ba}'),
+    (0xFEF4, '\u{e4bb}'),
+    (0xFEF5, '\u{e4bc}'),
+    (0xFEF6, '\u{e4bd}'),
+    (0xFEF7, '\u{e4be}'),
+    (0xFEF8, '\u{e4bf}'),
+    (0xFEF9, '\u{e4c0}'),
+    (0xFEFA, '\u{e4c1}'),
+    (0xFEFB, '\u{e4c2}'),
+    (0xFEFC, '\u{e4c3}'),
+    (0xFEFD, '\u{e4c4}'),
+    (0xFEFE, '\u{e4c5}'),
+]);
+
+/// The code points of the four-byte codes `0x81308130..=0x8439FE39`, numbered from 0: each
+/// `(first, last, index)` range maps the code points `first..=last` from `index` on.
+pub(crate) static GB18030_RANGES: &[(u16, u16, u16)] = &[
+    (0x0080, 0x00A3, 0),
+    (0x00A5, 0x00A6, 36),
+    (0x00A9, 0x00AF, 38),
+    (0x00B2, 0x00B6, 45),
+    (0x00B8, 0x00D6, 50),
+    (0x00D8, 0x00DF, 81),
+    (0x00E2, 0x00E7, 89),
+    (0x00EB, 0x00EB, 95),
+    (0x00EE, 0x00F1, 96),
+    (0x00F4, 0x00F6, 100),
+    (0x00F8, 0x00F8, 103),
+    (0x00FB, 0x00FB, 104),
+    (0x00FD, 0x0100, 105),
+    (0x0102, 0x0112, 109),
+    (0x0114, 0x011A, 126),
+    (0x011C, 0x012A, 133),
+    (0x012C, 0x0143, 148),
+    (0x0145, 0x0147, 172),
+    (0x0149, 0x014C, 175),
+    (0x014E, 0x016A, 179),
+    (0x016C, 0x01CD, 208),
+    (0x01CF, 0x01CF, 306),
+    (0x01D1, 0x01D1, 307),
+    (0x01D3, 0x01D3, 308),
+    (0x01D5, 0x01D5, 309),
+    (0x01D7, 0x01D7, 310),
+    (0x01D9, 0x01D9, 311),
+    (0x01DB, 0x01DB, 312),
+    (0x01DD, 0x01F8, 313),
+    (0x01FA, 0x0250, 341),
+    (0x0252, 0x0260, 428),
+    (0x0262, 0x02C6, 443),
+    (0x02C8, 0x02C8, 544),
+    (0x02CC, 0x02D8, 545),
+    (0x02DA, 0x0390, 558),
+    (0x03A2, 0x03A2, 741),
+    (0x03AA, 0x03B0, 742),
+    (0x03C2, 0x03C2, 749),
+    (0x03CA, 0x0400, 750),
+    (0x0402, 0x040F, 805),
+    (0x0450, 0x0450, 819),
+    (0x0452, 0x200F, 820),
+    (0x2011, 0x2012, 7922),
+    (0x2017, 0x2017, 7924),
+    (0x201A, 0x201B, 7925),
+    (0x201E, 0x2024, 7927),
+    (0x2027, 0x202F, 7934),
+    (0x2031, 0x2031, 7943),
+    (0x2034, 0x2034, 7944),
+    (0x2036, 0x203A, 7945),
+    (0x203C, 0x20AB, 7950),
+    (0x20AD, 0x2102, 8062),
+    (0x2104, 0x2104, 8148),
+    (0x2106, 0x2108, 8149),
+    (0x210A, 0x2115, 8152),
+    (0x2117, 0x2120, 8164),
+    (0x2122, 0x215F, 8174),
+    (0x216C, 0x216F, 8236),
+    (0x217A, 0x218F, 8240),
+    (0x2194, 0x2195, 8262),
+    (0x219A, 0x2207, 8264),
+    (0x2209, 0x220E, 8374),
+    (0x2210, 0x2210, 8380),
+    (0x2212, 0x2214, 8381),
+    (0x2216, 0x2219, 8384),
+    (0x221B, 0x221C, 8388),
+    (0x2221, 0x2222, 8390),
+    (0x2224, 0x2224, 8392),
+    (0x2226, 0x2226, 8393),
+    (0x222C, 0x222D, 8394),
+    (0x222F, 0x2233, 8396),
+    (0x2238, 0x223C, 8401),
+    (0x223E, 0x2247, 8406),
+    (0x2249, 0x224B, 8416),
+    (0x224D, 0x2251, 8419),
+    (0x2253, 0x225F, 8424),
+    (0x2262, 0x2263, 8437),
+    (0x2268, 0x226D, 8439),
+    (0x2270, 0x2294, 8445),
+    (0x2296, 0x2298, 8482),
+    (0x229A, 0x22A4, 8485),
+    (0x22A6, 0x22BE, 8496),
+    (0x22C0, 0x2311, 8521),
+    (0x2313, 0x245F, 8603),
+    (0x246A, 0x2473, 8936),
+    (0x249C, 0x24FF, 8946),
+    (0x254C, 0x254F, 9046),
+    (0x2574, 0x2580, 9050),
+    (0x2590, 0x2592, 9063),
+    (0x2596, 0x259F, 9066),
+    (0x25A2, 0x25B1, 9076),
+    (0x25B4, 0x25BB, 9092),
+    (0x25BE, 0x25C5, 9100),
+    (0x25C8, 0x25CA, 9108),
+    (0x25CC, 0x25CD, 9111),
+    (0x25D0, 0x25E1, 9113),
+    (0x25E6, 0x2604, 9131),
+    (0x2607, 0x2608, 9162),
+    (0x260A, 0x263F, 9164),
+    (0x2641, 0x2641, 9218),
+    (0x2643, 0x2E80, 9219),
+    (0x2E82, 0x2E83, 11329),
+    (0x2E85, 0x2E87, 11331),
+    (0x2E89, 0x2E8A, 11334),
+    (0x2E8D, 0x2E96, 11336),
+    (0x2E98, 0x2EA6, 11346),
+    (0x2EA8, 0x2EA9, 11361),
+    (0x2EAB, 0x2EAD, 11363),
+    (0x2EAF, 0x2EB2, 11366),
+    (0x2EB4, 0x2EB5, 11370),
+    (0x2EB8, 0x2EBA, 11372),
+    (0x2EBC, 0x2EC9, 11375),
+    (0x2ECB, 0x2FEF, 11389),
+    (0x2FFC, 0x2FFF, 11682),
+    (0x3004, 0x3004, 11686),
+    (0x3018, 0x301C, 11687),
+    (0x301F, 0x3020, 11692),
+    (0x302A, 0x303D, 11694),
+    (0x303F, 0x3040, 11714),
+    (0x3094, 0x309A, 11716),
+    (0x309F, 0x30A0, 11723),
+    (0x30F7, 0x30FB, 11725),
+    (0x30FF, 0x3104, 11730),
+    (0x312A, 0x321F, 11736),
+    (0x322A, 0x3230, 11982),
+    (0x3232, 0x32A2, 11989),
+    (0x32A4, 0x338D, 12102),
+    (0x3390, 0x339B, 12336),
+    (0x339F, 0x33A0, 12348),
+    (0x33A2, 0x33C3, 12350),
+    (0x33C5, 0x33CD, 12384),
+    (0x33CF, 0x33D0, 12393),
+    (0x33D3, 0x33D4, 12395),
+    (0x33D6, 0x3446, 12397),
+    (0x3448, 0x3472, 12510),
+    (0x3474, 0x359D, 12553),
+    (0x359F, 0x360D, 12851),
+    (0x360F, 0x3619, 12962),
+    (0x361B, 0x3917, 12973),
+    (0x3919, 0x396D, 13738),
+    (0x396F, 0x39CE, 13823),
+    (0x39D1, 0x39DE, 13919),
+    (0x39E0, 0x3A72, 13933),
+    (0x3A74, 0x3B4D, 14080),
+    (0x3B4F, 0x3C6D, 14298),
+    (0x3C6F, 0x3CDF, 14585),
+    (0x3CE1, 0x4055, 14698),
+    (0x4057, 0x415E, 15583),
+    (0x4160, 0x4336, 15847),
+    (0x4338, 0x43AB, 16318),
+    (0x43AD, 0x43B0, 16434),
+    (0x43B2, 0x43DC, 16438),
+    (0x43DE, 0x44D5, 16481),
+    (0x44D7, 0x464B, 16729),
+    (0x464D, 0x4660, 17102),
+    (0x4662, 0x4722, 17122),
+    (0x4724, 0x4728, 17315),
+    (0x472A, 0x477B, 17320),
+    (0x477D, 0x478C, 17402),
+    (0x478E, 0x4946, 17418),
+    (0x4948, 0x4979, 17859),
+    (0x497B, 0x497C, 17909),
+    (0x497E, 0x4981, 17911),
+    (0x4984, 0x4984, 17915),
+    (0x4987, 0x499A, 17916),
+    (0x499C, 0x499E, 17936),
+    (0x49A0, 0x49B5, 17939),
+    (0x49B8, 0x4C76, 17961),
+    (0x4C78, 0x4C9E, 18664),
+    (0x4CA4, 0x4D12, 18703),
+    (0x4D1A, 0x4DAD, 18814),
+    (0x4DAF, 0x4DFF, 18962),
+    (0x9FA6, 0xD7FF, 19043),
+    (0xE76C, 0xE76C, 33469),
+    (0xE7C8, 0xE7C8, 33470),
+    (0xE7E7, 0xE7F3, 33471),
+    (0xE815, 0xE815, 33484),
+    (0xE819, 0xE81D, 33485),
+    (0xE81F, 0xE825, 33490),
+    (0xE827, 0xE82A, 33497),
+    (0xE82D, 0xE830, 33501),
+    (0xE833, 0xE83A, 33505),
+    (0xE83C, 0xE842, 33513),
+    (0xE844, 0xE853, 33520),
+    (0xE856, 0xE863, 33536),
+    (0xE865, 0xF92B, 33550),
+    (0xF92D, 0xF978, 37845),
+    (0xF97A, 0xF994, 37921),
+    (0xF996, 0xF9E6, 37948),
+    (0xF9E8, 0xF9F0, 38029),
+    (0xF9F2, 0xFA0B, 38038),
+    (0xFA10, 0xFA10, 38064),
+    (0xFA12, 0xFA12, 38065),
+    (0xFA15, 0xFA17, 38066),
+    (0xFA19, 0xFA1E, 38069),
+    (0xFA22, 0xFA22, 38075),
+    (0xFA25, 0xFA26, 38076),
+    (0xFA2A, 0xFE2F, 38078),
+    (0xFE32, 0xFE32, 39108),
+    (0xFE45, 0xFE48, 39109),
+    (0xFE53, 0xFE53, 39113),
+    (0xFE58, 0xFE58, 39114),
+    (0xFE67, 0xFE67, 39115),
+    (0xFE6C, 0xFF00, 39116),
+    (0xFF5F, 0xFFDF, 39265),
+    (0xFFE6, 0xFFFF, 39394),
+];
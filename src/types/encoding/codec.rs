@@ -0,0 +1,159 @@
+use super::{Wtf8, Wtf8Buf};
+use crate::types::{ByteOrder, Ordered};
+
+/// Bytes (or, when encoding, WTF-8 bytes of the input) `start..end` could not be converted,
+/// for the `reason` a [`CodecError`](super::CodecError) reports.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Fault {
+    pub start: usize,
+    pub end: usize,
+    pub reason: &'static str,
+}
+
+impl Fault {
+    #[inline]
+    pub const fn new(start: usize, end: usize, reason: &'static str) -> Self {
+        Self { start, end, reason }
+    }
+}
+
+/// The stateful core every text codec implements.
+///
+/// `decode` converts as much of `input` as possible and returns the number of bytes consumed.
+/// That is the whole input unless `last` is false and the input ends inside a sequence, in
+/// which case the tail is left for the next call. On an invalid sequence it returns a [`Fault`]
+/// after having decoded `input[..fault.start]`.
+pub(crate) trait Decoder: Send {
+    fn decode(&mut self, input: &[u8], output: &mut Wtf8Buf, last: bool) -> Result<usize, Fault>;
+
+    /// The encoding form of the UTF codecs, which `surrogatepass` reads lone surrogates in.
+    fn form(&self) -> Option<Form> {
+        None
+    }
+}
+
+/// The encoding counterpart of [`Decoder`], with fault positions given as byte offsets into
+/// the input. Like in Python, a fault usually covers the whole run of consecutive unencodable
+/// code points.
+pub(crate) trait Encoder: Send {
+    fn encode(&mut self, input: &Wtf8, output: &mut Vec<u8>, last: bool) -> Result<usize, Fault>;
+
+    /// Size of the code units written; raw bytes from an error handler must fill whole units.
+    fn unit(&self) -> usize {
+        1
+    }
+
+    /// Whether `surrogateescape` resolves the escapable head of a fault and reports the rest,
+    /// as CPython's built-in ASCII, Latin-1 and UTF-8 encoders do, instead of failing whole.
+    fn partial_escape(&self) -> bool {
+        false
+    }
+
+    /// The encoding form of the UTF codecs, which `surrogatepass` writes lone surrogates in.
+    fn form(&self) -> Option<Form> {
+        None
+    }
+}
+
+/// A text codec as both its [`Decoder`] and its [`Encoder`], so that each encoding builds its
+/// codec in one place.
+pub(crate) trait Coder: Decoder + Encoder {}
+
+impl<T: Decoder + Encoder> Coder for T {}
+
+/// A Unicode encoding form, in which Python's `surrogatepass` passes lone surrogates through
+/// as if they were characters.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Form {
+    Utf8,
+    Utf16(ByteOrder),
+    Utf32(ByteOrder),
+}
+
+impl Form {
+    /// Reads the surrogate at the head of `input`; returns it with its length.
+    pub fn read_surrogate(self, input: &[u8]) -> Option<(u32, usize)> {
+        let (c, len) = match self {
+            Self::Utf8 => match *input {
+                [c1, c2, c3, ..] if c1 & 0xF0 == 0xE0 && c2 & 0xC0 == 0x80 && c3 & 0xC0 == 0x80 => {
+                    let c = (c1 as u32 & 0x0F) << 12 | (c2 as u32 & 0x3F) << 6 | c3 as u32 & 0x3F;
+                    (c, 3)
+                }
+                _ => return None,
+            },
+            Self::Utf16(order) => (order.read_u16(input).ok()? as u32, 2),
+            Self::Utf32(order) => (order.read_u32(input).ok()?, 4),
+        };
+        (0xD800..=0xDFFF).contains(&c).then_some((c, len))
+    }
+
+    /// Writes the surrogate `c`.
+    pub fn write_surrogate(self, c: u32, output: &mut Vec<u8>) {
+        match self {
+            Self::Utf8 => output.extend_from_slice(&[
+                0xE0 | (c >> 12) as u8,
+                0x80 | (c >> 6 & 0x3F) as u8,
+                0x80 | (c & 0x3F) as u8,
+            ]),
+            Self::Utf16(order) => output.extend_from_slice(&(c as u16).to_ordered(order)),
+            Self::Utf32(order) => output.extend_from_slice(&c.to_ordered(order)),
+        }
+    }
+}
+
+/// A fault covering the run of code points starting at byte offset `start` for which `bad`
+/// holds.
+pub(crate) fn run_fault(
+    input: &Wtf8,
+    start: usize,
+    reason: &'static str,
+    bad: impl Fn(u32) -> bool,
+) -> Fault {
+    let mut points = input.slice(start, input.len()).code_points();
+    while points.clone().next().is_some_and(&bad) {
+        let _ = points.next();
+    }
+    Fault::new(start, start + points.offset(), reason)
+}
+
+/// Length of the code point `c` in (generalized) UTF-8.
+#[inline]
+pub(crate) const fn len_utf8(c: u32) -> usize {
+    match c {
+        0..=0x7F => 1,
+        0x80..=0x7FF => 2,
+        0x800..=0xFFFF => 3,
+        _ => 4,
+    }
+}
+
+/// FNV-1a over what `encoding` makes of each of `codes`: the text it decodes to and that text
+/// encoded again, each followed by `0xFF`, or `0xFE 0xFF` in place of either on an error. The
+/// tests compare it with the digest of CPython's codecs that `tools/gen_tables.py --digests`
+/// prints, which checks a whole table in a line.
+#[cfg(test)]
+pub(crate) fn digest(encoding: super::Encoding, codes: impl IntoIterator<Item = Vec<u8>>) -> u64 {
+    let mut data = Vec::new();
+    for code in codes {
+        match encoding.decode(&code) {
+            Ok(text) => {
+                data.extend_from_slice(text.as_bytes());
+                data.push(0xFF);
+                match encoding.encode(&text) {
+                    Ok(bytes) => data.extend(bytes.into_iter().chain([0xFF])),
+                    Err(_) => data.extend([0xFE, 0xFF]),
+                }
+            }
+            Err(_) => data.extend([0xFE, 0xFF]),
+        }
+    }
+    data.iter().fold(0xCBF29CE484222325, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001B3)
+    })
+}
+
+/// The double-byte codes of the digests, leads `0x80..=0xFF` and trails `0x30..=0xFF`.
+#[cfg(test)]
+pub(crate) fn pairs() -> impl Iterator<Item = Vec<u8>> {
+    (0x80..=0xFF).flat_map(|lead| (0x30..=0xFF).map(move |trail| vec![lead, trail]))
+}
@@ -0,0 +1,369 @@
+//! The compression codecs `zlib` and `bz2`, on the `flate2` and `bzip2` crates.
+
+use super::{CodecError, Encoding};
+use flate2::{FlushCompress, FlushDecompress, Status};
+
+/// Bytes of output reserved at a time.
+const CHUNK: usize = 8192;
+
+enum Deflate {
+    Zlib(flate2::Compress),
+    Bz2(bzip2::Compress),
+}
+
+/// A streaming compressor, like Python's `zlib.compressobj` and `bz2.BZ2Compressor`.
+///
+/// # Example
+///
+/// ```rust
+/// use rsutil::types::{Compressor, Encoding};
+///
+/// let mut compressor = Compressor::with_level(Encoding::Zlib, 9).unwrap();
+/// let mut data = compressor.compress(b"hello ");
+/// data.extend(compressor.compress(b"world"));
+/// data.extend(compressor.finish());
+/// assert_eq!(Encoding::Zlib.transform_decode(&data), Ok(b"hello world".to_vec()));
+/// ```
+pub struct Compressor {
+    encoding: Encoding,
+    inner: Deflate,
+}
+
+impl Compressor {
+    /// A compressor at the default level of Python, 6 for `Zlib` and 9 for `Bz2`.
+    pub fn new(encoding: Encoding) -> Result<Self, CodecError> {
+        let level = match encoding {
+            Encoding::Bz2 => 9,
+            _ => 6,
+        };
+        Self::with_level(encoding, level)
+    }
+
+    /// A compressor at `level`, `0..=9` for `Zlib` and `1..=9` for `Bz2`.
+    pub fn with_level(encoding: Encoding, level: u32) -> Result<Self, CodecError> {
+        let inner = match (encoding, level) {
+            (Encoding::Zlib, 0..=9) => {
+                Deflate::Zlib(flate2::Compress::new(flate2::Compression::new(level), true))
+            }
+            (Encoding::Bz2, 1..=9) => {
+                Deflate::Bz2(bzip2::Compress::new(bzip2::Compression::new(level), 0))
+            }
+            (Encoding::Zlib | Encoding::Bz2, _) => {
+                return Err(CodecError::InvalidLevel(encoding, level))
+            }
+            _ => return Err(CodecError::NotCompression(encoding)),
+        };
+        Ok(Self { encoding, inner })
+    }
+
+    #[inline]
+    pub fn encoding(&self) -> Encoding {
+        self.encoding
+    }
+
+    /// Compresses the next piece of data; the compressor may hold some of it back until a
+    /// later call.
+    pub fn compress(&mut self, input: &[u8]) -> Vec<u8> {
+        self.run(input, false)
+    }
+
+    /// Ends the stream and returns the rest of it.
+    pub fn finish(mut self) -> Vec<u8> {
+        self.run(&[], true)
+    }
+
+    fn run(&mut self, mut input: &[u8], finish: bool) -> Vec<u8> {
+        let mut output = Vec::with_capacity(input.len() / 2 + 64);
+        loop {
+            if output.len() == output.capacity() {
+                output.reserve(CHUNK);
+            }
+            // a failure can only come from misuse, which `finish` taking `self` rules out
+            let (consumed, end) = match &mut self.inner {
+                Deflate::Zlib(zlib) => {
+                    let before = zlib.total_in();
+                    let flush = match finish {
+                        true => FlushCompress::Finish,
+                        false => FlushCompress::None,
+                    };
+                    let status = zlib.compress_vec(input, &mut output, flush);
+                    let end = matches!(status, Ok(Status::StreamEnd) | Err(_));
+                    (zlib.total_in() - before, end)
+                }
+                Deflate::Bz2(bz2) => {
+                    let before = bz2.total_in();
+                    let action = match finish {
+                        true => bzip2::Action::Finish,
+                        false => bzip2::Action::Run,
+                    };
+                    let status = bz2.compress_vec(input, &mut output, action);
+                    let end = matches!(status, Ok(bzip2::Status::StreamEnd) | Err(_));
+                    (bz2.total_in() - before, end)
+                }
+            };
+            input = &input[consumed as usize..];
+            // without finishing, all is out once the input is in and the output has room left
+            if end || (!finish && input.is_empty() && output.len() < output.capacity()) {
+                return output;
+            }
+        }
+    }
+}
+
+enum Inflate {
+    Zlib(flate2::Decompress),
+    Bz2(bzip2::Decompress),
+}
+
+/// A streaming decompressor, like Python's `zlib.decompressobj` and `bz2.BZ2Decompressor`.
+///
+/// It decompresses one stream; what follows the end of it is kept in
+/// [`Decompressor::unused_data`].
+///
+/// # Example
+///
+/// ```rust
+/// use rsutil::types::{Decompressor, Encoding};
+///
+/// let data = Encoding::Bz2.transform_encode(b"hello").unwrap();
+/// let (head, tail) = data.split_at(20);
+/// let mut decompressor = Decompressor::new(Encoding::Bz2).unwrap();
+/// let mut output = decompressor.decompress(head).unwrap();
+/// output.extend(decompressor.decompress(tail).unwrap());
+/// output.extend(decompressor.decompress(b"more").unwrap());
+/// assert_eq!(output, b"hello");
+/// assert!(decompressor.is_finished());
+/// assert_eq!(decompressor.unused_data(), b"more");
+/// ```
+pub struct Decompressor {
+    encoding: Encoding,
+    inner: Inflate,
+    finished: bool,
+    unused: Vec<u8>,
+}
+
+impl Decompressor {
+    pub fn new(encoding: Encoding) -> Result<Self, CodecError> {
+        let inner = match encoding {
+            Encoding::Zlib => Inflate::Zlib(flate2::Decompress::new(true)),
+            Encoding::Bz2 => Inflate::Bz2(bzip2::Decompress::new(false)),
+            _ => return Err(CodecError::NotCompression(encoding)),
+        };
+        Ok(Self {
+            encoding,
+            inner,
+            finished: false,
+            unused: Vec::new(),
+        })
+    }
+
+    #[inline]
+    pub fn encoding(&self) -> Encoding {
+        self.encoding
+    }
+
+    /// Whether the end of the stream was reached, Python's `eof`.
+    #[inline]
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// The data seen after the end of the stream.
+    #[inline]
+    pub fn unused_data(&self) -> &[u8] {
+        &self.unused
+    }
+
+    /// Decompresses the next piece of the stream.
+    pub fn decompress(&mut self, mut input: &[u8]) -> Result<Vec<u8>, CodecError> {
+        let encoding = self.encoding;
+        let error = |reason: String| CodecError::Transform { encoding, reason };
+        let mut output = Vec::with_capacity(input.len() * 2 + 64);
+        while !self.finished {
+            if output.len() == output.capacity() {
+                output.reserve(CHUNK.max(output.len()));
+            }
+            let written = output.len();
+            let consumed = match &mut self.inner {
+                Inflate::Zlib(zlib) => {
+                    let before = zlib.total_in();
+                    let status = zlib
+                        .decompress_vec(input, &mut output, FlushDecompress::None)
+                        .map_err(|e| match (e.needs_dictionary(), e.message()) {
+                            (Some(_), _) => error("Error 2 while decompressing data".to_string()),
+                            (None, Some(msg)) => {
+                                error(format!("Error -3 while decompressing data: {msg}"))
+                            }
+                            (None, None) => error("Error -3 while decompressing data".to_string()),
+                        })?;
+                    self.finished = status == Status::StreamEnd;
+                    zlib.total_in() - before
+                }
+                Inflate::Bz2(bz2) => {
+                    let before = bz2.total_in();
+                    let status = bz2
+                        .decompress_vec(input, &mut output)
+                        .map_err(|e| match e {
+                            bzip2::Error::Data | bzip2::Error::DataMagic => {
+                                error("Invalid data stream".to_string())
+                            }
+                            _ => error(e.to_string()),
+                        })?;
+                    self.finished = status == bzip2::Status::StreamEnd;
+                    bz2.total_in() - before
+                }
+            };
+            input = &input[consumed as usize..];
+            let stalled = consumed == 0 && output.len() == written;
+            if stalled || (input.is_empty() && output.len() < output.capacity()) {
+                break;
+            }
+        }
+        if self.finished {
+            self.unused.extend_from_slice(input);
+        }
+        Ok(output)
+    }
+}
+
+/// Compresses `input` at the default level, as Python's codecs do.
+pub(crate) fn compress(encoding: Encoding, input: &[u8]) -> Result<Vec<u8>, CodecError> {
+    let mut compressor = Compressor::new(encoding)?;
+    let mut output = compressor.compress(input);
+    output.extend(compressor.finish());
+    Ok(output)
+}
+
+/// Decompresses a whole stream. Like `zlib.decompress` what follows the stream is ignored,
+/// while like `bz2.decompress` the streams concatenated to the first are decompressed too,
+/// up to trailing garbage.
+pub(crate) fn decompress(encoding: Encoding, mut input: &[u8]) -> Result<Vec<u8>, CodecError> {
+    let mut output = Vec::with_capacity(input.len() * 2);
+    let mut streams = 0;
+    while streams == 0 || (encoding == Encoding::Bz2 && !input.is_empty()) {
+        if encoding == Encoding::Bz2 && input.is_empty() {
+            break;
+        }
+        let mut decompressor = Decompressor::new(encoding)?;
+        match decompressor.decompress(input) {
+            Ok(data) => output.extend(data),
+            Err(_) if streams > 0 => break,
+            Err(e) => return Err(e),
+        }
+        if !decompressor.is_finished() {
+            let reason = match encoding {
+                Encoding::Bz2 => {
+                    "Compressed data ended before the end-of-stream marker was reached"
+                }
+                _ => "Error -5 while decompressing data: incomplete or truncated stream",
+            };
+            return Err(CodecError::Transform {
+                encoding,
+                reason: reason.to_string(),
+            });
+        }
+        streams += 1;
+        input = &input[input.len() - decompressor.unused_data().len()..];
+    }
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Text that compresses well, followed by bytes that don't.
+    fn sample() -> Vec<u8> {
+        let mut data = b"the quick brown fox jumps over the lazy dog. ".repeat(500);
+        let mut x = 1u32;
+        data.extend((0..20000).map(|_| {
+            x = x.wrapping_mul(1103515245).wrapping_add(12345);
+            (x >> 16) as u8
+        }));
+        data
+    }
+
+    #[test]
+    fn test_chunks() {
+        let data = sample();
+        for encoding in [Encoding::Zlib, Encoding::Bz2] {
+            let mut compressor = Compressor::with_level(encoding, 1).unwrap();
+            let mut compressed = Vec::new();
+            for chunk in data.chunks(7) {
+                compressed.extend(compressor.compress(chunk));
+            }
+            compressed.extend(compressor.finish());
+            assert_eq!(encoding.transform_decode(&compressed).as_ref(), Ok(&data));
+
+            let mut decompressor = Decompressor::new(encoding).unwrap();
+            let mut output = Vec::new();
+            for chunk in compressed.chunks(3) {
+                assert!(!decompressor.is_finished(), "{encoding}");
+                output.extend(decompressor.decompress(chunk).unwrap());
+            }
+            assert!(decompressor.is_finished(), "{encoding}");
+            assert!(decompressor.unused_data().is_empty(), "{encoding}");
+            assert_eq!(output, data, "{encoding}");
+        }
+    }
+
+    #[test]
+    fn test_unused_data() {
+        let data = sample();
+        let compressed = Encoding::Zlib.transform_encode(&data).unwrap();
+        let mut decompressor = Decompressor::new(Encoding::Zlib).unwrap();
+        let (head, tail) = compressed.split_at(compressed.len() - 2);
+        let mut output = decompressor.decompress(head).unwrap();
+        assert!(!decompressor.is_finished());
+        output.extend(decompressor.decompress(&[tail, b"trail"].concat()).unwrap());
+        assert!(decompressor.is_finished());
+        assert_eq!(decompressor.decompress(b"ing"), Ok(Vec::new()));
+        assert_eq!(decompressor.unused_data(), b"trailing");
+        assert_eq!(output, data);
+
+        // a second bz2 stream is left for a decompressor of its own
+        let first = Encoding::Bz2.transform_encode(b"first").unwrap();
+        let second = Encoding::Bz2.transform_encode(b"second").unwrap();
+        let mut decompressor = Decompressor::new(Encoding::Bz2).unwrap();
+        let output = decompressor
+            .decompress(&[&first[..], &second].concat())
+            .unwrap();
+        assert_eq!(output, b"first");
+        assert!(decompressor.is_finished());
+        assert_eq!(decompressor.unused_data(), second);
+        let mut decompressor = Decompressor::new(Encoding::Bz2).unwrap();
+        assert_eq!(decompressor.decompress(&second), Ok(b"second".to_vec()));
+        assert!(decompressor.is_finished());
+    }
+
+    #[test]
+    fn test_levels() {
+        for level in 0..=9 {
+            let mut compressor = Compressor::with_level(Encoding::Zlib, level).unwrap();
+            let mut compressed = compressor.compress(b"level");
+            compressed.extend(compressor.finish());
+            assert_eq!(
+                decompress(Encoding::Zlib, &compressed),
+                Ok(b"level".to_vec())
+            );
+        }
+        for (encoding, level) in [
+            (Encoding::Zlib, 10),
+            (Encoding::Bz2, 0),
+            (Encoding::Bz2, 10),
+        ] {
+            assert!(matches!(
+                Compressor::with_level(encoding, level),
+                Err(CodecError::InvalidLevel(e, l)) if e == encoding && l == level
+            ));
+        }
+        assert!(matches!(
+            Compressor::new(Encoding::Utf8),
+            Err(CodecError::NotCompression(Encoding::Utf8))
+        ));
+        assert!(matches!(
+            Decompressor::new(Encoding::Base64),
+            Err(CodecError::NotCompression(Encoding::Base64))
+        ));
+    }
+}
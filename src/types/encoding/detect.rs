@@ -0,0 +1,529 @@
+use super::charmap::single_byte;
+use super::{Encoding, Errors, IncrementalDecoder, Wtf8};
+
+/// Bytes of the input looked at past the BOM.
+const SAMPLE: usize = 64 * 1024;
+
+/// Candidates of the statistical guess, in order of preference when they score the same.
+/// Subsets come before their supersets, so `ShiftJis` wins over `Cp932` unless the input
+/// uses the extensions of Windows.
+const CANDIDATES: &[Encoding] = &[
+    Encoding::Cp1252,
+    Encoding::Cp1250,
+    Encoding::Cp1251,
+    Encoding::Koi8R,
+    Encoding::Cp866,
+    Encoding::Cp1253,
+    Encoding::Cp1254,
+    Encoding::Cp1255,
+    Encoding::Cp1256,
+    Encoding::Cp1257,
+    Encoding::Tis620,
+    Encoding::ShiftJis,
+    Encoding::Cp932,
+    Encoding::EucJp,
+    Encoding::Gb2312,
+    Encoding::Gbk,
+    Encoding::Gb18030,
+    Encoding::Big5,
+    Encoding::Cp950,
+    Encoding::EucKr,
+    Encoding::Cp949,
+];
+
+/// The most frequent Han characters of Chinese, both simplified and traditional, and of
+/// Japanese.
+const COMMON_HAN: &str = "的一是不了在人有我他这个们中来上大为和国地到以说时要就出会可也你对\
+    生能而子那得于着下自之年过发后作里用道行所然家种事成方多经么去法学如都同现当没动面起看\
+    定天分还进好小部其些主样理心她本前开但因只从想实日這個們來為國說時會對過發後裡與經麼學\
+    現當沒動還進點樣體長東語見書間気円社員";
+
+/// The most frequent Hangul syllables of Korean.
+const COMMON_HANGUL: &str =
+    "이의다는에가을를하고서지한기로사도자리수대나인게어전정아시있없것그들면해라우주보";
+
+/// Cyrillic letters seldom seen in Russian text, which other scripts often turn into when
+/// read as a Cyrillic code page.
+const RARE_CYRILLIC: &str = "ъщэфцюшжхйёЪЩЭФЦЮШЖХЙЁ";
+
+/// The result of [`Encoding::detect`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Detection {
+    encoding: Encoding,
+    confidence: f32,
+    bom: usize,
+}
+
+impl Detection {
+    #[inline]
+    fn new(encoding: Encoding, confidence: f32, bom: usize) -> Self {
+        Self {
+            encoding,
+            confidence,
+            bom,
+        }
+    }
+
+    #[inline]
+    pub fn encoding(&self) -> Encoding {
+        self.encoding
+    }
+
+    /// How sure the guess is, from `0.0` to `1.0`; only a BOM or input that is ASCII
+    /// throughout gives `1.0`.
+    #[inline]
+    pub fn confidence(&self) -> f32 {
+        self.confidence
+    }
+
+    /// Length of the BOM at the start of the input, `0` without one. Decode the input past it,
+    /// since the byte order specific codecs keep a BOM as `U+FEFF`.
+    #[inline]
+    pub fn bom(&self) -> usize {
+        self.bom
+    }
+}
+
+impl Encoding {
+    /// Guesses the text encoding of `input`, like the `chardet` package of Python.
+    ///
+    /// A BOM of UTF-8, UTF-16 or UTF-32 decides the encoding on its own. Otherwise the first
+    /// 64 KiB are checked for the zero bytes of UTF-16 and UTF-32, for plain ASCII with the
+    /// escapes of ISO-2022 or HZ, and for valid UTF-8. What remains is decoded with each of
+    /// the single-byte Windows code pages and the CJK encodings, and the text that looks the
+    /// most like natural language wins. That guess needs some non-ASCII text to go on, and
+    /// its confidence drops when other encodings read the input nearly as well. Text in Latin
+    /// script reads as well with each of the Latin code pages, and comes out as `Cp1252` unless
+    /// it has letters only the others have.
+    ///
+    /// When the first 64 KiB are ASCII, the rest is scanned too: input that is ASCII throughout
+    /// comes out as `Ascii`, and otherwise the checks from UTF-8 on look at the 64 KiB from
+    /// the first non-ASCII byte. Empty input comes out as `Ascii` and input no encoding can
+    /// read as `Latin1`, both with a confidence of `0.0`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rsutil::types::Encoding;
+    ///
+    /// let detection = Encoding::detect(b"\xff\xfeh\x00i\x00");
+    /// assert_eq!(detection.encoding(), Encoding::Utf16le);
+    /// assert_eq!(detection.confidence(), 1.0);
+    /// assert_eq!(detection.bom(), 2);
+    ///
+    /// assert_eq!(Encoding::detect(b"plain text").encoding(), Encoding::Ascii);
+    /// assert_eq!(Encoding::detect("gr\u{fc}\u{df}e".as_bytes()).encoding(), Encoding::Utf8);
+    ///
+    /// let text = "\u{65e5}\u{672c}\u{8a9e}\u{306e}\u{30c6}\u{30ad}\u{30b9}\u{30c8}\u{3067}\u{3059}";
+    /// let bytes = Encoding::ShiftJis.encode(text).unwrap();
+    /// let detection = Encoding::detect(&bytes);
+    /// assert_eq!(detection.encoding(), Encoding::ShiftJis);
+    /// assert_eq!(detection.encoding().decode(&bytes[detection.bom()..]), Ok(text.to_string()));
+    ///
+    /// let bytes = Encoding::Cp1251.encode("\u{41f}\u{440}\u{438}\u{432}\u{435}\u{442}, \u{43c}\u{438}\u{440}").unwrap();
+    /// assert_eq!(Encoding::detect(&bytes).encoding(), Encoding::Cp1251);
+    /// ```
+    pub fn detect(input: &[u8]) -> Detection {
+        if let Some((encoding, bom)) = bom(input) {
+            return Detection::new(encoding, 1.0, bom);
+        }
+        if input.is_empty() {
+            return Detection::new(Self::Ascii, 0.0, 0);
+        }
+        let mut sample = &input[..input.len().min(SAMPLE)];
+        if let Some(detection) = wide(sample) {
+            return detection;
+        }
+        if sample.is_ascii() {
+            if let Some(detection) = escaped(sample) {
+                return detection;
+            }
+            // like a long log, the text may only turn non-ASCII past the sample
+            match input.iter().position(|b| !b.is_ascii()) {
+                Some(start) => sample = &input[start..input.len().min(start + SAMPLE)],
+                None => return Detection::new(Self::Ascii, 1.0, 0),
+            }
+        }
+        if let Some(detection) = utf8(sample) {
+            return detection;
+        }
+        guess(sample)
+    }
+}
+
+fn bom(input: &[u8]) -> Option<(Encoding, usize)> {
+    Some(match input {
+        [0xEF, 0xBB, 0xBF, ..] => (Encoding::Utf8, 3),
+        [0xFF, 0xFE, 0x00, 0x00, ..] => (Encoding::Utf32le, 4),
+        [0x00, 0x00, 0xFE, 0xFF, ..] => (Encoding::Utf32be, 4),
+        [0xFF, 0xFE, ..] => (Encoding::Utf16le, 2),
+        [0xFE, 0xFF, ..] => (Encoding::Utf16be, 2),
+        _ => return None,
+    })
+}
+
+/// Whether `input` decodes with `encoding`, tolerating a sequence cut at the end.
+fn decodes(encoding: Encoding, input: &[u8]) -> Option<String> {
+    IncrementalDecoder::new(encoding, Errors::Strict)
+        .ok()?
+        .decode(input, false)
+        .ok()
+}
+
+/// UTF-32 and UTF-16 without a BOM, told apart by where the zero bytes of ASCII text fall.
+fn wide(input: &[u8]) -> Option<Detection> {
+    if input.len() >= 4 && input.len().is_multiple_of(4) {
+        let units = input.chunks_exact(4);
+        let le = units.clone().all(|u| u[3] == 0 && u[2] <= 0x10);
+        let be = units.clone().all(|u| u[0] == 0 && u[1] <= 0x10);
+        for (fits, encoding) in [(le, Encoding::Utf32le), (be, Encoding::Utf32be)] {
+            if fits && decodes(encoding, input).is_some() {
+                return Some(Detection::new(encoding, 0.95, 0));
+            }
+        }
+    }
+    if input.len() < 2 {
+        return None;
+    }
+    let units = input.len() / 2;
+    let pairs = input.chunks_exact(2);
+    let le = pairs.clone().filter(|u| u[0] != 0 && u[1] == 0).count();
+    let be = pairs.filter(|u| u[0] == 0 && u[1] != 0).count();
+    for (zeros, other, encoding) in [(le, be, Encoding::Utf16le), (be, le, Encoding::Utf16be)] {
+        if zeros * 10 >= units * 3 && other * 20 < units && decodes(encoding, input).is_some() {
+            let confidence = 0.5 + 0.45 * zeros as f32 / units as f32;
+            return Some(Detection::new(encoding, confidence, 0));
+        }
+    }
+    None
+}
+
+/// The 7-bit encodings that switch to a CJK character set with escape sequences.
+fn escaped(input: &[u8]) -> Option<Detection> {
+    let contains = |needle: &[u8]| input.windows(needle.len()).any(|w| w == needle);
+    let candidates: &[Encoding] = if contains(b"\x1b$)C") {
+        &[Encoding::Iso2022Kr]
+    } else if input.contains(&0x1B) {
+        &[
+            Encoding::Iso2022Jp,
+            Encoding::Iso2022JpExt,
+            Encoding::Iso2022Jp2,
+            Encoding::Iso2022Jp2004,
+        ]
+    } else if contains(b"~{") {
+        &[Encoding::Hz]
+    } else {
+        return None;
+    };
+    candidates.iter().find_map(|&encoding| {
+        decodes(encoding, input)
+            .filter(|text| !text.is_ascii())
+            .map(|_| Detection::new(encoding, 0.95, 0))
+    })
+}
+
+/// Valid UTF-8, growing more certain with each multibyte sequence like `chardet` does.
+fn utf8(input: &[u8]) -> Option<Detection> {
+    let text = match std::str::from_utf8(input) {
+        Ok(text) => text,
+        // a sequence cut at the end of the sample
+        Err(e) if e.error_len().is_none() => std::str::from_utf8(&input[..e.valid_up_to()]).ok()?,
+        Err(_) => return None,
+    };
+    let multibyte = text.chars().filter(|c| !c.is_ascii()).count();
+    let confidence = 1.0 - 0.99 * 0.5f32.powi(multibyte.min(6) as i32);
+    Some(Detection::new(Encoding::Utf8, confidence, 0))
+}
+
+/// Scores the text of every candidate that decodes the input.
+fn guess(input: &[u8]) -> Detection {
+    let decoded = CANDIDATES
+        .iter()
+        .filter_map(|&encoding| {
+            let text = decodes(encoding, input)?;
+            let score = score(&text, &sequences(encoding, input, &text));
+            Some((encoding, score, text))
+        })
+        .collect::<Vec<_>>();
+    let Some((encoding, best, text)) = decoded.iter().fold(
+        None,
+        |best: Option<&(Encoding, f32, String)>, item| match best {
+            Some(best) if best.1 >= item.1 => Some(best),
+            _ => Some(item),
+        },
+    ) else {
+        return Detection::new(Encoding::Latin1, 0.0, 0);
+    };
+    // a superset reading the input the same way is no rival
+    let second = decoded
+        .iter()
+        .filter(|(_, _, other)| other != text)
+        .map(|&(_, score, _)| score)
+        .fold(f32::MIN, f32::max);
+    let margin = match *best > 0.0 {
+        true => ((best - second) / best).clamp(0.0, 1.0),
+        false => 0.0,
+    };
+    let confidence = 0.9 * best.clamp(0.0, 1.0) * (0.5 + 0.5 * margin);
+    Detection::new(*encoding, confidence, 0)
+}
+
+/// The bytes of `input` each character of `text`, its decoding with `encoding`, comes from.
+fn sequences<'a>(encoding: Encoding, input: &'a [u8], text: &str) -> Vec<&'a [u8]> {
+    let mut encoder = match single_byte(encoding) {
+        true => None,
+        false => encoding.encoder().ok(),
+    };
+    let mut bytes = Vec::new();
+    let mut pos = 0;
+    let mut buf = [0; 4];
+    text.chars()
+        .map(|c| {
+            let len = match &mut encoder {
+                Some(encoder) if !c.is_ascii() => {
+                    bytes.clear();
+                    let c = Wtf8::new(c.encode_utf8(&mut buf));
+                    // a character that only decodes takes a pair like most of the others
+                    match encoder.encode(c, &mut bytes, true) {
+                        Ok(_) => bytes.len(),
+                        Err(_) => 2,
+                    }
+                }
+                _ => 1,
+            };
+            let start = pos.min(input.len());
+            pos += len;
+            &input[start..pos.min(input.len())]
+        })
+        .collect()
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Script {
+    Latin,
+    Greek,
+    Cyrillic,
+    Hebrew,
+    Arabic,
+    Thai,
+    Kana,
+    Han,
+    Hangul,
+    Other,
+}
+
+fn script(c: char) -> Script {
+    if !c.is_alphabetic() {
+        return match c {
+            '\u{30fc}' | '\u{ff70}' => Script::Kana,
+            _ => Script::Other,
+        };
+    }
+    match c as u32 {
+        0x41..=0x5A | 0x61..=0x7A | 0xC0..=0x24F | 0x1E00..=0x1EFF => Script::Latin,
+        0x370..=0x3FF => Script::Greek,
+        0x400..=0x52F => Script::Cyrillic,
+        0x590..=0x5FF => Script::Hebrew,
+        0x600..=0x6FF | 0xFB50..=0xFDFF | 0xFE70..=0xFEFF => Script::Arabic,
+        0xE00..=0xE7F => Script::Thai,
+        0x3040..=0x30FF | 0x31F0..=0x31FF | 0xFF66..=0xFF9F => Script::Kana,
+        0x3400..=0x4DBF | 0x4E00..=0x9FFF | 0xF900..=0xFAFF | 0x20000..=0x3FFFF => Script::Han,
+        0x1100..=0x11FF | 0x3130..=0x318F | 0xAC00..=0xD7A3 => Script::Hangul,
+        _ => Script::Other,
+    }
+}
+
+/// How much `text` looks like natural language, in points per byte of its non-ASCII
+/// characters, whose `sequences` those are; well-formed text scores about `1.0`. Letters in
+/// words of a single script, frequent letters and CJK characters score, while mixed scripts,
+/// words made only of accented Latin letters, misplaced Thai and Arabic vowels, word-final
+/// Arabic letters inside a word and control characters cost. So does a double-byte
+/// character ending on an ASCII letter or digit, which in text of a single-byte encoding
+/// is a letter followed by the rest of its word.
+fn score(text: &str, sequences: &[&[u8]]) -> f32 {
+    let chars = text.chars().collect::<Vec<_>>();
+    let (mut score, mut bytes) = (0.0, 0);
+    let mut word = 0..0;
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_ascii() {
+            continue;
+        }
+        let sequence = sequences.get(i).copied().unwrap_or_default();
+        bytes += sequence.len().max(1);
+        if let [_, trail] = sequence {
+            if trail.is_ascii_alphanumeric() {
+                score -= 1.0;
+            }
+        }
+        let prev = i.checked_sub(1).map(|i| chars[i]);
+        let next = chars.get(i + 1).copied();
+        score += match script(c) {
+            Script::Kana => match c {
+                '\u{ff66}'..='\u{ff9f}' => 0.5,
+                _ => 2.0,
+            },
+            Script::Han if COMMON_HAN.contains(c) => 2.0,
+            Script::Hangul if COMMON_HANGUL.contains(c) => 2.0,
+            Script::Han | Script::Hangul => 1.5,
+            Script::Other => match c as u32 {
+                0xE000..=0xF8FF | 0xFFFD => -2.0,
+                0x3000..=0x303F | 0xFF01..=0xFF65 => 1.0,
+                _ if c.is_control() => -2.0,
+                _ => 0.0,
+            },
+            Script::Latin => {
+                if !word.contains(&i) {
+                    word = i..i;
+                    while chars.get(word.end).is_some_and(|c| c.is_alphabetic()) {
+                        word.end += 1;
+                    }
+                    while word.start > 0 && chars[word.start - 1].is_alphabetic() {
+                        word.start -= 1;
+                    }
+                }
+                let letters = &chars[word.clone()];
+                match letters.len() == 1 || letters.iter().any(char::is_ascii_alphabetic) {
+                    true if !mid_word_upper(c, prev) => 1.0,
+                    _ => -1.0,
+                }
+            }
+            script => {
+                let foreign = |n: Option<char>| {
+                    n.is_some_and(|n| n.is_alphabetic() && self::script(n) != script)
+                };
+                let misplaced = match script {
+                    Script::Greek => c == '\u{3c2}' && next.is_some_and(char::is_alphabetic),
+                    // the word-final teh marbuta and alef maksura, and vowel marks off a letter
+                    Script::Arabic => match c {
+                        '\u{629}' | '\u{649}' => next.is_some_and(char::is_alphabetic),
+                        '\u{64b}'..='\u{652}' => !prev.is_some_and(|p| self::script(p) == script),
+                        _ => false,
+                    },
+                    Script::Thai => !thai(c, prev, next),
+                    _ => false,
+                };
+                if foreign(prev) || foreign(next) || mid_word_upper(c, prev) || misplaced {
+                    -1.0
+                } else if c.is_uppercase() && prev.is_some_and(char::is_uppercase) {
+                    0.0
+                } else if RARE_CYRILLIC.contains(c) {
+                    0.25
+                } else if script == Script::Thai {
+                    // written text carries about one vowel or tone mark per consonant
+                    match thai_consonant(c) {
+                        true => 0.5,
+                        false => 1.5,
+                    }
+                } else {
+                    1.0
+                }
+            }
+        };
+    }
+    score / bytes.max(1) as f32
+}
+
+/// Whether a Thai vowel or tone mark sits next to the consonant it belongs to.
+fn thai(c: char, prev: Option<char>, next: Option<char>) -> bool {
+    match c {
+        '\u{e31}' | '\u{e34}'..='\u{e3a}' | '\u{e47}'..='\u{e4e}' => {
+            prev.is_some_and(|p| thai_consonant(p) || thai_mark(p))
+        }
+        '\u{e40}'..='\u{e44}' => next.is_some_and(thai_consonant),
+        _ => true,
+    }
+}
+
+#[inline]
+fn thai_consonant(c: char) -> bool {
+    ('\u{e01}'..='\u{e2e}').contains(&c)
+}
+
+#[inline]
+fn thai_mark(c: char) -> bool {
+    matches!(c, '\u{e31}' | '\u{e34}'..='\u{e3a}' | '\u{e47}'..='\u{e4e}')
+}
+
+/// An uppercase letter right after a lowercase one, rare in text but common in mojibake.
+#[inline]
+fn mid_word_upper(c: char, prev: Option<char>) -> bool {
+    c.is_uppercase() && prev.is_some_and(char::is_lowercase)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_guess() {
+        let samples = [
+            (Encoding::Cp1252, "Der B\u{e4}r l\u{e4}uft \u{fc}ber die Br\u{fc}cke."),
+            (Encoding::Cp1252, "\u{c7}a va tr\u{e8}s bien"),
+            (Encoding::Cp1252, "El ni\u{f1}o comi\u{f3} m\u{e1}s pi\u{f1}as el a\u{f1}o pasado."),
+            (Encoding::Cp1250, "Za\u{17c}\u{f3}\u{142}\u{107} g\u{119}\u{15b}l\u{105} ja\u{17a}\u{144}, pisz\u{119} po polsku."),
+            (Encoding::Cp1250, "P\u{159}\u{ed}li\u{161} \u{17e}lu\u{165}ou\u{10d}k\u{fd} k\u{16f}\u{148} \u{fa}p\u{11b}l \u{10f}\u{e1}belsk\u{e9} \u{f3}dy."),
+            (Encoding::Cp1251, "\u{41f}\u{440}\u{438}\u{432}\u{435}\u{442}, \u{43a}\u{430}\u{43a} \u{434}\u{435}\u{43b}\u{430}? \u{421}\u{435}\u{433}\u{43e}\u{434}\u{43d}\u{44f} \u{445}\u{43e}\u{440}\u{43e}\u{448}\u{430}\u{44f} \u{43f}\u{43e}\u{433}\u{43e}\u{434}\u{430}."),
+            (Encoding::Koi8R, "\u{41f}\u{440}\u{438}\u{432}\u{435}\u{442}, \u{43a}\u{430}\u{43a} \u{434}\u{435}\u{43b}\u{430}? \u{421}\u{435}\u{433}\u{43e}\u{434}\u{43d}\u{44f} \u{445}\u{43e}\u{440}\u{43e}\u{448}\u{430}\u{44f} \u{43f}\u{43e}\u{433}\u{43e}\u{434}\u{430}."),
+            (Encoding::Cp866, "\u{41f}\u{440}\u{438}\u{432}\u{435}\u{442}, \u{43a}\u{430}\u{43a} \u{434}\u{435}\u{43b}\u{430}? \u{421}\u{435}\u{433}\u{43e}\u{434}\u{43d}\u{44f} \u{445}\u{43e}\u{440}\u{43e}\u{448}\u{430}\u{44f} \u{43f}\u{43e}\u{433}\u{43e}\u{434}\u{430}."),
+            (Encoding::Cp1253, "\u{39a}\u{3b1}\u{3bb}\u{3b7}\u{3bc}\u{3ad}\u{3c1}\u{3b1} \u{3c3}\u{3b1}\u{3c2}, \u{3c4}\u{3b9} \u{3ba}\u{3ac}\u{3bd}\u{3b5}\u{3c4}\u{3b5} \u{3c3}\u{3ae}\u{3bc}\u{3b5}\u{3c1}\u{3b1};"),
+            (Encoding::Cp1256, "\u{645}\u{631}\u{62d}\u{628}\u{627} \u{628}\u{627}\u{644}\u{639}\u{627}\u{644}\u{645}\u{60c} \u{643}\u{64a}\u{641} \u{62d}\u{627}\u{644}\u{643} \u{627}\u{644}\u{64a}\u{648}\u{645}\u{61f} \u{647}\u{630}\u{647} \u{645}\u{62f}\u{64a}\u{646}\u{629} \u{62c}\u{645}\u{64a}\u{644}\u{629}."),
+            (Encoding::ShiftJis, "\u{65e5}\u{672c}\u{8a9e}\u{306e}\u{30c6}\u{30ad}\u{30b9}\u{30c8}\u{3067}\u{3059}\u{3002}\u{4eca}\u{65e5}\u{306f}\u{3044}\u{3044}\u{5929}\u{6c17}\u{3067}\u{3059}\u{306d}\u{3002}"),
+            (Encoding::EucJp, "\u{65e5}\u{672c}\u{8a9e}\u{306e}\u{30c6}\u{30ad}\u{30b9}\u{30c8}\u{3067}\u{3059}\u{3002}\u{4eca}\u{65e5}\u{306f}\u{3044}\u{3044}\u{5929}\u{6c17}\u{3067}\u{3059}\u{306d}\u{3002}"),
+            (Encoding::Gbk, "\u{6211}\u{4eec}\u{662f}\u{4e2d}\u{56fd}\u{4eba}\u{ff0c}\u{4eca}\u{5929}\u{5929}\u{6c14}\u{5f88}\u{597d}\u{3002}\u{8fd9}\u{662f}\u{4e00}\u{4e2a}\u{7b80}\u{5355}\u{7684}\u{4f8b}\u{5b50}\u{3002}"),
+            (Encoding::Big5, "\u{6211}\u{5011}\u{662f}\u{4e2d}\u{570b}\u{4eba}\u{ff0c}\u{4eca}\u{5929}\u{5929}\u{6c23}\u{5f88}\u{597d}\u{3002}\u{9019}\u{662f}\u{4e00}\u{500b}\u{7c21}\u{55ae}\u{7684}\u{4f8b}\u{5b50}\u{3002}"),
+            (Encoding::EucKr, "\u{d55c}\u{ad6d}\u{c5b4} \u{d14d}\u{c2a4}\u{d2b8}\u{c785}\u{b2c8}\u{b2e4}. \u{c624}\u{b298}\u{c740} \u{b0a0}\u{c528}\u{ac00} \u{c88b}\u{c2b5}\u{b2c8}\u{b2e4}."),
+        ];
+        for (encoding, text) in samples {
+            let bytes = encoding.encode(text).unwrap();
+            let detected = Encoding::detect(&bytes).encoding();
+            // a superset that reads the bytes the same way is as good
+            let decoded = detected.decode(&bytes);
+            assert_eq!(decoded.as_deref(), Ok(text), "read as {detected}");
+        }
+    }
+
+    #[test]
+    fn test_guess_baltic() {
+        // Latin text scores the same in the Latin code pages, which it takes the language to
+        // tell apart, but it is no CJK or Arabic text
+        let samples = [
+            "\u{104}\u{17e}uolas \u{17e}aliuoja, o \u{161}altinis \u{10d}iurlena.",
+            "Gl\u{101}\u{17e}\u{161}\u{137}\u{16b}\u{146}a r\u{16b}\u{137}\u{12b}\u{161}i dz\u{113}rum\u{101} \u{10d}iepj Baha koncertfl\u{12b}\u{123}e\u{13c}u v\u{101}kus.",
+        ];
+        for text in samples {
+            let bytes = Encoding::Cp1257.encode(text).unwrap();
+            let detected = Encoding::detect(&bytes).encoding();
+            let latin = [Encoding::Cp1252, Encoding::Cp1250, Encoding::Cp1257];
+            assert!(latin.contains(&detected), "read as {detected}");
+        }
+    }
+
+    #[test]
+    fn test_detect_past_sample() {
+        let head = "a".repeat(SAMPLE + 4464);
+        let text = format!("{head}caf\u{e9}");
+        let detection = Encoding::detect(text.as_bytes());
+        assert_eq!(detection.encoding(), Encoding::Utf8);
+        assert!(detection.confidence() < 1.0);
+        assert_eq!(
+            detection.encoding().decode(text.as_bytes()),
+            Ok(text.clone())
+        );
+
+        let text =
+            format!("{head}\u{41f}\u{440}\u{438}\u{432}\u{435}\u{442}, \u{43c}\u{438}\u{440}");
+        let bytes = Encoding::Cp1251.encode(&text).unwrap();
+        assert_eq!(Encoding::detect(&bytes).encoding(), Encoding::Cp1251);
+
+        let detection = Encoding::detect(head.as_bytes());
+        assert_eq!(
+            (detection.encoding(), detection.confidence()),
+            (Encoding::Ascii, 1.0)
+        );
+        let detection = Encoding::detect(b"");
+        assert_eq!(
+            (detection.encoding(), detection.confidence()),
+            (Encoding::Ascii, 0.0)
+        );
+    }
+}
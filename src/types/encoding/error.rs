@@ -0,0 +1,134 @@
+use super::{Encoding, Errors, Wtf8, Wtf8Buf};
+use std::fmt::{Display, Formatter, Result};
+
+/// Errors raised while encoding or decoding text, modeled after Python's
+/// `UnicodeEncodeError`/`UnicodeDecodeError`.
+#[derive(Debug, Clone, Eq, PartialEq, thiserror::Error)]
+pub enum CodecError {
+    /// `bytes` at `start..end` of the input can't be decoded.
+    #[error("'{encoding}' codec can't decode {}: {reason}", Span::bytes(.bytes, *.start))]
+    Decode {
+        encoding: Encoding,
+        start: usize,
+        end: usize,
+        bytes: Vec<u8>,
+        reason: &'static str,
+    },
+    /// `chars` at character positions `start..end` of the input can't be encoded.
+    #[error("'{encoding}' codec can't encode {}: {reason}", Span::chars(.chars, *.start))]
+    Encode {
+        encoding: Encoding,
+        start: usize,
+        end: usize,
+        chars: Wtf8Buf,
+        reason: &'static str,
+    },
+    /// The encoding is declared but has no implementation yet.
+    #[error("'{0}' codec is not implemented")]
+    Unsupported(Encoding),
+    /// The encoding converts bytes to bytes and can't be used for text.
+    #[error("'{0}' is not a text encoding")]
+    NotText(Encoding),
+    /// The encoding converts text and can't be used as a bytes-to-bytes transform.
+    #[error("'{0}' is not a bytes-to-bytes encoding")]
+    NotTransform(Encoding),
+    /// A bytes-to-bytes transform got malformed input, like Python's `binascii.Error`.
+    #[error("decoding with '{encoding}' codec failed: {reason}")]
+    Transform { encoding: Encoding, reason: String },
+    /// The encoding isn't a compression codec.
+    #[error("'{0}' is not a compression encoding")]
+    NotCompression(Encoding),
+    /// The compression level is out of the range of the codec.
+    #[error("invalid compression level {1} for '{0}'")]
+    InvalidLevel(Encoding, u32),
+    /// The name belongs to a built-in or an already registered encoding.
+    #[error("encoding '{0}' is already registered")]
+    AlreadyRegistered(String),
+    #[error("unknown encoding: {0}")]
+    UnknownEncoding(String),
+    /// The encoding doesn't take the error handler, like `idna` any but `strict`, or the
+    /// handler needs a feature that is off, like `namereplace`.
+    #[error("unsupported error handling '{1}' for '{0}'")]
+    UnsupportedErrors(Encoding, Errors),
+    #[error("unknown error handler name '{0}'")]
+    UnknownErrors(String),
+}
+
+impl CodecError {
+    pub(crate) fn decode(
+        encoding: Encoding,
+        input: &[u8],
+        start: usize,
+        end: usize,
+        reason: &'static str,
+    ) -> Self {
+        Self::Decode {
+            encoding,
+            start,
+            end,
+            bytes: input[start..end].to_vec(),
+            reason,
+        }
+    }
+
+    /// `start`/`end` are byte offsets into `input`; the error reports character positions.
+    pub(crate) fn encode(
+        encoding: Encoding,
+        input: &Wtf8,
+        start: usize,
+        end: usize,
+        reason: &'static str,
+    ) -> Self {
+        let position = input.count_to(start);
+        let chars = input.slice(start, end).to_owned();
+        Self::Encode {
+            encoding,
+            start: position,
+            end: position + chars.code_points().count(),
+            chars,
+            reason,
+        }
+    }
+}
+
+/// Formats the offending part of the input the way Python does.
+enum Span<'a> {
+    Bytes(&'a [u8], usize),
+    Chars(&'a Wtf8, usize),
+}
+
+impl<'a> Span<'a> {
+    fn bytes(bytes: &'a [u8], start: usize) -> Self {
+        Self::Bytes(bytes, start)
+    }
+
+    fn chars(chars: &'a Wtf8, start: usize) -> Self {
+        Self::Chars(chars, start)
+    }
+}
+
+impl Display for Span<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Self::Bytes([byte], start) => write!(f, "byte 0x{byte:02x} in position {start}"),
+            Self::Bytes(bytes, start) => {
+                write!(
+                    f,
+                    "bytes in position {start}-{}",
+                    start + bytes.len().max(1) - 1
+                )
+            }
+            Self::Chars(chars, start) => match chars.code_points().count() {
+                1 => {
+                    let ch = chars.code_points().next().unwrap_or_default();
+                    match ch {
+                        0..=0xFF => write!(f, "character '\\x{ch:02x}' in position {start}"),
+                        0x100..=0xFFFF => write!(f, "character '\\u{ch:04x}' in position {start}"),
+                        _ => write!(f, "character '\\U{ch:08x}' in position {start}"),
+                    }
+                }
+                n => write!(f, "characters in position {start}-{}", start + n - 1),
+            },
+        }
+    }
+}
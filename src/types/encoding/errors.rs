@@ -0,0 +1,597 @@
+use super::codec::{Decoder, Encoder, Fault, Form};
+use super::{CodecError, Wtf8, Wtf8Buf};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// How encoding and decoding deal with invalid input, mirroring Python's `errors` argument.
+///
+/// - `Strict`: Fail with a [`CodecError`] reporting the offending position.
+/// - `Ignore`: Drop the offending input.
+/// - `Replace`: Substitute `U+FFFD` when decoding and `?` when encoding.
+/// - `BackslashReplace`: Substitute `\xNN`, `\uNNNN` or `\UNNNNNNNN` escapes.
+/// - `XmlCharRefReplace`: Substitute `&#NNNN;` references; encoding only.
+/// - `NameReplace`: Substitute `\N{UNICODE NAME}` escapes; encoding only, and only with the
+///   `namereplace` feature, which brings the Unicode name tables.
+/// - `SurrogateEscape`: Decode each undecodable byte `0xNN` as the lone surrogate `U+DCNN`
+///   and encode such surrogates back to the original byte, making the round trip lossless.
+/// - `SurrogatePass`: Let lone surrogates through the UTF-8, UTF-16 and UTF-32 codecs, as
+///   found in data from Windows or Java; other codecs fail like with `Strict`.
+///
+/// The encoding-only handlers behave like `Strict` when decoding, where Python refuses them.
+/// The names parse and serialize as Python spells them, e.g. `"surrogateescape"`.
+///
+/// # Example
+///
+/// ```rust
+/// use rsutil::types::{Encoding, Errors};
+///
+/// let raw = b"temp=21\xb0C";
+/// let text = Encoding::Utf8.decode_with(raw, Errors::Replace).unwrap();
+/// assert_eq!(text.to_string_lossy(), "temp=21\u{fffd}C");
+/// let text = Encoding::Utf8.decode_with(raw, Errors::BackslashReplace).unwrap();
+/// assert_eq!(text.to_string_lossy(), "temp=21\\xb0C");
+///
+/// // surrogateescape gives the original bytes back
+/// let text = Encoding::Utf8.decode_with(raw, Errors::SurrogateEscape).unwrap();
+/// assert_eq!(Encoding::Utf8.encode_with(&text, Errors::SurrogateEscape).unwrap(), raw);
+///
+/// // an unpaired surrogate from UTF-16 is kept in the `Wtf8Buf`
+/// let text = Encoding::Utf16le.decode_with(b"a\x00\x00\xd8", Errors::SurrogatePass).unwrap();
+/// assert_eq!(text.to_string_lossy(), "a\u{fffd}");
+/// assert_eq!(Encoding::Utf8.encode_with(&text, Errors::SurrogatePass).unwrap(), b"a\xed\xa0\x80");
+///
+/// assert_eq!("strict".parse(), Ok(Errors::Strict));
+/// assert_eq!(Errors::XmlCharRefReplace.to_string(), "xmlcharrefreplace");
+///
+/// #[cfg(feature = "namereplace")]
+/// assert_eq!(
+///     Encoding::Ascii.encode_with("30\u{b0}C", Errors::NameReplace).unwrap(),
+///     b"30\\N{DEGREE SIGN}C"
+/// );
+/// #[cfg(not(feature = "namereplace"))]
+/// assert!(Encoding::Ascii.encode_with("30\u{b0}C", Errors::NameReplace).is_err());
+/// ```
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Errors {
+    #[default]
+    Strict,
+    Ignore,
+    Replace,
+    BackslashReplace,
+    XmlCharRefReplace,
+    NameReplace,
+    SurrogateEscape,
+    SurrogatePass,
+}
+
+impl Errors {
+    /// The Python name of the handler.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Strict => "strict",
+            Self::Ignore => "ignore",
+            Self::Replace => "replace",
+            Self::BackslashReplace => "backslashreplace",
+            Self::XmlCharRefReplace => "xmlcharrefreplace",
+            Self::NameReplace => "namereplace",
+            Self::SurrogateEscape => "surrogateescape",
+            Self::SurrogatePass => "surrogatepass",
+        }
+    }
+
+    /// Runs `decoder` over `input`, resolving every fault with this handler. A fault the
+    /// handler can't resolve is returned with positions relative to `input`.
+    pub(crate) fn decode(
+        self,
+        decoder: &mut dyn Decoder,
+        input: &[u8],
+        output: &mut Wtf8Buf,
+        last: bool,
+    ) -> Result<usize, Fault> {
+        let mut pos = 0;
+        loop {
+            let fault = match decoder.decode(&input[pos..], output, last) {
+                Ok(len) => return Ok(pos + len),
+                Err(f) => Fault::new(pos + f.start, pos + f.end, f.reason),
+            };
+            pos = self
+                .on_decode(
+                    &input[fault.start..],
+                    fault.end - fault.start,
+                    decoder.form(),
+                    output,
+                )
+                .map(|resume| fault.start + resume)
+                .ok_or(fault)?;
+        }
+    }
+
+    /// Substitutes the `len` undecodable bytes at the head of `input`; returns how many bytes
+    /// were handled.
+    fn on_decode(
+        self,
+        input: &[u8],
+        len: usize,
+        form: Option<Form>,
+        output: &mut Wtf8Buf,
+    ) -> Option<usize> {
+        let bytes = &input[..len];
+        match self {
+            Self::Strict | Self::XmlCharRefReplace | Self::NameReplace => return None,
+            Self::Ignore => {}
+            Self::Replace => output.push(char::REPLACEMENT_CHARACTER),
+            Self::BackslashReplace => bytes
+                .iter()
+                .for_each(|b| output.push_str(&format!("\\x{b:02x}"))),
+            Self::SurrogateEscape => {
+                // like Python, ASCII is never escaped and at most 4 bytes are taken at once
+                let len = bytes.iter().take(4).take_while(|&&b| b >= 0x80).count();
+                bytes[..len]
+                    .iter()
+                    .for_each(|&b| output.push_code_point(0xDC00 + b as u32));
+                return (len > 0).then_some(len);
+            }
+            Self::SurrogatePass => {
+                // the fault may cover only the first byte of the surrogate, as in UTF-8
+                let (c, len) = form?.read_surrogate(input)?;
+                output.push_code_point(c);
+                return Some(len);
+            }
+        }
+        Some(bytes.len())
+    }
+
+    /// Runs `encoder` over `input`, resolving every fault with this handler. A fault the
+    /// handler can't resolve is returned with byte offsets relative to `input`.
+    pub(crate) fn encode(
+        self,
+        encoder: &mut dyn Encoder,
+        input: &Wtf8,
+        output: &mut Vec<u8>,
+        last: bool,
+    ) -> Result<usize, Fault> {
+        let mut pos = 0;
+        loop {
+            let fault = match encoder.encode(input.slice(pos, input.len()), output, last) {
+                Ok(len) => return Ok(pos + len),
+                Err(f) => Fault::new(pos + f.start, pos + f.end, f.reason),
+            };
+            let bad = input.slice(fault.start, fault.end);
+            let replacement: Option<String> = match self {
+                Self::Strict => return Err(fault),
+                Self::Ignore => None,
+                Self::Replace => Some(bad.code_points().map(|_| '?').collect()),
+                Self::BackslashReplace => Some(bad.code_points().map(escape).collect()),
+                Self::XmlCharRefReplace => {
+                    Some(bad.code_points().map(|c| format!("&#{c};")).collect())
+                }
+                #[cfg(feature = "namereplace")]
+                Self::NameReplace => Some(
+                    bad.code_points()
+                        .map(|c| match char::from_u32(c).and_then(unicode_names2::name) {
+                            Some(name) => format!("\\N{{{name}}}"),
+                            None => escape(c),
+                        })
+                        .collect(),
+                ),
+                #[cfg(not(feature = "namereplace"))]
+                Self::NameReplace => return Err(fault),
+                Self::SurrogateEscape => {
+                    let bytes: Vec<u8> = bad
+                        .code_points()
+                        .map_while(|c| match c {
+                            0xDC80..=0xDCFF => Some((c - 0xDC00) as u8),
+                            _ => None,
+                        })
+                        .collect();
+                    // each escapable surrogate takes 3 bytes of WTF-8
+                    let escaped = fault.start + bytes.len() * 3;
+                    let partial = escaped < fault.end;
+                    if !bytes.len().is_multiple_of(encoder.unit())
+                        || (partial && (bytes.is_empty() || !encoder.partial_escape()))
+                    {
+                        return Err(fault);
+                    }
+                    if partial {
+                        return Err(Fault::new(escaped, fault.end, fault.reason));
+                    }
+                    output.extend_from_slice(&bytes);
+                    None
+                }
+                Self::SurrogatePass => {
+                    let Some(form) = encoder.form() else {
+                        return Err(fault);
+                    };
+                    if !bad.code_points().all(|c| (0xD800..=0xDFFF).contains(&c)) {
+                        return Err(fault);
+                    }
+                    bad.code_points()
+                        .for_each(|c| form.write_surrogate(c, output));
+                    None
+                }
+            };
+            if let Some(replacement) = replacement {
+                // the replacement goes through the codec, and must be encodable itself
+                if encoder
+                    .encode(Wtf8::new(&replacement), output, false)
+                    .is_err()
+                {
+                    return Err(fault);
+                }
+            }
+            pos = fault.end;
+        }
+    }
+}
+
+/// Python's backslash escape of a single code point.
+fn escape(c: u32) -> String {
+    match c {
+        0..=0xFF => format!("\\x{c:02x}"),
+        0x100..=0xFFFF => format!("\\u{c:04x}"),
+        _ => format!("\\U{c:08x}"),
+    }
+}
+
+impl Display for Errors {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Errors {
+    type Err = CodecError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "strict" => Self::Strict,
+            "ignore" => Self::Ignore,
+            "replace" => Self::Replace,
+            "backslashreplace" => Self::BackslashReplace,
+            "xmlcharrefreplace" => Self::XmlCharRefReplace,
+            "namereplace" => Self::NameReplace,
+            "surrogateescape" => Self::SurrogateEscape,
+            "surrogatepass" => Self::SurrogatePass,
+            _ => return Err(CodecError::UnknownErrors(s.to_string())),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Encoding;
+
+    type Expected = Result<&'static [u8], (usize, usize)>;
+    type Outcome = Result<Vec<u8>, (usize, usize)>;
+
+    /// The WTF-8 bytes of the text, or the positions of the error.
+    fn decode(encoding: Encoding, input: &[u8], errors: Errors) -> Outcome {
+        match encoding.decode_with(input, errors) {
+            Ok(text) => Ok(text.as_bytes().to_vec()),
+            Err(CodecError::Decode { start, end, .. }) => Err((start, end)),
+            Err(e) => panic!("{e}"),
+        }
+    }
+
+    /// Encodes the text given as WTF-8 bytes; the bytes, or the positions of the error.
+    fn encode(encoding: Encoding, input: &[u8], errors: Errors) -> Outcome {
+        let text = Encoding::Utf8
+            .decode_with(input, Errors::SurrogatePass)
+            .unwrap();
+        match encoding.encode_with(&text, errors) {
+            Ok(bytes) => Ok(bytes),
+            Err(CodecError::Encode { start, end, .. }) => Err((start, end)),
+            Err(e) => panic!("{e}"),
+        }
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        // the results of CPython
+        let cases: [(Encoding, &[u8], Errors, Expected); 30] = [
+            (Encoding::Utf8, &b"a\xffb\xe2\x82c\xed\xa0\x80d"[..], Errors::Strict, Err((1, 2))),
+            (Encoding::Utf8, &b"a\xffb\xe2\x82c\xed\xa0\x80d"[..], Errors::Ignore, Ok(&b"abcd"[..])),
+            (Encoding::Utf8, &b"a\xffb\xe2\x82c\xed\xa0\x80d"[..], Errors::Replace, Ok(&b"a\xef\xbf\xbdb\xef\xbf\xbdc\xef\xbf\xbd\xef\xbf\xbd\xef\xbf\xbdd"[..])),
+            (Encoding::Utf8, &b"a\xffb\xe2\x82c\xed\xa0\x80d"[..], Errors::BackslashReplace, Ok(&b"a\\xffb\\xe2\\x82c\\xed\\xa0\\x80d"[..])),
+            (Encoding::Utf8, &b"a\xffb\xe2\x82c\xed\xa0\x80d"[..], Errors::SurrogateEscape, Ok(&b"a\xed\xb3\xbfb\xed\xb3\xa2\xed\xb2\x82c\xed\xb3\xad\xed\xb2\xa0\xed\xb2\x80d"[..])),
+            (Encoding::Utf8, &b"a\xffb\xe2\x82c\xed\xa0\x80d"[..], Errors::SurrogatePass, Err((1, 2))),
+            (Encoding::Cp1253, &b"a\xaa\xd2b"[..], Errors::Strict, Err((1, 2))),
+            (Encoding::Cp1253, &b"a\xaa\xd2b"[..], Errors::Ignore, Ok(&b"ab"[..])),
+            (Encoding::Cp1253, &b"a\xaa\xd2b"[..], Errors::Replace, Ok(&b"a\xef\xbf\xbd\xef\xbf\xbdb"[..])),
+            (Encoding::Cp1253, &b"a\xaa\xd2b"[..], Errors::BackslashReplace, Ok(&b"a\\xaa\\xd2b"[..])),
+            (Encoding::Cp1253, &b"a\xaa\xd2b"[..], Errors::SurrogateEscape, Ok(&b"a\xed\xb2\xaa\xed\xb3\x92b"[..])),
+            (Encoding::Cp1253, &b"a\xaa\xd2b"[..], Errors::SurrogatePass, Err((1, 2))),
+            (Encoding::ShiftJis, &b"a\x81 b\xa0"[..], Errors::Strict, Err((1, 2))),
+            (Encoding::ShiftJis, &b"a\x81 b\xa0"[..], Errors::Ignore, Ok(&b"a b"[..])),
+            (Encoding::ShiftJis, &b"a\x81 b\xa0"[..], Errors::Replace, Ok(&b"a\xef\xbf\xbd b\xef\xbf\xbd"[..])),
+            (Encoding::ShiftJis, &b"a\x81 b\xa0"[..], Errors::BackslashReplace, Ok(&b"a\\x81 b\\xa0"[..])),
+            (Encoding::ShiftJis, &b"a\x81 b\xa0"[..], Errors::SurrogateEscape, Ok(&b"a\xed\xb2\x81 b\xed\xb2\xa0"[..])),
+            (Encoding::ShiftJis, &b"a\x81 b\xa0"[..], Errors::SurrogatePass, Err((1, 2))),
+            (Encoding::Utf16le, &b"a\x00\x00\xd8b\x00\x00\xdc"[..], Errors::Strict, Err((2, 4))),
+            (Encoding::Utf16le, &b"a\x00\x00\xd8b\x00\x00\xdc"[..], Errors::Ignore, Ok(&b"ab"[..])),
+            (Encoding::Utf16le, &b"a\x00\x00\xd8b\x00\x00\xdc"[..], Errors::Replace, Ok(&b"a\xef\xbf\xbdb\xef\xbf\xbd"[..])),
+            (Encoding::Utf16le, &b"a\x00\x00\xd8b\x00\x00\xdc"[..], Errors::BackslashReplace, Ok(&b"a\\x00\\xd8b\\x00\\xdc"[..])),
+            (Encoding::Utf16le, &b"a\x00\x00\xd8b\x00\x00\xdc"[..], Errors::SurrogateEscape, Err((2, 4))),
+            (Encoding::Utf16le, &b"a\x00\x00\xd8b\x00\x00\xdc"[..], Errors::SurrogatePass, Ok(&b"a\xed\xa0\x80b\xed\xb0\x80"[..])),
+            (Encoding::Iso2022Jp, &b"a\x1b$BF|\x80\x1b(Bb"[..], Errors::Strict, Err((6, 7))),
+            (Encoding::Iso2022Jp, &b"a\x1b$BF|\x80\x1b(Bb"[..], Errors::Ignore, Ok(&b"a\xe6\x97\xa5b"[..])),
+            (Encoding::Iso2022Jp, &b"a\x1b$BF|\x80\x1b(Bb"[..], Errors::Replace, Ok(&b"a\xe6\x97\xa5\xef\xbf\xbdb"[..])),
+            (Encoding::Iso2022Jp, &b"a\x1b$BF|\x80\x1b(Bb"[..], Errors::BackslashReplace, Ok(&b"a\xe6\x97\xa5\\x80b"[..])),
+            (Encoding::Iso2022Jp, &b"a\x1b$BF|\x80\x1b(Bb"[..], Errors::SurrogateEscape, Ok(&b"a\xe6\x97\xa5\xed\xb2\x80b"[..])),
+            (Encoding::Iso2022Jp, &b"a\x1b$BF|\x80\x1b(Bb"[..], Errors::SurrogatePass, Err((6, 7))),
+        ];
+        for (encoding, input, errors, expected) in cases {
+            let expected = expected.map(<[u8]>::to_vec);
+            assert_eq!(
+                decode(encoding, input, errors),
+                expected,
+                "{encoding} {errors}"
+            );
+        }
+        // the handlers for encoding fail like `strict`
+        let errors = Errors::XmlCharRefReplace;
+        assert_eq!(decode(Encoding::Cp1253, b"a\xaa", errors), Err((1, 2)));
+        #[cfg(feature = "namereplace")]
+        assert_eq!(
+            decode(Encoding::Cp1253, b"a\xaa", Errors::NameReplace),
+            Err((1, 2))
+        );
+    }
+
+    #[test]
+    fn test_encode_errors() {
+        // the results of CPython, with the text as UTF-8 that may hold lone surrogates
+        let cases: [(Encoding, &[u8], Errors, Expected); 35] = [
+            (
+                Encoding::Ascii,
+                &b"a\xc3\xa9\xe2\x82\xacb"[..],
+                Errors::Strict,
+                Err((1, 3)),
+            ),
+            (
+                Encoding::Ascii,
+                &b"a\xc3\xa9\xe2\x82\xacb"[..],
+                Errors::Ignore,
+                Ok(&b"ab"[..]),
+            ),
+            (
+                Encoding::Ascii,
+                &b"a\xc3\xa9\xe2\x82\xacb"[..],
+                Errors::Replace,
+                Ok(&b"a??b"[..]),
+            ),
+            (
+                Encoding::Ascii,
+                &b"a\xc3\xa9\xe2\x82\xacb"[..],
+                Errors::BackslashReplace,
+                Ok(&b"a\\xe9\\u20acb"[..]),
+            ),
+            (
+                Encoding::Ascii,
+                &b"a\xc3\xa9\xe2\x82\xacb"[..],
+                Errors::XmlCharRefReplace,
+                Ok(&b"a&#233;&#8364;b"[..]),
+            ),
+            (
+                Encoding::Ascii,
+                &b"a\xc3\xa9\xe2\x82\xacb"[..],
+                Errors::SurrogateEscape,
+                Err((1, 3)),
+            ),
+            (
+                Encoding::Ascii,
+                &b"a\xc3\xa9\xe2\x82\xacb"[..],
+                Errors::SurrogatePass,
+                Err((1, 3)),
+            ),
+            (
+                Encoding::Cp1252,
+                &b"a\xc4\x80\xc4\x81b\xe2\x82\xac"[..],
+                Errors::Strict,
+                Err((1, 3)),
+            ),
+            (
+                Encoding::Cp1252,
+                &b"a\xc4\x80\xc4\x81b\xe2\x82\xac"[..],
+                Errors::Ignore,
+                Ok(&b"ab\x80"[..]),
+            ),
+            (
+                Encoding::Cp1252,
+                &b"a\xc4\x80\xc4\x81b\xe2\x82\xac"[..],
+                Errors::Replace,
+                Ok(&b"a??b\x80"[..]),
+            ),
+            (
+                Encoding::Cp1252,
+                &b"a\xc4\x80\xc4\x81b\xe2\x82\xac"[..],
+                Errors::BackslashReplace,
+                Ok(&b"a\\u0100\\u0101b\x80"[..]),
+            ),
+            (
+                Encoding::Cp1252,
+                &b"a\xc4\x80\xc4\x81b\xe2\x82\xac"[..],
+                Errors::XmlCharRefReplace,
+                Ok(&b"a&#256;&#257;b\x80"[..]),
+            ),
+            (
+                Encoding::Cp1252,
+                &b"a\xc4\x80\xc4\x81b\xe2\x82\xac"[..],
+                Errors::SurrogateEscape,
+                Err((1, 3)),
+            ),
+            (
+                Encoding::Cp1252,
+                &b"a\xc4\x80\xc4\x81b\xe2\x82\xac"[..],
+                Errors::SurrogatePass,
+                Err((1, 3)),
+            ),
+            (
+                Encoding::Utf8,
+                &b"a\xed\xb2\x80\xed\xb3\xbfb\xed\xa0\x80"[..],
+                Errors::Strict,
+                Err((1, 3)),
+            ),
+            (
+                Encoding::Utf8,
+                &b"a\xed\xb2\x80\xed\xb3\xbfb\xed\xa0\x80"[..],
+                Errors::Ignore,
+                Ok(&b"ab"[..]),
+            ),
+            (
+                Encoding::Utf8,
+                &b"a\xed\xb2\x80\xed\xb3\xbfb\xed\xa0\x80"[..],
+                Errors::Replace,
+                Ok(&b"a??b?"[..]),
+            ),
+            (
+                Encoding::Utf8,
+                &b"a\xed\xb2\x80\xed\xb3\xbfb\xed\xa0\x80"[..],
+                Errors::BackslashReplace,
+                Ok(&b"a\\udc80\\udcffb\\ud800"[..]),
+            ),
+            (
+                Encoding::Utf8,
+                &b"a\xed\xb2\x80\xed\xb3\xbfb\xed\xa0\x80"[..],
+                Errors::XmlCharRefReplace,
+                Ok(&b"a&#56448;&#56575;b&#55296;"[..]),
+            ),
+            (
+                Encoding::Utf8,
+                &b"a\xed\xb2\x80\xed\xb3\xbfb\xed\xa0\x80"[..],
+                Errors::SurrogateEscape,
+                Err((4, 5)),
+            ),
+            (
+                Encoding::Utf8,
+                &b"a\xed\xb2\x80\xed\xb3\xbfb\xed\xa0\x80"[..],
+                Errors::SurrogatePass,
+                Ok(&b"a\xed\xb2\x80\xed\xb3\xbfb\xed\xa0\x80"[..]),
+            ),
+            (
+                Encoding::ShiftJis,
+                &b"\xe6\x97\xa5\xc3\xa9\xe6\x9c\xac"[..],
+                Errors::Strict,
+                Err((1, 2)),
+            ),
+            (
+                Encoding::ShiftJis,
+                &b"\xe6\x97\xa5\xc3\xa9\xe6\x9c\xac"[..],
+                Errors::Ignore,
+                Ok(&b"\x93\xfa\x96{"[..]),
+            ),
+            (
+                Encoding::ShiftJis,
+                &b"\xe6\x97\xa5\xc3\xa9\xe6\x9c\xac"[..],
+                Errors::Replace,
+                Ok(&b"\x93\xfa?\x96{"[..]),
+            ),
+            (
+                Encoding::ShiftJis,
+                &b"\xe6\x97\xa5\xc3\xa9\xe6\x9c\xac"[..],
+                Errors::BackslashReplace,
+                Ok(&b"\x93\xfa\\xe9\x96{"[..]),
+            ),
+            (
+                Encoding::ShiftJis,
+                &b"\xe6\x97\xa5\xc3\xa9\xe6\x9c\xac"[..],
+                Errors::XmlCharRefReplace,
+                Ok(&b"\x93\xfa&#233;\x96{"[..]),
+            ),
+            (
+                Encoding::ShiftJis,
+                &b"\xe6\x97\xa5\xc3\xa9\xe6\x9c\xac"[..],
+                Errors::SurrogateEscape,
+                Err((1, 2)),
+            ),
+            (
+                Encoding::ShiftJis,
+                &b"\xe6\x97\xa5\xc3\xa9\xe6\x9c\xac"[..],
+                Errors::SurrogatePass,
+                Err((1, 2)),
+            ),
+            (
+                Encoding::Utf16le,
+                &b"a\xed\xa0\x80b"[..],
+                Errors::Strict,
+                Err((1, 2)),
+            ),
+            (
+                Encoding::Utf16le,
+                &b"a\xed\xa0\x80b"[..],
+                Errors::Ignore,
+                Ok(&b"a\x00b\x00"[..]),
+            ),
+            (
+                Encoding::Utf16le,
+                &b"a\xed\xa0\x80b"[..],
+                Errors::Replace,
+                Ok(&b"a\x00?\x00b\x00"[..]),
+            ),
+            (
+                Encoding::Utf16le,
+                &b"a\xed\xa0\x80b"[..],
+                Errors::BackslashReplace,
+                Ok(&b"a\x00\\\x00u\x00d\x008\x000\x000\x00b\x00"[..]),
+            ),
+            (
+                Encoding::Utf16le,
+                &b"a\xed\xa0\x80b"[..],
+                Errors::XmlCharRefReplace,
+                Ok(&b"a\x00&\x00#\x005\x005\x002\x009\x006\x00;\x00b\x00"[..]),
+            ),
+            (
+                Encoding::Utf16le,
+                &b"a\xed\xa0\x80b"[..],
+                Errors::SurrogateEscape,
+                Err((1, 2)),
+            ),
+            (
+                Encoding::Utf16le,
+                &b"a\xed\xa0\x80b"[..],
+                Errors::SurrogatePass,
+                Ok(&b"a\x00\x00\xd8b\x00"[..]),
+            ),
+        ];
+        for (encoding, input, errors, expected) in cases {
+            let expected = expected.map(<[u8]>::to_vec);
+            assert_eq!(
+                encode(encoding, input, errors),
+                expected,
+                "{encoding} {errors}"
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "namereplace")]
+    fn test_name_replace() {
+        let cases: [(Encoding, &[u8], Errors, Expected); 3] = [
+            (
+                Encoding::Ascii,
+                &b"a\xc3\xa9\xe2\x82\xacb"[..],
+                Errors::NameReplace,
+                Ok(&b"a\\N{LATIN SMALL LETTER E WITH ACUTE}\\N{EURO SIGN}b"[..]),
+            ),
+            (
+                Encoding::Utf8,
+                &b"a\xed\xb2\x80b"[..],
+                Errors::NameReplace,
+                Ok(&b"a\\udc80b"[..]),
+            ),
+            (
+                Encoding::Latin1,
+                &b"x\xf0\x9f\x98\x80y"[..],
+                Errors::NameReplace,
+                Ok(&b"x\\N{GRINNING FACE}y"[..]),
+            ),
+        ];
+        for (encoding, input, errors, expected) in cases {
+            let expected = expected.map(<[u8]>::to_vec);
+            assert_eq!(encode(encoding, input, errors), expected, "{encoding}");
+        }
+    }
+}
@@ -0,0 +1,161 @@
+//! The `idna` codec, which converts a domain name label by label like Python's, mapping the
+//! labels with the UTS-46 data of the `idna` crate.
+
+use super::codec::{Decoder, Encoder, Fault};
+use super::{punycode, Wtf8, Wtf8Buf};
+use ::idna::uts46::{AsciiDenyList, DnsLength, Hyphens, Uts46};
+use std::borrow::Cow;
+
+const ACE_PREFIX: &[u8] = b"xn--";
+
+/// Offset and length of the first label separator in the WTF-8 `bytes`: the full stop, or
+/// the ideographic, fullwidth or halfwidth one.
+fn find_dot(bytes: &[u8]) -> Option<(usize, usize)> {
+    bytes
+        .iter()
+        .enumerate()
+        .find_map(|(i, &b)| match (b, bytes.get(i + 1..i + 3)) {
+            (b'.', _) => Some((i, 1)),
+            (0xE3, Some([0x80, 0x82])) | (0xEF, Some([0xBC, 0x8E] | [0xBD, 0xA1])) => Some((i, 3)),
+            _ => None,
+        })
+}
+
+/// The deviations of IDNA 2003 from UTS-46 nontransitional processing.
+fn transitional(label: &str) -> Cow<'_, str> {
+    if !label.contains(['\u{df}', '\u{1e9e}', '\u{3c2}', '\u{200c}', '\u{200d}']) {
+        return Cow::Borrowed(label);
+    }
+    let mut mapped = String::with_capacity(label.len() + 1);
+    for ch in label.chars() {
+        match ch {
+            '\u{df}' | '\u{1e9e}' => mapped.push_str("ss"),
+            '\u{3c2}' => mapped.push('\u{3c3}'),
+            '\u{200c}' | '\u{200d}' => {}
+            ch => mapped.push(ch),
+        }
+    }
+    Cow::Owned(mapped)
+}
+
+/// Python's `ToASCII`, with the UTS-46 mapping in place of nameprep. Like in Python, an ASCII
+/// label is only checked for its length.
+fn to_ascii(label: &str, output: &mut Vec<u8>) -> Result<(), &'static str> {
+    let ascii = match label.is_ascii() {
+        true => Cow::Borrowed(label),
+        false => Uts46::new()
+            .to_ascii(
+                transitional(label).as_bytes(),
+                AsciiDenyList::EMPTY,
+                Hyphens::Allow,
+                DnsLength::Ignore,
+            )
+            .map(|ascii| Cow::Owned(ascii.into_owned()))
+            .map_err(|_| "invalid label")?,
+    };
+    if ascii.contains('.') {
+        return Err("invalid label");
+    }
+    if ascii.is_empty() || ascii.len() > 63 {
+        return Err("label empty or too long");
+    }
+    output.extend_from_slice(ascii.as_bytes());
+    Ok(())
+}
+
+/// Python's `ToUnicode` of an ASCII label, which must survive the round trip through
+/// [`to_ascii`] if it has the ACE prefix. Like in Python, only the lowercase prefix counts,
+/// so a label like `XN--MNCHEN-3YA` stays as it is.
+fn to_unicode(label: &[u8], output: &mut Wtf8Buf) -> Result<(), &'static str> {
+    let text = std::str::from_utf8(label).map_err(|_| "ordinal not in range(128)")?;
+    if !label.starts_with(ACE_PREFIX) {
+        output.push_str(text);
+        return Ok(());
+    }
+    let unicode = punycode::decode(&label[ACE_PREFIX.len()..])?
+        .into_iter()
+        .map(char::from_u32)
+        .collect::<Option<String>>()
+        .ok_or("invalid character")?;
+    let mut ascii = Vec::with_capacity(label.len());
+    to_ascii(&unicode, &mut ascii)?;
+    if !ascii.eq_ignore_ascii_case(label) {
+        return Err("IDNA does not round-trip");
+    }
+    output.push_str(&unicode);
+    Ok(())
+}
+
+/// The `idna` codec. A label is converted once the dot after it arrives, or on the last
+/// call; a fault covers the whole label.
+pub(crate) struct Idna;
+
+impl Decoder for Idna {
+    fn decode(&mut self, input: &[u8], output: &mut Wtf8Buf, last: bool) -> Result<usize, Fault> {
+        let mut pos = 0;
+        loop {
+            let end = match input[pos..].iter().position(|&b| b == b'.') {
+                Some(i) => pos + i,
+                None if last => input.len(),
+                None => return Ok(pos),
+            };
+            let label = &input[pos..end];
+            if let Some(i) = label.iter().position(|b| !b.is_ascii()) {
+                let bad = pos + i;
+                return Err(Fault::new(bad, bad + 1, "ordinal not in range(128)"));
+            }
+            to_unicode(label, output).map_err(|reason| Fault::new(pos, end, reason))?;
+            if end == input.len() {
+                return Ok(end);
+            }
+            output.push('.');
+            pos = end + 1;
+        }
+    }
+}
+
+impl Encoder for Idna {
+    fn encode(&mut self, input: &Wtf8, output: &mut Vec<u8>, last: bool) -> Result<usize, Fault> {
+        let bytes = input.as_bytes();
+        let mut pos = 0;
+        loop {
+            let (end, dot) = match find_dot(&bytes[pos..]) {
+                Some((i, len)) => (pos + i, len),
+                None if last => (bytes.len(), 0),
+                None => return Ok(pos),
+            };
+            // the empty label after a trailing dot ends the name
+            if end == pos && dot == 0 {
+                return Ok(pos);
+            }
+            let label = std::str::from_utf8(&bytes[pos..end]).map_err(|_| "invalid label");
+            label
+                .and_then(|label| to_ascii(label, output))
+                .map_err(|reason| Fault::new(pos, end.max(pos + dot), reason))?;
+            if dot == 0 {
+                return Ok(end);
+            }
+            output.push(b'.');
+            pos = end + dot;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::Encoding;
+
+    #[test]
+    fn test_decode_case() {
+        // the results of Python's `bytes.decode("idna")`
+        let cases = [
+            (&b"xn--mnchen-3ya.de"[..], "m\u{fc}nchen.de"),
+            (b"XN--MNCHEN-3YA.DE", "XN--MNCHEN-3YA.DE"),
+            (b"Xn--mnchen-3ya.de", "Xn--mnchen-3ya.de"),
+            (b"xn--MNCHEN-3YA.de", "M\u{fc}NCHEN.de"),
+        ];
+        for (input, text) in cases {
+            assert_eq!(Encoding::Idna.decode(input), Ok(text.to_string()));
+        }
+    }
+}
@@ -0,0 +1,232 @@
+use super::codec::{Decoder, Encoder};
+use super::{CodecError, Encoding, Errors, Wtf8, Wtf8Buf};
+use std::fmt::{Debug, Formatter};
+
+/// A stateful decoder fed with chunks of a stream, like the objects returned by Python's
+/// `codecs.getincrementaldecoder`.
+///
+/// A sequence cut by a chunk boundary is kept until the next call completes it. Pass
+/// `last = true` with the final chunk, so that an incomplete sequence at the very end is
+/// reported instead of kept. Error positions are relative to the buffered tail followed by the
+/// chunk; after an error the buffered tail is dropped.
+///
+/// # Example
+///
+/// ```rust
+/// use rsutil::types::{Encoding, Errors, IncrementalDecoder};
+///
+/// let mut decoder = IncrementalDecoder::new(Encoding::Utf8, Errors::Strict).unwrap();
+/// // "€" is e2 82 ac, split over three reads
+/// assert_eq!(decoder.decode(b"1 \xe2", false), Ok("1 ".to_string()));
+/// assert_eq!(decoder.decode(b"\x82", false), Ok("".to_string()));
+/// assert_eq!(decoder.decode(b"\xac!", false), Ok("\u{20ac}!".to_string()));
+///
+/// // a truncated sequence is an error only once the stream ends
+/// assert_eq!(decoder.decode(b"\xe2\x82", false), Ok("".to_string()));
+/// assert!(decoder.decode(b"", true).is_err());
+///
+/// // the shift state of a stateful encoding carries over too
+/// let mut decoder = IncrementalDecoder::new(Encoding::Iso2022Jp, Errors::Strict).unwrap();
+/// assert_eq!(decoder.decode(b"\x1b$B", false), Ok("".to_string()));
+/// assert_eq!(decoder.decode(b"F|", true), Ok("\u{65e5}".to_string()));
+/// ```
+pub struct IncrementalDecoder {
+    encoding: Encoding,
+    errors: Errors,
+    decoder: Box<dyn Decoder>,
+    pending: Vec<u8>,
+}
+
+impl IncrementalDecoder {
+    pub fn new(encoding: Encoding, errors: Errors) -> Result<Self, CodecError> {
+        encoding.check_errors(errors)?;
+        Ok(Self {
+            encoding,
+            errors,
+            decoder: encoding.decoder()?,
+            pending: Vec::new(),
+        })
+    }
+
+    #[inline]
+    pub fn encoding(&self) -> Encoding {
+        self.encoding
+    }
+
+    #[inline]
+    pub fn errors(&self) -> Errors {
+        self.errors
+    }
+
+    /// Number of bytes held back from the previous chunks.
+    #[inline]
+    pub fn pending(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Decodes the next chunk; lone surrogates, like those from [`Errors::SurrogateEscape`],
+    /// come out as `U+FFFD`, see [`IncrementalDecoder::decode_into`] to keep them.
+    pub fn decode(&mut self, input: &[u8], last: bool) -> Result<String, CodecError> {
+        let mut output = Wtf8Buf::with_capacity(input.len());
+        self.decode_into(input, &mut output, last)?;
+        Ok(output.into_string_lossy())
+    }
+
+    /// Decodes the next chunk, appending the text to `output`.
+    pub fn decode_into(
+        &mut self,
+        input: &[u8],
+        output: &mut Wtf8Buf,
+        last: bool,
+    ) -> Result<(), CodecError> {
+        let mut buffered = std::mem::take(&mut self.pending);
+        let data = match buffered.is_empty() {
+            true => input,
+            false => {
+                buffered.extend_from_slice(input);
+                buffered.as_slice()
+            }
+        };
+        match self
+            .errors
+            .decode(self.decoder.as_mut(), data, output, last)
+        {
+            Ok(len) => {
+                self.pending.extend_from_slice(&data[len..]);
+                Ok(())
+            }
+            Err(f) => Err(CodecError::decode(
+                self.encoding,
+                data,
+                f.start,
+                f.end,
+                f.reason,
+            )),
+        }
+    }
+
+    /// Drops the buffered bytes and any state, like a freshly created decoder.
+    pub fn reset(&mut self) -> Result<(), CodecError> {
+        self.decoder = self.encoding.decoder()?;
+        self.pending.clear();
+        Ok(())
+    }
+}
+
+impl Debug for IncrementalDecoder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IncrementalDecoder")
+            .field("encoding", &self.encoding)
+            .field("errors", &self.errors)
+            .field("pending", &self.pending)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A stateful encoder fed with pieces of a text, like the objects returned by Python's
+/// `codecs.getincrementalencoder`.
+///
+/// Encodings with a preamble or shift states, like `utf-16` with its BOM, write it only once.
+/// Pass `last = true` with the final piece so that such an encoding can return to its initial
+/// state. Error positions are character positions in the piece.
+///
+/// # Example
+///
+/// ```rust
+/// use rsutil::types::{Encoding, Errors, IncrementalEncoder};
+///
+/// let mut encoder = IncrementalEncoder::new(Encoding::Utf16, Errors::Strict).unwrap();
+/// let mut bytes = encoder.encode("ab", false).unwrap();
+/// bytes.extend(encoder.encode("c", true).unwrap());
+/// assert_eq!(bytes, Encoding::Utf16.encode("abc").unwrap());
+/// ```
+pub struct IncrementalEncoder {
+    encoding: Encoding,
+    errors: Errors,
+    encoder: Box<dyn Encoder>,
+    pending: Wtf8Buf,
+}
+
+impl IncrementalEncoder {
+    pub fn new(encoding: Encoding, errors: Errors) -> Result<Self, CodecError> {
+        encoding.check_errors(errors)?;
+        Ok(Self {
+            encoding,
+            errors,
+            encoder: encoding.encoder()?,
+            pending: Wtf8Buf::new(),
+        })
+    }
+
+    #[inline]
+    pub fn encoding(&self) -> Encoding {
+        self.encoding
+    }
+
+    #[inline]
+    pub fn errors(&self) -> Errors {
+        self.errors
+    }
+
+    /// Encodes the next piece of text.
+    pub fn encode<S: AsRef<Wtf8> + ?Sized>(
+        &mut self,
+        input: &S,
+        last: bool,
+    ) -> Result<Vec<u8>, CodecError> {
+        let mut output = Vec::with_capacity(input.as_ref().len());
+        self.encode_into(input, &mut output, last)?;
+        Ok(output)
+    }
+
+    /// Encodes the next piece of text, appending the bytes to `output`.
+    pub fn encode_into<S: AsRef<Wtf8> + ?Sized>(
+        &mut self,
+        input: &S,
+        output: &mut Vec<u8>,
+        last: bool,
+    ) -> Result<(), CodecError> {
+        let input = input.as_ref();
+        let mut buffered = std::mem::take(&mut self.pending);
+        let data = match buffered.is_empty() {
+            true => input,
+            false => {
+                buffered.push_wtf8(input);
+                &*buffered
+            }
+        };
+        match self
+            .errors
+            .encode(self.encoder.as_mut(), data, output, last)
+        {
+            Ok(len) => {
+                self.pending.push_wtf8(data.slice(len, data.len()));
+                Ok(())
+            }
+            Err(f) => Err(CodecError::encode(
+                self.encoding,
+                data,
+                f.start,
+                f.end,
+                f.reason,
+            )),
+        }
+    }
+
+    /// Drops any buffered text and state, like a freshly created encoder.
+    pub fn reset(&mut self) -> Result<(), CodecError> {
+        self.encoder = self.encoding.encoder()?;
+        self.pending = Wtf8Buf::new();
+        Ok(())
+    }
+}
+
+impl Debug for IncrementalEncoder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IncrementalEncoder")
+            .field("encoding", &self.encoding)
+            .field("errors", &self.errors)
+            .field("pending", &self.pending)
+            .finish_non_exhaustive()
+    }
+}
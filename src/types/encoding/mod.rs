@@ -0,0 +1,436 @@
+mod charmap;
+mod cjk;
+mod codec;
+pub use codec::Fault;
+#[cfg(feature = "compress")]
+mod compress;
+#[cfg(feature = "compress")]
+pub use compress::*;
+mod detect;
+pub use detect::*;
+mod error;
+pub use error::*;
+mod errors;
+pub use errors::*;
+#[cfg(feature = "idna")]
+mod idna;
+mod incremental;
+pub use incremental::*;
+mod names;
+mod punycode;
+mod registry;
+pub use registry::*;
+mod stream;
+pub use stream::*;
+mod transcode;
+pub use transcode::*;
+mod transform;
+mod utf;
+mod wtf8;
+pub use wtf8::*;
+
+use crate::types::ByteOrder;
+use codec::{Coder, Decoder, Encoder};
+use serde::{Deserialize, Serialize};
+
+/// encodings from python
+///
+/// Text encodings convert between `str` and bytes with [`Encoding::encode`] and
+/// [`Encoding::decode`], following the behavior of the Python codec of the same name. The
+/// bytes-to-bytes codecs like `Base64` only work with [`Encoding::transform_encode`] and
+/// [`Encoding::transform_decode`], and the other way around. `Zlib` and `Bz2` need the
+/// `compress` feature, which also brings the streaming `Compressor` and `Decompressor`, and
+/// `Idna` needs the `idna` feature for its UTS-46 mapping tables.
+///
+/// # Example
+///
+/// ```rust
+/// use rsutil::types::{CodecError, Encoding};
+///
+/// assert_eq!(Encoding::Utf8.encode("h\u{e9}"), Ok(b"h\xc3\xa9".to_vec()));
+/// assert_eq!(Encoding::Utf16be.encode("a"), Ok(b"\x00a".to_vec()));
+/// // like Python, `utf-16` writes a BOM in native order and `utf-16-le` does not
+/// #[cfg(target_endian = "little")]
+/// assert_eq!(Encoding::Utf16.encode("a"), Ok(b"\xff\xfea\x00".to_vec()));
+/// assert_eq!(Encoding::Utf16le.encode("a"), Ok(b"a\x00".to_vec()));
+///
+/// // a BOM picks the byte order of `utf-16`/`utf-32` and is dropped
+/// assert_eq!(Encoding::Utf16.decode(b"\xfe\xff\x00a"), Ok("a".to_string()));
+/// assert_eq!(Encoding::Utf32.decode(b"\xff\xfe\x00\x00a\x00\x00\x00"), Ok("a".to_string()));
+/// assert_eq!(Encoding::Utf16le.decode(b"\xff\xfea\x00"), Ok("\u{feff}a".to_string()));
+/// // UTF-7 writes the optional direct characters as they are, like Python
+/// assert_eq!(Encoding::Utf7.encode("1+1!\u{20ac}"), Ok(b"1+-1!+IKw-".to_vec()));
+/// assert_eq!(Encoding::Utf7.decode(b"Hi Mom -+Jjo--!"), Ok("Hi Mom -\u{263a}-!".to_string()));
+/// // IMAP mailbox names use the modified UTF-7 of RFC 3501, which shifts with `&`
+/// assert_eq!(Encoding::Imap4Utf7.decode(b"&AOk-t&-"), Ok("\u{e9}t&".to_string()));
+/// assert_eq!(Encoding::Imap4Utf7.encode("\u{53f0}\u{5317}"), Ok(b"&U,BTFw-".to_vec()));
+///
+/// assert_eq!(Encoding::Cp1252.decode(b"5\x80"), Ok("5\u{20ac}".to_string()));
+/// assert_eq!(Encoding::Latin1.encode("caf\u{e9}"), Ok(b"caf\xe9".to_vec()));
+/// // EBCDIC: `cp1140` is `cp037` with the euro sign in place of the currency sign
+/// assert_eq!(Encoding::Cp037.decode(b"\xc1\x9f"), Ok("A\u{a4}".to_string()));
+/// assert_eq!(Encoding::Cp1140.decode(b"\xc1\x9f"), Ok("A\u{20ac}".to_string()));
+/// // Japanese: `cp932` adds the NEC and IBM extensions of Windows to `shift_jis`
+/// assert_eq!(Encoding::ShiftJis.encode("\u{65e5}\u{672c}"), Ok(b"\x93\xfa\x96\x7b".to_vec()));
+/// assert_eq!(Encoding::Cp932.decode(b"\x87\x40"), Ok("\u{2460}".to_string()));
+/// assert!(Encoding::ShiftJis.decode(b"\x87\x40").is_err());
+/// // JIS X 0213 has codes for a kana followed by a combining mark
+/// assert_eq!(Encoding::EucJis2004.encode("\u{304b}\u{309a}"), Ok(b"\xa4\xf7".to_vec()));
+/// // Chinese: GB 18030 covers all of Unicode with four-byte codes
+/// assert_eq!(Encoding::Gb18030.encode("\u{1f600}"), Ok(b"\x94\x39\xfc\x36".to_vec()));
+/// assert_eq!(Encoding::Gb18030.decode(b"\xd6\xd0\xa2\xe3"), Ok("\u{4e2d}\u{20ac}".to_string()));
+/// assert!(Encoding::Gbk.encode("\u{1f600}").is_err());
+/// assert_eq!(Encoding::Hz.encode("\u{4e2d}a"), Ok(b"~{VP~}a".to_vec()));
+/// assert_eq!(Encoding::Big5.encode("\u{4e2d}"), Ok(b"\xa4\xa4".to_vec()));
+/// assert_eq!(Encoding::Big5HkScs.encode("\u{ca}\u{304}"), Ok(b"\x88\x62".to_vec()));
+/// // Korean: EUC-KR spells the syllables missing from KS X 1001 with eight bytes
+/// assert_eq!(Encoding::EucKr.encode("\u{d55c}"), Ok(b"\xc7\xd1".to_vec()));
+/// assert_eq!(Encoding::EucKr.encode("\u{b620}"), Ok(b"\xa4\xd4\xa4\xa8\xa4\xc7\xa4\xb1".to_vec()));
+/// assert_eq!(Encoding::Cp949.encode("\u{b620}"), Ok(b"\x8c\x63".to_vec()));
+/// // ISO-2022: escape sequences switch between ASCII and the two-byte sets
+/// assert_eq!(Encoding::Iso2022Jp.encode("a\u{65e5}"), Ok(b"a\x1b$BF|\x1b(B".to_vec()));
+/// assert_eq!(Encoding::Iso2022Kr.decode(b"\x1b$)C\x0eGQ\x0f"), Ok("\u{d55c}".to_string()));
+/// // `punycode` is RFC 3492, `idna` converts a domain name label by label like Python
+/// assert_eq!(Encoding::Punycode.encode("m\u{fc}nchen"), Ok(b"mnchen-3ya".to_vec()));
+/// #[cfg(feature = "idna")]
+/// {
+///     assert_eq!(Encoding::Idna.encode("M\u{fc}nchen.de"), Ok(b"xn--mnchen-3ya.de".to_vec()));
+///     assert_eq!(Encoding::Idna.encode("stra\u{df}e.de"), Ok(b"strasse.de".to_vec()));
+///     assert_eq!(Encoding::Idna.decode(b"xn--mnchen-3ya.de"), Ok("m\u{fc}nchen.de".to_string()));
+///     assert!(Encoding::Idna.encode("a..b").is_err());
+///     // like in Python, it takes no other error handler than `strict`
+///     assert!(Encoding::Idna.encode_with("m\u{fc}nchen", rsutil::types::Errors::Replace).is_err());
+/// }
+///
+/// let err = Encoding::Utf8.decode(b"ok\xff").unwrap_err();
+/// assert!(matches!(err, CodecError::Decode { start: 2, end: 3, reason: "invalid start byte", .. }));
+/// ```
+///
+/// Encodings parse from any of their Python names and aliases, ignoring case and punctuation
+/// the way Python does, and display as the canonical Python name; serde uses the same names.
+/// As in Python, the aliases of ISO-8859-1 all resolve to `Latin1`, which shares its table
+/// with `Iso8859_1`. Codecs the application registers with [`Encoding::register`] parse too,
+/// as [`Encoding::Custom`].
+///
+/// ```rust
+/// use rsutil::types::Encoding;
+///
+/// assert_eq!("U8".parse(), Ok(Encoding::Utf8));
+/// assert_eq!("cp65001".parse(), Ok(Encoding::Utf8));
+/// assert_eq!("ISO-8859-1".parse(), Ok(Encoding::Latin1));
+/// assert_eq!("sjis".parse(), Ok(Encoding::ShiftJis));
+/// assert_eq!("EUC-JP".parse(), Ok(Encoding::EucJp));
+/// assert!("utf-9".parse::<Encoding>().is_err());
+///
+/// assert_eq!(Encoding::Utf16le.to_string(), "utf-16-le");
+/// assert_eq!(Encoding::ShiftJis.name(), "shift_jis");
+/// ```
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "&'static str")]
+pub enum Encoding {
+    Ascii,
+    Base64,
+    Big5,
+    Big5HkScs,
+    Bz2,
+    Cp037,
+    Cp1026,
+    Cp1125,
+    Cp1140,
+    Cp1250,
+    Cp1251,
+    Cp1252,
+    Cp1253,
+    Cp1254,
+    Cp1255,
+    Cp1256,
+    Cp1257,
+    Cp1258,
+    Cp273,
+    Cp424,
+    Cp437,
+    Cp500,
+    Cp775,
+    Cp850,
+    Cp852,
+    Cp855,
+    Cp857,
+    Cp858,
+    Cp860,
+    Cp861,
+    Cp862,
+    Cp863,
+    Cp864,
+    Cp865,
+    Cp866,
+    Cp869,
+    Cp932,
+    Cp949,
+    Cp950,
+    /// A codec registered with [`Encoding::register`], by its normalized name.
+    Custom(&'static str),
+    EucJis2004,
+    EucJisx0213,
+    EucJp,
+    EucKr,
+    Gb18030,
+    Gb2312,
+    Gbk,
+    Hex,
+    HpRoman8,
+    Hz,
+    Idna,
+    /// The modified UTF-7 of IMAP mailbox names.
+    Imap4Utf7,
+    Iso2022Jp,
+    Iso2022Jp1,
+    Iso2022Jp2,
+    Iso2022Jp2004,
+    Iso2022Jp3,
+    Iso2022JpExt,
+    Iso2022Kr,
+    Iso8859_10,
+    Iso8859_11,
+    Iso8859_13,
+    Iso8859_14,
+    Iso8859_15,
+    Iso8859_16,
+    Iso8859_1,
+    Iso8859_2,
+    Iso8859_3,
+    Iso8859_4,
+    Iso8859_5,
+    Iso8859_6,
+    Iso8859_7,
+    Iso8859_8,
+    Iso8859_9,
+    Johab,
+    Koi8R,
+    Kz1048,
+    Latin1,
+    MacCyrillic,
+    MacGreek,
+    MacIceland,
+    MacLatin2,
+    MacRoman,
+    MacTurkish,
+    Mbcs,
+    Ptcp154,
+    Punycode,
+    Quopri,
+    Rot13,
+    ShiftJis,
+    ShiftJis2004,
+    ShiftJisx0213,
+    Tis620,
+    Utf16,
+    Utf16be,
+    Utf16le,
+    Utf32,
+    Utf32be,
+    Utf32le,
+    Utf7,
+    #[default]
+    Utf8,
+    UU,
+    Zlib,
+}
+
+impl Encoding {
+    /// Whether this is a text encoding, as opposed to the bytes-to-bytes codecs like `Base64`.
+    pub fn is_text(&self) -> bool {
+        !matches!(
+            self,
+            Self::Base64
+                | Self::Bz2
+                | Self::Hex
+                | Self::Quopri
+                | Self::Rot13
+                | Self::UU
+                | Self::Zlib
+        )
+    }
+
+    /// Encodes `input`, failing on the first character the encoding can't represent.
+    pub fn encode(&self, input: &str) -> Result<Vec<u8>, CodecError> {
+        self.encode_with(input, Errors::Strict)
+    }
+
+    /// Decodes `input`, failing on the first invalid sequence. The lone surrogates UTF-7 can
+    /// carry come out as `U+FFFD`, see [`Encoding::decode_with`] to keep them.
+    pub fn decode(&self, input: &[u8]) -> Result<String, CodecError> {
+        self.decode_with(input, Errors::Strict)
+            .map(Wtf8Buf::into_string_lossy)
+    }
+
+    /// Encodes `input`, resolving unencodable characters with `errors`.
+    ///
+    /// The input may be a `str` or a [`Wtf8`] string carrying the surrogates that
+    /// [`Errors::SurrogateEscape`] maps back to bytes or [`Errors::SurrogatePass`] passes
+    /// through.
+    pub fn encode_with<S: AsRef<Wtf8> + ?Sized>(
+        &self,
+        input: &S,
+        errors: Errors,
+    ) -> Result<Vec<u8>, CodecError> {
+        IncrementalEncoder::new(*self, errors)?.encode(input, true)
+    }
+
+    /// Decodes `input`, resolving invalid sequences with `errors`.
+    ///
+    /// The result is a [`Wtf8Buf`] because [`Errors::SurrogateEscape`] and
+    /// [`Errors::SurrogatePass`] produce lone surrogates, as does UTF-7 on its own; otherwise
+    /// [`Wtf8Buf::into_string`] always succeeds.
+    pub fn decode_with(&self, input: &[u8], errors: Errors) -> Result<Wtf8Buf, CodecError> {
+        let mut output = Wtf8Buf::with_capacity(input.len());
+        IncrementalDecoder::new(*self, errors)?.decode_into(input, &mut output, true)?;
+        Ok(output)
+    }
+
+    /// Applies a bytes-to-bytes codec, like Python's `codecs.encode(input, "base64")`.
+    ///
+    /// `Base64` writes lines of 76 characters and `Quopri` quotes tabs and spaces, as Python
+    /// does; `Rot13` rotates the ASCII letters of the bytes. With the `compress` feature,
+    /// `Zlib` and `Bz2` compress at the default level of Python.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rsutil::types::{CodecError, Encoding};
+    ///
+    /// assert_eq!(Encoding::Hex.transform_encode(b"\x01\xab"), Ok(b"01ab".to_vec()));
+    /// assert_eq!(Encoding::Base64.transform_encode(b"hi"), Ok(b"aGk=\n".to_vec()));
+    /// assert_eq!(Encoding::Quopri.transform_encode(b"a b=\xff"), Ok(b"a=20b=3D=FF".to_vec()));
+    /// assert!(matches!(
+    ///     Encoding::Utf8.transform_encode(b"hi"),
+    ///     Err(CodecError::NotTransform(Encoding::Utf8))
+    /// ));
+    /// ```
+    pub fn transform_encode(&self, input: &[u8]) -> Result<Vec<u8>, CodecError> {
+        Ok(match self {
+            Self::Base64 => transform::base64_encode(input),
+            Self::Hex => transform::hex_encode(input),
+            Self::Quopri => transform::quopri_encode(input),
+            Self::Rot13 => transform::rot13(input),
+            Self::UU => transform::uu_encode(input),
+            #[cfg(feature = "compress")]
+            Self::Bz2 | Self::Zlib => compress::compress(*self, input)?,
+            #[cfg(not(feature = "compress"))]
+            Self::Bz2 | Self::Zlib => return Err(CodecError::Unsupported(*self)),
+            _ => return Err(CodecError::NotTransform(*self)),
+        })
+    }
+
+    /// Reverses a bytes-to-bytes codec, like Python's `codecs.decode(input, "base64")`.
+    ///
+    /// As in Python, `Base64` skips the characters outside its alphabet and `Quopri` keeps
+    /// malformed escapes, while `Hex` and `UU` reject malformed input. `Bz2` decompresses
+    /// concatenated streams like `bz2.decompress`, and `Zlib` ignores what follows its stream.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rsutil::types::{CodecError, Encoding};
+    ///
+    /// assert_eq!(Encoding::Base64.transform_decode(b"aG\nk="), Ok(b"hi".to_vec()));
+    /// assert_eq!(Encoding::UU.transform_decode(b"begin 666 <data>\n\":&D \n \nend\n"), Ok(b"hi".to_vec()));
+    /// let err = Encoding::Hex.transform_decode(b"abc").unwrap_err();
+    /// assert_eq!(err.to_string(), "decoding with 'hex' codec failed: Odd-length string");
+    /// ```
+    pub fn transform_decode(&self, input: &[u8]) -> Result<Vec<u8>, CodecError> {
+        match self {
+            Self::Base64 => transform::base64_decode(input),
+            Self::Hex => transform::hex_decode(input),
+            Self::Quopri => Ok(transform::quopri_decode(input)),
+            Self::Rot13 => Ok(transform::rot13(input)),
+            Self::UU => transform::uu_decode(input),
+            #[cfg(feature = "compress")]
+            Self::Bz2 | Self::Zlib => return compress::decompress(*self, input),
+            #[cfg(not(feature = "compress"))]
+            Self::Bz2 | Self::Zlib => return Err(CodecError::Unsupported(*self)),
+            _ => return Err(CodecError::NotTransform(*self)),
+        }
+        .map_err(|reason| CodecError::Transform {
+            encoding: *self,
+            reason,
+        })
+    }
+
+    fn check_text(&self) -> Result<(), CodecError> {
+        match self.is_text() {
+            true => Ok(()),
+            false => Err(CodecError::NotText(*self)),
+        }
+    }
+
+    /// Like in Python, `idna` only takes `strict` errors; `namereplace` needs its feature.
+    pub(crate) fn check_errors(&self, errors: Errors) -> Result<(), CodecError> {
+        match (self, errors) {
+            (Self::Idna, errors) if errors != Errors::Strict => {
+                Err(CodecError::UnsupportedErrors(*self, errors))
+            }
+            #[cfg(not(feature = "namereplace"))]
+            (_, Errors::NameReplace) => Err(CodecError::UnsupportedErrors(*self, errors)),
+            _ => Ok(()),
+        }
+    }
+
+    /// A fresh codec of the text encoding, which [`decoder`](Self::decoder) and
+    /// [`encoder`](Self::encoder) hand out as one side or the other.
+    fn codec(&self) -> Result<Box<dyn Coder>, CodecError> {
+        self.check_text()?;
+        if let Some(map) = charmap::table(*self) {
+            return Ok(Box::new(map));
+        }
+        Ok(match self {
+            Self::Ascii => Box::new(charmap::Ordinal::ASCII),
+            Self::Latin1 => Box::new(charmap::Ordinal::LATIN1),
+            Self::ShiftJis => Box::new(cjk::ShiftJis),
+            Self::Cp932 => Box::new(cjk::Cp932),
+            Self::EucJp => Box::new(cjk::EucJp),
+            Self::ShiftJis2004 => Box::new(cjk::Jisx0213::SHIFT_JIS_2004),
+            Self::ShiftJisx0213 => Box::new(cjk::Jisx0213::SHIFT_JISX0213),
+            Self::EucJis2004 => Box::new(cjk::Jisx0213::EUC_JIS_2004),
+            Self::EucJisx0213 => Box::new(cjk::Jisx0213::EUC_JISX0213),
+            Self::Gb2312 => Box::new(cjk::Gb2312),
+            Self::Gbk => Box::new(cjk::Gbk),
+            Self::Gb18030 => Box::new(cjk::Gb18030),
+            Self::Hz => Box::<cjk::Hz>::default(),
+            Self::Big5 => Box::new(cjk::Big5),
+            Self::Cp950 => Box::new(cjk::Cp950),
+            Self::Big5HkScs => Box::new(cjk::Big5HkScs),
+            Self::EucKr => Box::new(cjk::EucKr),
+            Self::Cp949 => Box::new(cjk::Cp949),
+            Self::Johab => Box::new(cjk::Johab),
+            Self::Iso2022Jp => Box::new(cjk::Iso2022::JP),
+            Self::Iso2022Jp1 => Box::new(cjk::Iso2022::JP_1),
+            Self::Iso2022Jp2 => Box::new(cjk::Iso2022::JP_2),
+            Self::Iso2022Jp2004 => Box::new(cjk::Iso2022::JP_2004),
+            Self::Iso2022Jp3 => Box::new(cjk::Iso2022::JP_3),
+            Self::Iso2022JpExt => Box::new(cjk::Iso2022::JP_EXT),
+            Self::Iso2022Kr => Box::new(cjk::Iso2022::KR),
+            Self::Utf8 => Box::new(utf::Utf8),
+            Self::Utf16 => Box::new(utf::Utf16::new(None)),
+            Self::Utf16be => Box::new(utf::Utf16::new(Some(ByteOrder::Big))),
+            Self::Utf16le => Box::new(utf::Utf16::new(Some(ByteOrder::Little))),
+            Self::Utf32 => Box::new(utf::Utf32::new(None)),
+            Self::Utf32be => Box::new(utf::Utf32::new(Some(ByteOrder::Big))),
+            Self::Utf32le => Box::new(utf::Utf32::new(Some(ByteOrder::Little))),
+            Self::Utf7 => Box::new(utf::Utf7),
+            Self::Imap4Utf7 => Box::new(utf::Imap4Utf7),
+            Self::Punycode => Box::new(punycode::Punycode),
+            #[cfg(feature = "idna")]
+            Self::Idna => Box::new(idna::Idna),
+            Self::Custom(name) => Box::new(registry::Registered::new(name)?),
+            _ => return Err(CodecError::Unsupported(*self)),
+        })
+    }
+
+    pub(crate) fn decoder(&self) -> Result<Box<dyn Decoder>, CodecError> {
+        Ok(self.codec()?)
+    }
+
+    pub(crate) fn encoder(&self) -> Result<Box<dyn Encoder>, CodecError> {
+        Ok(self.codec()?)
+    }
+}
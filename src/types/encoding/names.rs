@@ -0,0 +1,618 @@
+use super::{registry, CodecError, Encoding};
+use std::fmt::{Display, Formatter, Result};
+use std::str::FromStr;
+
+impl Encoding {
+    /// The canonical Python name of the encoding, e.g. `"utf-8"` or `"shift_jis"`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Ascii => "ascii",
+            Self::Base64 => "base64",
+            Self::Big5 => "big5",
+            Self::Big5HkScs => "big5hkscs",
+            Self::Bz2 => "bz2",
+            Self::Cp037 => "cp037",
+            Self::Cp1026 => "cp1026",
+            Self::Cp1125 => "cp1125",
+            Self::Cp1140 => "cp1140",
+            Self::Cp1250 => "cp1250",
+            Self::Cp1251 => "cp1251",
+            Self::Cp1252 => "cp1252",
+            Self::Cp1253 => "cp1253",
+            Self::Cp1254 => "cp1254",
+            Self::Cp1255 => "cp1255",
+            Self::Cp1256 => "cp1256",
+            Self::Cp1257 => "cp1257",
+            Self::Cp1258 => "cp1258",
+            Self::Cp273 => "cp273",
+            Self::Cp424 => "cp424",
+            Self::Cp437 => "cp437",
+            Self::Cp500 => "cp500",
+            Self::Cp775 => "cp775",
+            Self::Cp850 => "cp850",
+            Self::Cp852 => "cp852",
+            Self::Cp855 => "cp855",
+            Self::Cp857 => "cp857",
+            Self::Cp858 => "cp858",
+            Self::Cp860 => "cp860",
+            Self::Cp861 => "cp861",
+            Self::Cp862 => "cp862",
+            Self::Cp863 => "cp863",
+            Self::Cp864 => "cp864",
+            Self::Cp865 => "cp865",
+            Self::Cp866 => "cp866",
+            Self::Cp869 => "cp869",
+            Self::Cp932 => "cp932",
+            Self::Cp949 => "cp949",
+            Self::Cp950 => "cp950",
+            Self::Custom(name) => name,
+            Self::EucJis2004 => "euc_jis_2004",
+            Self::EucJisx0213 => "euc_jisx0213",
+            Self::EucJp => "euc_jp",
+            Self::EucKr => "euc_kr",
+            Self::Gb18030 => "gb18030",
+            Self::Gb2312 => "gb2312",
+            Self::Gbk => "gbk",
+            Self::Hex => "hex",
+            Self::HpRoman8 => "hp-roman8",
+            Self::Hz => "hz",
+            Self::Idna => "idna",
+            Self::Imap4Utf7 => "imap4-utf-7",
+            Self::Iso2022Jp => "iso2022_jp",
+            Self::Iso2022Jp1 => "iso2022_jp_1",
+            Self::Iso2022Jp2 => "iso2022_jp_2",
+            Self::Iso2022Jp2004 => "iso2022_jp_2004",
+            Self::Iso2022Jp3 => "iso2022_jp_3",
+            Self::Iso2022JpExt => "iso2022_jp_ext",
+            Self::Iso2022Kr => "iso2022_kr",
+            Self::Iso8859_10 => "iso8859-10",
+            Self::Iso8859_11 => "iso8859-11",
+            Self::Iso8859_13 => "iso8859-13",
+            Self::Iso8859_14 => "iso8859-14",
+            Self::Iso8859_15 => "iso8859-15",
+            Self::Iso8859_16 => "iso8859-16",
+            Self::Iso8859_1 => "iso8859-1",
+            Self::Iso8859_2 => "iso8859-2",
+            Self::Iso8859_3 => "iso8859-3",
+            Self::Iso8859_4 => "iso8859-4",
+            Self::Iso8859_5 => "iso8859-5",
+            Self::Iso8859_6 => "iso8859-6",
+            Self::Iso8859_7 => "iso8859-7",
+            Self::Iso8859_8 => "iso8859-8",
+            Self::Iso8859_9 => "iso8859-9",
+            Self::Johab => "johab",
+            Self::Koi8R => "koi8-r",
+            Self::Kz1048 => "kz1048",
+            Self::Latin1 => "latin-1",
+            Self::MacCyrillic => "mac-cyrillic",
+            Self::MacGreek => "mac-greek",
+            Self::MacIceland => "mac-iceland",
+            Self::MacLatin2 => "mac-latin2",
+            Self::MacRoman => "mac-roman",
+            Self::MacTurkish => "mac-turkish",
+            Self::Mbcs => "mbcs",
+            Self::Ptcp154 => "ptcp154",
+            Self::Punycode => "punycode",
+            Self::Quopri => "quopri",
+            Self::Rot13 => "rot-13",
+            Self::ShiftJis => "shift_jis",
+            Self::ShiftJis2004 => "shift_jis_2004",
+            Self::ShiftJisx0213 => "shift_jisx0213",
+            Self::Tis620 => "tis-620",
+            Self::Utf16 => "utf-16",
+            Self::Utf16be => "utf-16-be",
+            Self::Utf16le => "utf-16-le",
+            Self::Utf32 => "utf-32",
+            Self::Utf32be => "utf-32-be",
+            Self::Utf32le => "utf-32-le",
+            Self::Utf7 => "utf-7",
+            Self::Utf8 => "utf-8",
+            Self::UU => "uu",
+            Self::Zlib => "zlib",
+        }
+    }
+}
+
+/// Normalizes like Python's `encodings.normalize_encoding`: lowercase, with every run of
+/// punctuation other than `.` collapsed into a single `_`.
+pub(crate) fn normalize(name: &str) -> String {
+    let mut norm = String::with_capacity(name.len());
+    let mut punct = false;
+    for c in name.trim().chars() {
+        if c.is_alphanumeric() || c == '.' {
+            if punct && !norm.is_empty() {
+                norm.push('_');
+            }
+            if c.is_ascii() {
+                norm.push(c.to_ascii_lowercase());
+            }
+            punct = false;
+        } else {
+            punct = true;
+        }
+    }
+    norm
+}
+
+/// Python's alias table (`encodings.aliases`) plus the codec module names, normalized and
+/// sorted for binary search.
+const ALIASES: &[(&str, Encoding)] = &[
+    ("037", Encoding::Cp037),
+    ("1026", Encoding::Cp1026),
+    ("1125", Encoding::Cp1125),
+    ("1140", Encoding::Cp1140),
+    ("1250", Encoding::Cp1250),
+    ("1251", Encoding::Cp1251),
+    ("1252", Encoding::Cp1252),
+    ("1253", Encoding::Cp1253),
+    ("1254", Encoding::Cp1254),
+    ("1255", Encoding::Cp1255),
+    ("1256", Encoding::Cp1256),
+    ("1257", Encoding::Cp1257),
+    ("1258", Encoding::Cp1258),
+    ("273", Encoding::Cp273),
+    ("424", Encoding::Cp424),
+    ("437", Encoding::Cp437),
+    ("500", Encoding::Cp500),
+    ("646", Encoding::Ascii),
+    ("775", Encoding::Cp775),
+    ("850", Encoding::Cp850),
+    ("852", Encoding::Cp852),
+    ("855", Encoding::Cp855),
+    ("857", Encoding::Cp857),
+    ("858", Encoding::Cp858),
+    ("860", Encoding::Cp860),
+    ("861", Encoding::Cp861),
+    ("862", Encoding::Cp862),
+    ("863", Encoding::Cp863),
+    ("864", Encoding::Cp864),
+    ("865", Encoding::Cp865),
+    ("866", Encoding::Cp866),
+    ("869", Encoding::Cp869),
+    ("8859", Encoding::Latin1),
+    ("932", Encoding::Cp932),
+    ("936", Encoding::Gbk),
+    ("949", Encoding::Cp949),
+    ("950", Encoding::Cp950),
+    ("ansi", Encoding::Mbcs),
+    ("ansi_x3.4_1968", Encoding::Ascii),
+    ("ansi_x3.4_1986", Encoding::Ascii),
+    ("ansi_x3_4_1968", Encoding::Ascii),
+    ("arabic", Encoding::Iso8859_6),
+    ("ascii", Encoding::Ascii),
+    ("asmo_708", Encoding::Iso8859_6),
+    ("base64", Encoding::Base64),
+    ("base64_codec", Encoding::Base64),
+    ("base_64", Encoding::Base64),
+    ("big5", Encoding::Big5),
+    ("big5_hkscs", Encoding::Big5HkScs),
+    ("big5_tw", Encoding::Big5),
+    ("big5hkscs", Encoding::Big5HkScs),
+    ("bz2", Encoding::Bz2),
+    ("bz2_codec", Encoding::Bz2),
+    ("chinese", Encoding::Gb2312),
+    ("cp037", Encoding::Cp037),
+    ("cp1026", Encoding::Cp1026),
+    ("cp1051", Encoding::HpRoman8),
+    ("cp1125", Encoding::Cp1125),
+    ("cp1140", Encoding::Cp1140),
+    ("cp1250", Encoding::Cp1250),
+    ("cp1251", Encoding::Cp1251),
+    ("cp1252", Encoding::Cp1252),
+    ("cp1253", Encoding::Cp1253),
+    ("cp1254", Encoding::Cp1254),
+    ("cp1255", Encoding::Cp1255),
+    ("cp1256", Encoding::Cp1256),
+    ("cp1257", Encoding::Cp1257),
+    ("cp1258", Encoding::Cp1258),
+    ("cp1361", Encoding::Johab),
+    ("cp154", Encoding::Ptcp154),
+    ("cp273", Encoding::Cp273),
+    ("cp367", Encoding::Ascii),
+    ("cp424", Encoding::Cp424),
+    ("cp437", Encoding::Cp437),
+    ("cp500", Encoding::Cp500),
+    ("cp65001", Encoding::Utf8),
+    ("cp775", Encoding::Cp775),
+    ("cp819", Encoding::Latin1),
+    ("cp850", Encoding::Cp850),
+    ("cp852", Encoding::Cp852),
+    ("cp855", Encoding::Cp855),
+    ("cp857", Encoding::Cp857),
+    ("cp858", Encoding::Cp858),
+    ("cp860", Encoding::Cp860),
+    ("cp861", Encoding::Cp861),
+    ("cp862", Encoding::Cp862),
+    ("cp863", Encoding::Cp863),
+    ("cp864", Encoding::Cp864),
+    ("cp865", Encoding::Cp865),
+    ("cp866", Encoding::Cp866),
+    ("cp866u", Encoding::Cp1125),
+    ("cp869", Encoding::Cp869),
+    ("cp932", Encoding::Cp932),
+    ("cp936", Encoding::Gbk),
+    ("cp949", Encoding::Cp949),
+    ("cp950", Encoding::Cp950),
+    ("cp_gr", Encoding::Cp869),
+    ("cp_is", Encoding::Cp861),
+    ("csascii", Encoding::Ascii),
+    ("csbig5", Encoding::Big5),
+    ("cshproman8", Encoding::HpRoman8),
+    ("csibm037", Encoding::Cp037),
+    ("csibm1026", Encoding::Cp1026),
+    ("csibm273", Encoding::Cp273),
+    ("csibm424", Encoding::Cp424),
+    ("csibm500", Encoding::Cp500),
+    ("csibm855", Encoding::Cp855),
+    ("csibm857", Encoding::Cp857),
+    ("csibm858", Encoding::Cp858),
+    ("csibm860", Encoding::Cp860),
+    ("csibm861", Encoding::Cp861),
+    ("csibm863", Encoding::Cp863),
+    ("csibm864", Encoding::Cp864),
+    ("csibm865", Encoding::Cp865),
+    ("csibm866", Encoding::Cp866),
+    ("csibm869", Encoding::Cp869),
+    ("csiso2022jp", Encoding::Iso2022Jp),
+    ("csiso2022kr", Encoding::Iso2022Kr),
+    ("csiso58gb231280", Encoding::Gb2312),
+    ("csisolatin1", Encoding::Latin1),
+    ("csisolatin2", Encoding::Iso8859_2),
+    ("csisolatin3", Encoding::Iso8859_3),
+    ("csisolatin4", Encoding::Iso8859_4),
+    ("csisolatin5", Encoding::Iso8859_9),
+    ("csisolatin6", Encoding::Iso8859_10),
+    ("csisolatinarabic", Encoding::Iso8859_6),
+    ("csisolatincyrillic", Encoding::Iso8859_5),
+    ("csisolatingreek", Encoding::Iso8859_7),
+    ("csisolatinhebrew", Encoding::Iso8859_8),
+    ("cskoi8r", Encoding::Koi8R),
+    ("cspc775baltic", Encoding::Cp775),
+    ("cspc850multilingual", Encoding::Cp850),
+    ("cspc862latinhebrew", Encoding::Cp862),
+    ("cspc8codepage437", Encoding::Cp437),
+    ("cspcp852", Encoding::Cp852),
+    ("csptcp154", Encoding::Ptcp154),
+    ("csshiftjis", Encoding::ShiftJis),
+    ("cyrillic", Encoding::Iso8859_5),
+    ("cyrillic_asian", Encoding::Ptcp154),
+    ("dbcs", Encoding::Mbcs),
+    ("ebcdic_cp_be", Encoding::Cp500),
+    ("ebcdic_cp_ca", Encoding::Cp037),
+    ("ebcdic_cp_ch", Encoding::Cp500),
+    ("ebcdic_cp_he", Encoding::Cp424),
+    ("ebcdic_cp_nl", Encoding::Cp037),
+    ("ebcdic_cp_us", Encoding::Cp037),
+    ("ebcdic_cp_wt", Encoding::Cp037),
+    ("ecma_114", Encoding::Iso8859_6),
+    ("ecma_118", Encoding::Iso8859_7),
+    ("elot_928", Encoding::Iso8859_7),
+    ("euc_cn", Encoding::Gb2312),
+    ("euc_jis2004", Encoding::EucJis2004),
+    ("euc_jis_2004", Encoding::EucJis2004),
+    ("euc_jisx0213", Encoding::EucJisx0213),
+    ("euc_jp", Encoding::EucJp),
+    ("euc_kr", Encoding::EucKr),
+    ("euccn", Encoding::Gb2312),
+    ("eucgb2312_cn", Encoding::Gb2312),
+    ("eucjis2004", Encoding::EucJis2004),
+    ("eucjisx0213", Encoding::EucJisx0213),
+    ("eucjp", Encoding::EucJp),
+    ("euckr", Encoding::EucKr),
+    ("gb18030", Encoding::Gb18030),
+    ("gb18030_2000", Encoding::Gb18030),
+    ("gb2312", Encoding::Gb2312),
+    ("gb2312_1980", Encoding::Gb2312),
+    ("gb2312_80", Encoding::Gb2312),
+    ("gbk", Encoding::Gbk),
+    ("greek", Encoding::Iso8859_7),
+    ("greek8", Encoding::Iso8859_7),
+    ("hebrew", Encoding::Iso8859_8),
+    ("hex", Encoding::Hex),
+    ("hex_codec", Encoding::Hex),
+    ("hkscs", Encoding::Big5HkScs),
+    ("hp_roman8", Encoding::HpRoman8),
+    ("hz", Encoding::Hz),
+    ("hz_gb", Encoding::Hz),
+    ("hz_gb_2312", Encoding::Hz),
+    ("hzgb", Encoding::Hz),
+    ("ibm037", Encoding::Cp037),
+    ("ibm039", Encoding::Cp037),
+    ("ibm1026", Encoding::Cp1026),
+    ("ibm1051", Encoding::HpRoman8),
+    ("ibm1125", Encoding::Cp1125),
+    ("ibm1140", Encoding::Cp1140),
+    ("ibm273", Encoding::Cp273),
+    ("ibm367", Encoding::Ascii),
+    ("ibm424", Encoding::Cp424),
+    ("ibm437", Encoding::Cp437),
+    ("ibm500", Encoding::Cp500),
+    ("ibm775", Encoding::Cp775),
+    ("ibm819", Encoding::Latin1),
+    ("ibm850", Encoding::Cp850),
+    ("ibm852", Encoding::Cp852),
+    ("ibm855", Encoding::Cp855),
+    ("ibm857", Encoding::Cp857),
+    ("ibm858", Encoding::Cp858),
+    ("ibm860", Encoding::Cp860),
+    ("ibm861", Encoding::Cp861),
+    ("ibm862", Encoding::Cp862),
+    ("ibm863", Encoding::Cp863),
+    ("ibm864", Encoding::Cp864),
+    ("ibm865", Encoding::Cp865),
+    ("ibm866", Encoding::Cp866),
+    ("ibm869", Encoding::Cp869),
+    ("idna", Encoding::Idna),
+    ("imap4_utf_7", Encoding::Imap4Utf7),
+    ("iso2022_jp", Encoding::Iso2022Jp),
+    ("iso2022_jp_1", Encoding::Iso2022Jp1),
+    ("iso2022_jp_2", Encoding::Iso2022Jp2),
+    ("iso2022_jp_2004", Encoding::Iso2022Jp2004),
+    ("iso2022_jp_3", Encoding::Iso2022Jp3),
+    ("iso2022_jp_ext", Encoding::Iso2022JpExt),
+    ("iso2022_kr", Encoding::Iso2022Kr),
+    ("iso2022jp", Encoding::Iso2022Jp),
+    ("iso2022jp_1", Encoding::Iso2022Jp1),
+    ("iso2022jp_2", Encoding::Iso2022Jp2),
+    ("iso2022jp_2004", Encoding::Iso2022Jp2004),
+    ("iso2022jp_3", Encoding::Iso2022Jp3),
+    ("iso2022jp_ext", Encoding::Iso2022JpExt),
+    ("iso2022kr", Encoding::Iso2022Kr),
+    ("iso646_us", Encoding::Ascii),
+    ("iso8859", Encoding::Latin1),
+    ("iso8859_1", Encoding::Latin1),
+    ("iso8859_10", Encoding::Iso8859_10),
+    ("iso8859_11", Encoding::Iso8859_11),
+    ("iso8859_13", Encoding::Iso8859_13),
+    ("iso8859_14", Encoding::Iso8859_14),
+    ("iso8859_15", Encoding::Iso8859_15),
+    ("iso8859_16", Encoding::Iso8859_16),
+    ("iso8859_2", Encoding::Iso8859_2),
+    ("iso8859_3", Encoding::Iso8859_3),
+    ("iso8859_4", Encoding::Iso8859_4),
+    ("iso8859_5", Encoding::Iso8859_5),
+    ("iso8859_6", Encoding::Iso8859_6),
+    ("iso8859_7", Encoding::Iso8859_7),
+    ("iso8859_8", Encoding::Iso8859_8),
+    ("iso8859_9", Encoding::Iso8859_9),
+    ("iso_2022_jp", Encoding::Iso2022Jp),
+    ("iso_2022_jp_1", Encoding::Iso2022Jp1),
+    ("iso_2022_jp_2", Encoding::Iso2022Jp2),
+    ("iso_2022_jp_2004", Encoding::Iso2022Jp2004),
+    ("iso_2022_jp_3", Encoding::Iso2022Jp3),
+    ("iso_2022_jp_ext", Encoding::Iso2022JpExt),
+    ("iso_2022_kr", Encoding::Iso2022Kr),
+    ("iso_646.irv_1991", Encoding::Ascii),
+    ("iso_8859_1", Encoding::Latin1),
+    ("iso_8859_10", Encoding::Iso8859_10),
+    ("iso_8859_10_1992", Encoding::Iso8859_10),
+    ("iso_8859_11", Encoding::Iso8859_11),
+    ("iso_8859_11_2001", Encoding::Iso8859_11),
+    ("iso_8859_13", Encoding::Iso8859_13),
+    ("iso_8859_14", Encoding::Iso8859_14),
+    ("iso_8859_14_1998", Encoding::Iso8859_14),
+    ("iso_8859_15", Encoding::Iso8859_15),
+    ("iso_8859_16", Encoding::Iso8859_16),
+    ("iso_8859_16_2001", Encoding::Iso8859_16),
+    ("iso_8859_1_1987", Encoding::Latin1),
+    ("iso_8859_2", Encoding::Iso8859_2),
+    ("iso_8859_2_1987", Encoding::Iso8859_2),
+    ("iso_8859_3", Encoding::Iso8859_3),
+    ("iso_8859_3_1988", Encoding::Iso8859_3),
+    ("iso_8859_4", Encoding::Iso8859_4),
+    ("iso_8859_4_1988", Encoding::Iso8859_4),
+    ("iso_8859_5", Encoding::Iso8859_5),
+    ("iso_8859_5_1988", Encoding::Iso8859_5),
+    ("iso_8859_6", Encoding::Iso8859_6),
+    ("iso_8859_6_1987", Encoding::Iso8859_6),
+    ("iso_8859_7", Encoding::Iso8859_7),
+    ("iso_8859_7_1987", Encoding::Iso8859_7),
+    ("iso_8859_8", Encoding::Iso8859_8),
+    ("iso_8859_8_1988", Encoding::Iso8859_8),
+    ("iso_8859_9", Encoding::Iso8859_9),
+    ("iso_8859_9_1989", Encoding::Iso8859_9),
+    ("iso_celtic", Encoding::Iso8859_14),
+    ("iso_ir_100", Encoding::Latin1),
+    ("iso_ir_101", Encoding::Iso8859_2),
+    ("iso_ir_109", Encoding::Iso8859_3),
+    ("iso_ir_110", Encoding::Iso8859_4),
+    ("iso_ir_126", Encoding::Iso8859_7),
+    ("iso_ir_127", Encoding::Iso8859_6),
+    ("iso_ir_138", Encoding::Iso8859_8),
+    ("iso_ir_144", Encoding::Iso8859_5),
+    ("iso_ir_148", Encoding::Iso8859_9),
+    ("iso_ir_157", Encoding::Iso8859_10),
+    ("iso_ir_166", Encoding::Tis620),
+    ("iso_ir_199", Encoding::Iso8859_14),
+    ("iso_ir_226", Encoding::Iso8859_16),
+    ("iso_ir_58", Encoding::Gb2312),
+    ("iso_ir_6", Encoding::Ascii),
+    ("jisx0213", Encoding::EucJis2004),
+    ("johab", Encoding::Johab),
+    ("koi8_r", Encoding::Koi8R),
+    ("korean", Encoding::EucKr),
+    ("ks_c_5601", Encoding::EucKr),
+    ("ks_c_5601_1987", Encoding::EucKr),
+    ("ks_x_1001", Encoding::EucKr),
+    ("ksc5601", Encoding::EucKr),
+    ("ksx1001", Encoding::EucKr),
+    ("kz1048", Encoding::Kz1048),
+    ("kz_1048", Encoding::Kz1048),
+    ("l1", Encoding::Latin1),
+    ("l10", Encoding::Iso8859_16),
+    ("l2", Encoding::Iso8859_2),
+    ("l3", Encoding::Iso8859_3),
+    ("l4", Encoding::Iso8859_4),
+    ("l5", Encoding::Iso8859_9),
+    ("l6", Encoding::Iso8859_10),
+    ("l7", Encoding::Iso8859_13),
+    ("l8", Encoding::Iso8859_14),
+    ("l9", Encoding::Iso8859_15),
+    ("latin", Encoding::Latin1),
+    ("latin1", Encoding::Latin1),
+    ("latin10", Encoding::Iso8859_16),
+    ("latin2", Encoding::Iso8859_2),
+    ("latin3", Encoding::Iso8859_3),
+    ("latin4", Encoding::Iso8859_4),
+    ("latin5", Encoding::Iso8859_9),
+    ("latin6", Encoding::Iso8859_10),
+    ("latin7", Encoding::Iso8859_13),
+    ("latin8", Encoding::Iso8859_14),
+    ("latin9", Encoding::Iso8859_15),
+    ("latin_1", Encoding::Latin1),
+    ("mac_centeuro", Encoding::MacLatin2),
+    ("mac_cyrillic", Encoding::MacCyrillic),
+    ("mac_greek", Encoding::MacGreek),
+    ("mac_iceland", Encoding::MacIceland),
+    ("mac_latin2", Encoding::MacLatin2),
+    ("mac_roman", Encoding::MacRoman),
+    ("mac_turkish", Encoding::MacTurkish),
+    ("maccentraleurope", Encoding::MacLatin2),
+    ("maccyrillic", Encoding::MacCyrillic),
+    ("macgreek", Encoding::MacGreek),
+    ("maciceland", Encoding::MacIceland),
+    ("macintosh", Encoding::MacRoman),
+    ("maclatin2", Encoding::MacLatin2),
+    ("macroman", Encoding::MacRoman),
+    ("macturkish", Encoding::MacTurkish),
+    ("mbcs", Encoding::Mbcs),
+    ("ms1361", Encoding::Johab),
+    ("ms932", Encoding::Cp932),
+    ("ms936", Encoding::Gbk),
+    ("ms949", Encoding::Cp949),
+    ("ms950", Encoding::Cp950),
+    ("ms_kanji", Encoding::Cp932),
+    ("mskanji", Encoding::Cp932),
+    ("pt154", Encoding::Ptcp154),
+    ("ptcp154", Encoding::Ptcp154),
+    ("punycode", Encoding::Punycode),
+    ("quopri", Encoding::Quopri),
+    ("quopri_codec", Encoding::Quopri),
+    ("quoted_printable", Encoding::Quopri),
+    ("quotedprintable", Encoding::Quopri),
+    ("r8", Encoding::HpRoman8),
+    ("rk1048", Encoding::Kz1048),
+    ("roman8", Encoding::HpRoman8),
+    ("rot13", Encoding::Rot13),
+    ("rot_13", Encoding::Rot13),
+    ("ruscii", Encoding::Cp1125),
+    ("s_jis", Encoding::ShiftJis),
+    ("s_jis_2004", Encoding::ShiftJis2004),
+    ("s_jisx0213", Encoding::ShiftJisx0213),
+    ("shift_jis", Encoding::ShiftJis),
+    ("shift_jis_2004", Encoding::ShiftJis2004),
+    ("shift_jisx0213", Encoding::ShiftJisx0213),
+    ("shiftjis", Encoding::ShiftJis),
+    ("shiftjis2004", Encoding::ShiftJis2004),
+    ("shiftjisx0213", Encoding::ShiftJisx0213),
+    ("sjis", Encoding::ShiftJis),
+    ("sjis_2004", Encoding::ShiftJis2004),
+    ("sjisx0213", Encoding::ShiftJisx0213),
+    ("strk1048_2002", Encoding::Kz1048),
+    ("thai", Encoding::Iso8859_11),
+    ("tis620", Encoding::Tis620),
+    ("tis_620", Encoding::Tis620),
+    ("tis_620_0", Encoding::Tis620),
+    ("tis_620_2529_0", Encoding::Tis620),
+    ("tis_620_2529_1", Encoding::Tis620),
+    ("u16", Encoding::Utf16),
+    ("u32", Encoding::Utf32),
+    ("u7", Encoding::Utf7),
+    ("u8", Encoding::Utf8),
+    ("u_jis", Encoding::EucJp),
+    ("uhc", Encoding::Cp949),
+    ("ujis", Encoding::EucJp),
+    ("unicode_1_1_utf_7", Encoding::Utf7),
+    ("unicodebigunmarked", Encoding::Utf16be),
+    ("unicodelittleunmarked", Encoding::Utf16le),
+    ("us", Encoding::Ascii),
+    ("us_ascii", Encoding::Ascii),
+    ("utf", Encoding::Utf8),
+    ("utf16", Encoding::Utf16),
+    ("utf32", Encoding::Utf32),
+    ("utf7", Encoding::Utf7),
+    ("utf8", Encoding::Utf8),
+    ("utf8_ucs2", Encoding::Utf8),
+    ("utf8_ucs4", Encoding::Utf8),
+    ("utf_16", Encoding::Utf16),
+    ("utf_16_be", Encoding::Utf16be),
+    ("utf_16_le", Encoding::Utf16le),
+    ("utf_16be", Encoding::Utf16be),
+    ("utf_16le", Encoding::Utf16le),
+    ("utf_32", Encoding::Utf32),
+    ("utf_32_be", Encoding::Utf32be),
+    ("utf_32_le", Encoding::Utf32le),
+    ("utf_32be", Encoding::Utf32be),
+    ("utf_32le", Encoding::Utf32le),
+    ("utf_7", Encoding::Utf7),
+    ("utf_7_imap", Encoding::Imap4Utf7),
+    ("utf_8", Encoding::Utf8),
+    ("uu", Encoding::UU),
+    ("uu_codec", Encoding::UU),
+    ("windows_1250", Encoding::Cp1250),
+    ("windows_1251", Encoding::Cp1251),
+    ("windows_1252", Encoding::Cp1252),
+    ("windows_1253", Encoding::Cp1253),
+    ("windows_1254", Encoding::Cp1254),
+    ("windows_1255", Encoding::Cp1255),
+    ("windows_1256", Encoding::Cp1256),
+    ("windows_1257", Encoding::Cp1257),
+    ("windows_1258", Encoding::Cp1258),
+    ("x_mac_japanese", Encoding::ShiftJis),
+    ("x_mac_korean", Encoding::EucKr),
+    ("x_mac_simp_chinese", Encoding::Gb2312),
+    ("x_mac_trad_chinese", Encoding::Big5),
+    ("zip", Encoding::Zlib),
+    ("zlib", Encoding::Zlib),
+    ("zlib_codec", Encoding::Zlib),
+];
+
+pub(crate) fn lookup(name: &str) -> Option<Encoding> {
+    ALIASES
+        .binary_search_by(|(alias, _)| alias.cmp(&name))
+        .ok()
+        .map(|i| ALIASES[i].1)
+}
+
+/// Every built-in encoding, once each.
+#[cfg(test)]
+pub(crate) fn encodings() -> Vec<Encoding> {
+    let mut encodings = ALIASES.iter().map(|&(_, e)| e).collect::<Vec<_>>();
+    encodings.sort_by_key(|e| e.name());
+    encodings.dedup();
+    encodings
+}
+
+impl Display for Encoding {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Encoding {
+    type Err = CodecError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let norm = normalize(s);
+        lookup(&norm)
+            .or_else(|| lookup(&norm.replace('.', "_")))
+            .or_else(|| registry::find(&norm))
+            .ok_or_else(|| CodecError::UnknownEncoding(s.to_string()))
+    }
+}
+
+impl TryFrom<String> for Encoding {
+    type Error = CodecError;
+
+    #[inline]
+    fn try_from(value: String) -> std::result::Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<Encoding> for &'static str {
+    #[inline]
+    fn from(value: Encoding) -> Self {
+        value.name()
+    }
+}
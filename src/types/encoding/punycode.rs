@@ -0,0 +1,157 @@
+//! The `punycode` codec of RFC 3492, which IDNA uses for the labels of a domain name.
+
+use super::codec::{Decoder, Encoder, Fault};
+use super::{Wtf8, Wtf8Buf};
+
+const BASE: u64 = 36;
+const TMIN: u64 = 1;
+const TMAX: u64 = 26;
+const SKEW: u64 = 38;
+const DAMP: u64 = 700;
+const INITIAL_BIAS: u64 = 72;
+const INITIAL_N: u64 = 0x80;
+
+/// The threshold of the digit at position `k`.
+#[inline]
+fn threshold(k: u64, bias: u64) -> u64 {
+    k.saturating_sub(bias).clamp(TMIN, TMAX)
+}
+
+fn adapt(delta: u64, points: u64, first: bool) -> u64 {
+    let mut delta = delta / if first { DAMP } else { 2 };
+    delta += delta / points;
+    let mut k = 0;
+    while delta > (BASE - TMIN) * TMAX / 2 {
+        delta /= BASE - TMIN;
+        k += BASE;
+    }
+    k + (BASE - TMIN + 1) * delta / (delta + SKEW)
+}
+
+#[inline]
+fn digit(d: u64) -> u8 {
+    match d {
+        0..=25 => b'a' + d as u8,
+        _ => b'0' + (d - 26) as u8,
+    }
+}
+
+/// Encodes the code points, which may include surrogates like in Python, appending the
+/// ASCII result to `output`.
+pub(crate) fn encode(input: &[u32], output: &mut Vec<u8>) {
+    output.extend(input.iter().filter(|&&c| c < 0x80).map(|&c| c as u8));
+    let mut handled = input.iter().filter(|&&c| c < 0x80).count() as u64;
+    if handled > 0 {
+        output.push(b'-');
+    }
+    let first = handled;
+    let (mut n, mut delta, mut bias) = (INITIAL_N, 0, INITIAL_BIAS);
+    while (handled as usize) < input.len() {
+        let m = input
+            .iter()
+            .map(|&c| c as u64)
+            .filter(|&c| c >= n)
+            .min()
+            .unwrap_or(n);
+        delta += (m - n) * (handled + 1);
+        n = m;
+        for &c in input {
+            let c = c as u64;
+            if c < n {
+                delta += 1;
+            }
+            if c == n {
+                let mut q = delta;
+                let mut k = BASE;
+                loop {
+                    let t = threshold(k, bias);
+                    if q < t {
+                        break;
+                    }
+                    output.push(digit(t + (q - t) % (BASE - t)));
+                    q = (q - t) / (BASE - t);
+                    k += BASE;
+                }
+                output.push(digit(q));
+                bias = adapt(delta, handled + 1, handled == first);
+                delta = 0;
+                handled += 1;
+            }
+        }
+        delta += 1;
+        n += 1;
+    }
+}
+
+/// Decodes `input` into code points; fails with the reason Python gives.
+pub(crate) fn decode(input: &[u8]) -> Result<Vec<u32>, &'static str> {
+    if !input.is_ascii() {
+        return Err("ordinal not in range(128)");
+    }
+    let (basic, extended) = match input.iter().rposition(|&b| b == b'-') {
+        Some(pos) => (&input[..pos], &input[pos + 1..]),
+        None => (&input[..0], input),
+    };
+    let mut output = basic.iter().map(|&b| b as u32).collect::<Vec<_>>();
+    let (mut n, mut i, mut bias) = (INITIAL_N, 0u64, INITIAL_BIAS);
+    let mut digits = extended.iter();
+    while digits.len() > 0 {
+        let start = i;
+        let mut w = 1u64;
+        let mut k = BASE;
+        loop {
+            let d = match digits.next() {
+                Some(&b @ b'a'..=b'z') => b - b'a',
+                Some(&b @ b'A'..=b'Z') => b - b'A',
+                Some(&b @ b'0'..=b'9') => b - b'0' + 26,
+                Some(_) => return Err("invalid extended code point"),
+                None => return Err("incomplete punycode string"),
+            } as u64;
+            i = d
+                .checked_mul(w)
+                .and_then(|v| v.checked_add(i))
+                .ok_or("invalid character")?;
+            let t = threshold(k, bias);
+            if d < t {
+                break;
+            }
+            w = w.checked_mul(BASE - t).ok_or("invalid character")?;
+            k += BASE;
+        }
+        let len = output.len() as u64 + 1;
+        bias = adapt(i - start, len, start == 0);
+        n = n.checked_add(i / len).ok_or("invalid character")?;
+        i %= len;
+        if n > 0x10FFFF {
+            return Err("invalid character");
+        }
+        output.insert(i as usize, n as u32);
+        i += 1;
+    }
+    Ok(output)
+}
+
+/// The `punycode` codec, which needs the whole input and so holds it back until the last
+/// call. Invalid input is reported as a whole.
+pub(crate) struct Punycode;
+
+impl Decoder for Punycode {
+    fn decode(&mut self, input: &[u8], output: &mut Wtf8Buf, last: bool) -> Result<usize, Fault> {
+        if !last {
+            return Ok(0);
+        }
+        let points = decode(input).map_err(|reason| Fault::new(0, input.len(), reason))?;
+        points.into_iter().for_each(|c| output.push_code_point(c));
+        Ok(input.len())
+    }
+}
+
+impl Encoder for Punycode {
+    fn encode(&mut self, input: &Wtf8, output: &mut Vec<u8>, last: bool) -> Result<usize, Fault> {
+        if !last {
+            return Ok(0);
+        }
+        encode(&input.code_points().collect::<Vec<_>>(), output);
+        Ok(input.len())
+    }
+}
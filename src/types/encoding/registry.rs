@@ -0,0 +1,274 @@
+use super::codec::{run_fault, Decoder, Encoder, Fault};
+use super::names::{lookup, normalize};
+use super::{CodecError, Encoding, Wtf8, Wtf8Buf};
+use std::collections::BTreeMap;
+use std::sync::{Arc, PoisonError, RwLock};
+
+/// A text codec defined by the application and registered with [`Encoding::register`], like
+/// the codecs Python's `codecs.register` adds.
+///
+/// Every decoder and encoder of the encoding, streaming ones included, gets a codec of its
+/// own from the factory given to [`Encoding::register`], so a codec can keep state between
+/// calls, like the shift state of ISO-2022. Both methods convert as much of `input` as
+/// possible and return the number of bytes consumed. That is the whole input unless `last` is
+/// false and the input ends inside a sequence, in which case the tail comes back at the head
+/// of the next call, so only the consumed bytes may change the state. On input it can't
+/// convert, a method returns a [`Fault`] with byte offsets into `input`, after having
+/// converted `input[..start]`; the error handler then resolves the fault or reports it, and
+/// the conversion goes on from `end` with the same codec.
+pub trait Codec: Send {
+    fn decode(&mut self, input: &[u8], output: &mut Wtf8Buf, last: bool) -> Result<usize, Fault>;
+
+    /// Encodes `input`, which never holds the lone surrogates a [`Wtf8`] string can carry;
+    /// those fail before they reach the codec, unless `surrogateescape` maps them to bytes.
+    fn encode(&mut self, input: &str, output: &mut Vec<u8>, last: bool) -> Result<usize, Fault>;
+}
+
+/// Makes a fresh codec for each decoder or encoder.
+type Factory = dyn Fn() -> Box<dyn Codec> + Send + Sync;
+
+/// The registered codecs by normalized name. A name keeps its entry once unregistered, so
+/// that the leaked `&'static str` is reused when it is registered again.
+type Registry = BTreeMap<&'static str, Option<Arc<Factory>>>;
+
+static REGISTRY: RwLock<Registry> = RwLock::new(BTreeMap::new());
+
+impl Encoding {
+    /// Registers the codecs `factory` makes under `name`, returning the [`Encoding::Custom`]
+    /// that stands for them. Each decoder and encoder of the encoding calls `factory` once.
+    ///
+    /// The name is normalized the way Python normalizes encoding names, and from then on
+    /// parses to the returned encoding, which works with every API taking an [`Encoding`],
+    /// the error handlers and the streaming ones included. Names of built-in encodings and
+    /// names already registered are refused; see [`Encoding::unregister`] to replace a codec.
+    /// The first registration of a name leaks it as the `&'static str` of `Custom`, and later
+    /// ones reuse it, so the memory leaked is bounded by the distinct names registered.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rsutil::types::{Codec, CodecError, Encoding, Errors, Fault, Wtf8Buf};
+    ///
+    /// /// ASCII with a yen sign in place of the backslash, as on some printers.
+    /// struct Yen;
+    ///
+    /// impl Codec for Yen {
+    ///     fn decode(&mut self, input: &[u8], output: &mut Wtf8Buf, _last: bool) -> Result<usize, Fault> {
+    ///         for (pos, &byte) in input.iter().enumerate() {
+    ///             match byte {
+    ///                 b'\\' => output.push('\u{a5}'),
+    ///                 0..=0x7F => output.push(byte as char),
+    ///                 _ => return Err(Fault::new(pos, pos + 1, "ordinal not in range(128)")),
+    ///             }
+    ///         }
+    ///         Ok(input.len())
+    ///     }
+    ///
+    ///     fn encode(&mut self, input: &str, output: &mut Vec<u8>, _last: bool) -> Result<usize, Fault> {
+    ///         for (pos, ch) in input.char_indices() {
+    ///             match ch {
+    ///                 '\u{a5}' => output.push(b'\\'),
+    ///                 '\\' | '\u{80}'.. => {
+    ///                     return Err(Fault::new(pos, pos + ch.len_utf8(), "character maps to <undefined>"))
+    ///                 }
+    ///                 _ => output.push(ch as u8),
+    ///             }
+    ///         }
+    ///         Ok(input.len())
+    ///     }
+    /// }
+    ///
+    /// let encoding = Encoding::register("Device-Yen", || Yen).unwrap();
+    /// assert_eq!(encoding.name(), "device_yen");
+    /// assert_eq!("device yen".parse(), Ok(encoding));
+    /// assert_eq!(encoding.decode(b"\\100"), Ok("\u{a5}100".to_string()));
+    /// assert_eq!(encoding.encode_with("\u{a5}5\u{e9}", Errors::Replace), Ok(b"\\5?".to_vec()));
+    /// assert!(matches!(encoding.encode("\\"), Err(CodecError::Encode { start: 0, end: 1, .. })));
+    ///
+    /// assert!(Encoding::register("utf8", || Yen).is_err());
+    /// assert!(Encoding::unregister("device_yen"));
+    /// assert!("device_yen".parse::<Encoding>().is_err());
+    /// ```
+    pub fn register<C, F>(name: &str, factory: F) -> Result<Self, CodecError>
+    where
+        C: Codec + 'static,
+        F: Fn() -> C + Send + Sync + 'static,
+    {
+        let norm = normalize(name);
+        if norm.is_empty() {
+            return Err(CodecError::UnknownEncoding(name.to_string()));
+        }
+        if lookup(&norm).is_some() {
+            return Err(CodecError::AlreadyRegistered(norm));
+        }
+        let mut registry = REGISTRY.write().unwrap_or_else(PoisonError::into_inner);
+        let name = match registry.get_key_value(norm.as_str()) {
+            Some((_, Some(_))) => return Err(CodecError::AlreadyRegistered(norm)),
+            Some((&name, None)) => name,
+            None => Box::leak(norm.into_boxed_str()),
+        };
+        let factory: Arc<Factory> = Arc::new(move || Box::new(factory()));
+        let _ = registry.insert(name, Some(factory));
+        Ok(Self::Custom(name))
+    }
+
+    /// Removes the codec registered under `name`, like Python's `codecs.unregister`; returns
+    /// whether there was one. Decoders and encoders created before keep their codecs.
+    pub fn unregister(name: &str) -> bool {
+        REGISTRY
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get_mut(normalize(name).as_str())
+            .and_then(Option::take)
+            .is_some()
+    }
+}
+
+/// The registered encoding of the normalized `name`.
+pub(crate) fn find(name: &str) -> Option<Encoding> {
+    match REGISTRY
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get_key_value(name)
+    {
+        Some((&name, Some(_))) => Some(Encoding::Custom(name)),
+        _ => None,
+    }
+}
+
+/// A registered codec as the [`Decoder`] and [`Encoder`] of its encoding.
+pub(crate) struct Registered(Box<dyn Codec>);
+
+impl Registered {
+    pub fn new(name: &str) -> Result<Self, CodecError> {
+        let factory = REGISTRY
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(normalize(name).as_str())
+            .and_then(Option::clone)
+            .ok_or_else(|| CodecError::UnknownEncoding(name.to_string()))?;
+        // the factory runs without the lock, so it may use the registry itself
+        Ok(Self(factory()))
+    }
+}
+
+impl Decoder for Registered {
+    fn decode(&mut self, input: &[u8], output: &mut Wtf8Buf, last: bool) -> Result<usize, Fault> {
+        self.0.decode(input, output, last)
+    }
+}
+
+impl Encoder for Registered {
+    fn encode(&mut self, input: &Wtf8, output: &mut Vec<u8>, last: bool) -> Result<usize, Fault> {
+        // the codec gets the text up to the first surrogate, which is a fault of its own
+        let (text, end) = match std::str::from_utf8(input.as_bytes()) {
+            Ok(text) => (text, input.len()),
+            Err(e) => {
+                let end = e.valid_up_to();
+                (input.slice(0, end).as_str().unwrap_or_default(), end)
+            }
+        };
+        let len = self.0.encode(text, output, last || end < input.len())?;
+        if len < end || end == input.len() {
+            return Ok(len);
+        }
+        Err(run_fault(input, end, "surrogates not allowed", |c| {
+            (0xD800..=0xDFFF).contains(&c)
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Errors, IncrementalDecoder, IncrementalEncoder};
+
+    /// ASCII where SO and SI shift the letters in between to uppercase, with the shift state
+    /// kept between calls.
+    #[derive(Default)]
+    struct Shift {
+        upper: bool,
+    }
+
+    impl Codec for Shift {
+        fn decode(
+            &mut self,
+            input: &[u8],
+            output: &mut Wtf8Buf,
+            _last: bool,
+        ) -> Result<usize, Fault> {
+            for (pos, &byte) in input.iter().enumerate() {
+                match byte {
+                    0x0E => self.upper = true,
+                    0x0F => self.upper = false,
+                    b'a'..=b'z' if self.upper => output.push(byte.to_ascii_uppercase() as char),
+                    b'A'..=b'Z' => {
+                        return Err(Fault::new(pos, pos + 1, "unexpected uppercase letter"))
+                    }
+                    0..=0x7F => output.push(byte as char),
+                    _ => return Err(Fault::new(pos, pos + 1, "ordinal not in range(128)")),
+                }
+            }
+            Ok(input.len())
+        }
+
+        fn encode(
+            &mut self,
+            input: &str,
+            output: &mut Vec<u8>,
+            last: bool,
+        ) -> Result<usize, Fault> {
+            for (pos, ch) in input.char_indices() {
+                match ch {
+                    'A'..='Z' if !self.upper => {
+                        output.extend([0x0E, ch.to_ascii_lowercase() as u8])
+                    }
+                    'a'..='z' if self.upper => output.extend([0x0F, ch as u8]),
+                    'A'..='Z' => output.push(ch.to_ascii_lowercase() as u8),
+                    '\0'..='\x7f' => output.push(ch as u8),
+                    _ => {
+                        return Err(Fault::new(
+                            pos,
+                            pos + ch.len_utf8(),
+                            "ordinal not in range(128)",
+                        ))
+                    }
+                }
+                self.upper = ch.is_ascii_uppercase() || self.upper && !ch.is_ascii_lowercase();
+            }
+            if last && self.upper {
+                output.push(0x0F);
+                self.upper = false;
+            }
+            Ok(input.len())
+        }
+    }
+
+    #[test]
+    fn test_stateful_codec() {
+        let encoding = Encoding::register("test-shift", Shift::default).unwrap();
+        let text = "Hello, WORLD and Rust";
+        let bytes = b"\x0eh\x0fello, \x0eworld \x0fand \x0er\x0fust";
+        assert_eq!(encoding.encode(text), Ok(bytes.to_vec()));
+        assert_eq!(encoding.decode(bytes), Ok(text.to_string()));
+        // every stream starts unshifted, whatever the others did
+        for split in 0..=bytes.len() {
+            let mut decoder = IncrementalDecoder::new(encoding, Errors::Strict).unwrap();
+            let mut other = IncrementalDecoder::new(encoding, Errors::Strict).unwrap();
+            assert_eq!(other.decode(b"\x0e", false), Ok(String::new()));
+            let mut decoded = decoder.decode(&bytes[..split], false).unwrap();
+            decoded.push_str(&decoder.decode(&bytes[split..], true).unwrap());
+            assert_eq!(decoded, text, "split at {split}");
+        }
+        for split in 0..=text.len() {
+            let mut encoder = IncrementalEncoder::new(encoding, Errors::Strict).unwrap();
+            let mut encoded = encoder.encode(&text[..split], false).unwrap();
+            encoded.extend(encoder.encode(&text[split..], true).unwrap());
+            assert_eq!(encoded, bytes, "split at {split}");
+        }
+        // the error handler goes on with the state of the codec
+        let decoded = encoding.decode_with(b"\x0ea\xffb", Errors::Replace);
+        assert_eq!(decoded, Ok("A\u{fffd}B".into()));
+        assert!(Encoding::unregister("test_shift"));
+    }
+}
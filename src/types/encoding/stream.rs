@@ -0,0 +1,443 @@
+use super::{CodecError, Encoding, Errors, IncrementalDecoder, IncrementalEncoder, Wtf8Buf};
+use std::borrow::Cow;
+use std::io::{BufRead, Error, ErrorKind, Read, Result, Write};
+
+const CHUNK: usize = 8 * 1024;
+
+/// Line ending that [`EncodeWriter`] writes in place of `\n`.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Newline {
+    #[default]
+    Lf,
+    Cr,
+    CrLf,
+}
+
+impl Newline {
+    #[inline]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Lf => "\n",
+            Self::Cr => "\r",
+            Self::CrLf => "\r\n",
+        }
+    }
+}
+
+#[inline]
+fn invalid_data(e: CodecError) -> Error {
+    Error::new(ErrorKind::InvalidData, e)
+}
+
+/// Reads a byte stream in any text [`Encoding`] as UTF-8, like Python's `io.TextIOWrapper`
+/// opened for reading.
+///
+/// The inner reader is consumed in chunks of 8 KiB, so files of any size stream through a
+/// bounded buffer. Invalid input fails the read with [`ErrorKind::InvalidData`] wrapping the
+/// [`CodecError`], unless the error handler resolves it; lone surrogates, like those of
+/// [`Errors::SurrogateEscape`], come out as `U+FFFD`.
+///
+/// With universal newlines enabled, `\r\n` and `\r` are read as `\n`.
+///
+/// # Example
+///
+/// ```rust
+/// use rsutil::types::{DecodeReader, Encoding, Errors};
+/// use std::io::BufRead;
+///
+/// let raw: &[u8] = b"a\x00\r\x00\n\x00\xe9\x00\r\x00";
+/// let mut reader = DecodeReader::new(raw, Encoding::Utf16le, Errors::Strict).unwrap();
+/// reader.set_universal_newlines(true);
+/// let lines = reader.lines().collect::<Result<Vec<_>, _>>().unwrap();
+/// assert_eq!(lines, ["a", "\u{e9}"]);
+/// ```
+#[derive(Debug)]
+pub struct DecodeReader<R> {
+    inner: R,
+    decoder: IncrementalDecoder,
+    chunk: Box<[u8]>,
+    output: Vec<u8>,
+    pos: usize,
+    eof: bool,
+    universal: bool,
+    cr: bool,
+}
+
+impl<R: Read> DecodeReader<R> {
+    pub fn new(
+        inner: R,
+        encoding: Encoding,
+        errors: Errors,
+    ) -> std::result::Result<Self, CodecError> {
+        Ok(Self {
+            inner,
+            decoder: IncrementalDecoder::new(encoding, errors)?,
+            chunk: vec![0; CHUNK].into_boxed_slice(),
+            output: Vec::new(),
+            pos: 0,
+            eof: false,
+            universal: false,
+            cr: false,
+        })
+    }
+
+    #[inline]
+    pub fn encoding(&self) -> Encoding {
+        self.decoder.encoding()
+    }
+
+    /// Translates `\r\n` and `\r` to `\n` when `universal` is true.
+    #[inline]
+    pub fn set_universal_newlines(&mut self, universal: bool) -> &mut Self {
+        self.universal = universal;
+        self
+    }
+
+    #[inline]
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Unwraps the inner reader; decoded text not read yet is lost.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Reads and decodes chunks until some text comes out or the stream ends.
+    fn refill(&mut self) -> Result<()> {
+        self.output.clear();
+        self.pos = 0;
+        while self.output.is_empty() && !self.eof {
+            let len = match self.inner.read(&mut self.chunk) {
+                Ok(len) => len,
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+            self.eof = len == 0;
+            let mut text = Wtf8Buf::new();
+            self.decoder
+                .decode_into(&self.chunk[..len], &mut text, self.eof)
+                .map_err(invalid_data)?;
+            let text = text.into_string_lossy();
+            match self.universal {
+                true => self.translate(&text),
+                false => self.output.extend_from_slice(text.as_bytes()),
+            }
+        }
+        Ok(())
+    }
+
+    /// Appends `text` with universal newlines; a `\n` right after a `\r` ending the previous
+    /// chunk is dropped.
+    fn translate(&mut self, text: &str) {
+        for &b in text.as_bytes() {
+            match (b, self.cr) {
+                (b'\n', true) => {}
+                (b'\r', _) => self.output.push(b'\n'),
+                _ => self.output.push(b),
+            }
+            self.cr = b == b'\r';
+        }
+    }
+}
+
+impl<R: Read> Read for DecodeReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        let available = self.fill_buf()?;
+        let len = available.len().min(buf.len());
+        buf[..len].copy_from_slice(&available[..len]);
+        self.consume(len);
+        Ok(len)
+    }
+}
+
+impl<R: Read> BufRead for DecodeReader<R> {
+    fn fill_buf(&mut self) -> Result<&[u8]> {
+        if self.pos == self.output.len() {
+            self.refill()?;
+        }
+        Ok(&self.output[self.pos..])
+    }
+
+    #[inline]
+    fn consume(&mut self, amt: usize) {
+        self.pos = (self.pos + amt).min(self.output.len());
+    }
+}
+
+/// Writes UTF-8 text to a byte stream in any text [`Encoding`], like Python's
+/// `io.TextIOWrapper` opened for writing.
+///
+/// Writes may split UTF-8 sequences anywhere; an incomplete tail is kept for the next write.
+/// Text that can't be encoded fails the write with [`ErrorKind::InvalidData`], unless the error
+/// handler resolves it. Call [`EncodeWriter::finish`] at the end, so that an incomplete tail is
+/// reported and stateful encodings return to their initial state.
+///
+/// Like a [`BufWriter`](std::io::BufWriter), the writer keeps the encoded bytes the inner
+/// writer fails to take and writes them out first on the next write, flush or finish, so a
+/// failing inner writer never makes text come out twice.
+///
+/// # Example
+///
+/// ```rust
+/// use rsutil::types::{EncodeWriter, Encoding, Errors, Newline};
+/// use std::io::Write;
+///
+/// let mut writer = EncodeWriter::new(Vec::new(), Encoding::Utf16be, Errors::Strict).unwrap();
+/// writer.set_newline(Newline::CrLf);
+/// writeln!(writer, "a").unwrap();
+/// let raw = writer.finish().unwrap();
+/// assert_eq!(raw, b"\x00a\x00\r\x00\n");
+/// ```
+#[derive(Debug)]
+pub struct EncodeWriter<W> {
+    inner: W,
+    encoder: IncrementalEncoder,
+    pending: Vec<u8>,
+    output: Vec<u8>,
+    newline: Newline,
+}
+
+impl<W: Write> EncodeWriter<W> {
+    pub fn new(
+        inner: W,
+        encoding: Encoding,
+        errors: Errors,
+    ) -> std::result::Result<Self, CodecError> {
+        Ok(Self {
+            inner,
+            encoder: IncrementalEncoder::new(encoding, errors)?,
+            pending: Vec::new(),
+            output: Vec::new(),
+            newline: Newline::default(),
+        })
+    }
+
+    #[inline]
+    pub fn encoding(&self) -> Encoding {
+        self.encoder.encoding()
+    }
+
+    /// Writes `newline` for every `\n`.
+    #[inline]
+    pub fn set_newline(&mut self, newline: Newline) -> &mut Self {
+        self.newline = newline;
+        self
+    }
+
+    #[inline]
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Encodes `text` into the bytes waiting for the inner writer; nothing is added if it
+    /// fails.
+    fn encode(&mut self, text: &str, last: bool) -> Result<()> {
+        let start = self.output.len();
+        let result = match self.newline {
+            Newline::Lf => self.encoder.encode_into(text, &mut self.output, last),
+            newline => {
+                let text = text.replace('\n', newline.as_str());
+                self.encoder.encode_into(&text, &mut self.output, last)
+            }
+        };
+        result.map_err(|e| {
+            self.output.truncate(start);
+            invalid_data(e)
+        })
+    }
+
+    /// Writes out the encoded bytes, keeping those the inner writer didn't take when it fails.
+    fn write_output(&mut self) -> Result<()> {
+        let mut written = 0;
+        let result = loop {
+            if written == self.output.len() {
+                break Ok(());
+            }
+            match self.inner.write(&self.output[written..]) {
+                Ok(0) => break Err(ErrorKind::WriteZero.into()),
+                Ok(len) => written += len,
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => break Err(e),
+            }
+        };
+        let _ = self.output.drain(..written);
+        result
+    }
+
+    /// Ends the text, flushes and returns the inner writer.
+    pub fn finish(mut self) -> Result<W> {
+        if !self.pending.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "stream did not end on a UTF-8 character boundary",
+            ));
+        }
+        self.encode("", true)?;
+        self.write_output()?;
+        self.inner.flush()?;
+        Ok(self.inner)
+    }
+}
+
+impl<W: Write> Write for EncodeWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        // bytes left over by a failed write go first, and until they do none of `buf` is taken
+        self.write_output()?;
+        // the buffered tail is only replaced once the text is encoded, so a retry still has it
+        let data = match self.pending.is_empty() {
+            true => Cow::Borrowed(buf),
+            false => Cow::Owned([self.pending.as_slice(), buf].concat()),
+        };
+        let (text, rest) = match std::str::from_utf8(&data) {
+            Ok(text) => (text, &[][..]),
+            Err(e) if e.error_len().is_none() => {
+                let (text, rest) = data.split_at(e.valid_up_to());
+                // the head was just validated
+                (std::str::from_utf8(text).unwrap_or_default(), rest)
+            }
+            Err(e) => return Err(Error::new(ErrorKind::InvalidData, e)),
+        };
+        self.encode(text, false)?;
+        self.pending.clear();
+        self.pending.extend_from_slice(rest);
+        // `buf` is taken once encoded, like `BufWriter` takes it; the bytes the inner writer
+        // refuses stay buffered and its error comes back from the next write, flush or finish
+        let _ = self.write_output();
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        self.write_output()?;
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Takes one byte per read.
+    struct Trickle<'a>(&'a [u8]);
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+            let len = self.0.len().min(buf.len()).min(1);
+            buf[..len].copy_from_slice(&self.0[..len]);
+            self.0 = &self.0[len..];
+            Ok(len)
+        }
+    }
+
+    /// Takes one byte per write and fails every other write.
+    #[derive(Default)]
+    struct Flaky {
+        data: Vec<u8>,
+        fail: bool,
+    }
+
+    impl Write for Flaky {
+        fn write(&mut self, buf: &[u8]) -> Result<usize> {
+            self.fail = !self.fail;
+            if !self.fail {
+                return Err(Error::other("flaky"));
+            }
+            self.data.extend_from_slice(&buf[..buf.len().min(1)]);
+            Ok(buf.len().min(1))
+        }
+
+        fn flush(&mut self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    /// Reads `raw` to the end one byte at a time.
+    fn read(raw: &[u8], encoding: Encoding, errors: Errors, universal: bool) -> Result<String> {
+        let mut reader = DecodeReader::new(Trickle(raw), encoding, errors).map_err(invalid_data)?;
+        let mut text = String::new();
+        let _ = reader
+            .set_universal_newlines(universal)
+            .read_to_string(&mut text)?;
+        Ok(text)
+    }
+
+    #[test]
+    fn test_read_newlines() {
+        let raw = "a\r\nb\rc\n\r\r\n\u{e9}\r";
+        let text = read(raw.as_bytes(), Encoding::Utf8, Errors::Strict, false);
+        assert_eq!(text.unwrap(), raw);
+        // each `\r` arrives in a chunk of its own, so the `\n` after it is in the next one
+        let text = read(raw.as_bytes(), Encoding::Utf8, Errors::Strict, true);
+        assert_eq!(text.unwrap(), "a\nb\nc\n\n\n\u{e9}\n");
+        let raw = b"a\x00\r\x00\n\x00\r\x00";
+        let text = read(raw, Encoding::Utf16le, Errors::Strict, true);
+        assert_eq!(text.unwrap(), "a\n\n");
+    }
+
+    #[test]
+    fn test_read_errors() {
+        let err = read(b"ab\xff", Encoding::Utf8, Errors::Strict, false).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+        assert!(matches!(
+            err.get_ref().and_then(|e| e.downcast_ref()),
+            Some(CodecError::Decode {
+                start: 0,
+                end: 1,
+                ..
+            })
+        ));
+        let text = read(b"ab\xff", Encoding::Utf8, Errors::Replace, false);
+        assert_eq!(text.unwrap(), "ab\u{fffd}");
+    }
+
+    #[test]
+    fn test_write_keeps_pending_on_error() {
+        let mut writer = EncodeWriter::new(Vec::new(), Encoding::Latin1, Errors::Strict).unwrap();
+        assert_eq!(writer.write(b"a\xc3").unwrap(), 2);
+        assert_eq!(
+            writer.write(b"\xff").unwrap_err().kind(),
+            ErrorKind::InvalidData
+        );
+        // the lead byte of "é" survived the failed write
+        assert_eq!(writer.write(b"\xa9").unwrap(), 1);
+        assert_eq!(writer.finish().unwrap(), b"a\xe9");
+
+        // complete text that can't be encoded fails without leaving anything behind
+        let mut writer = EncodeWriter::new(Vec::new(), Encoding::Ascii, Errors::Strict).unwrap();
+        assert_eq!(writer.write(b"a").unwrap(), 1);
+        let err = writer.write("b\u{e9}".as_bytes()).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+        assert_eq!(
+            err.to_string(),
+            "'ascii' codec can't encode character '\\xe9' in position 1: ordinal not in range(128)"
+        );
+        assert_eq!(writer.write(b"c").unwrap(), 1);
+        assert_eq!(writer.finish().unwrap(), b"ac");
+    }
+
+    #[test]
+    fn test_write_keeps_output_on_error() {
+        let mut writer =
+            EncodeWriter::new(Flaky::default(), Encoding::Utf16be, Errors::Strict).unwrap();
+        // "a" goes out before the inner writer fails; the rest waits for the next call
+        assert_eq!(writer.write(b"abc").unwrap(), 3);
+        assert_eq!(writer.get_ref().data, b"\x00");
+        assert!(writer.write(b"d").is_err());
+        assert_eq!(writer.get_ref().data, b"\x00a");
+        while writer.flush().is_err() {}
+        assert_eq!(writer.write(b"d").unwrap(), 1);
+        while writer.flush().is_err() {}
+        assert_eq!(writer.finish().unwrap().data, b"\x00a\x00b\x00c\x00d");
+    }
+}
@@ -0,0 +1,335 @@
+use super::charmap::single_byte;
+use super::{CodecError, Encoding, Errors, IncrementalDecoder, IncrementalEncoder, Wtf8Buf};
+use std::fmt::{Debug, Formatter};
+use std::sync::{Arc, PoisonError, RwLock};
+
+/// The byte of the target encoding for each byte of the source, if the character it stands
+/// for exists in both.
+type ByteTable = [Option<u8>; 256];
+
+/// The tables built so far, shared by every transcoder of the same pair.
+static TABLES: RwLock<Vec<(Encoding, Encoding, Arc<ByteTable>)>> = RwLock::new(Vec::new());
+
+/// The byte table between two single-byte encodings, built on first use.
+fn byte_table(from: Encoding, to: Encoding) -> Result<Option<Arc<ByteTable>>, CodecError> {
+    if !single_byte(from) || !single_byte(to) {
+        return Ok(None);
+    }
+    let cached = TABLES
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .iter()
+        .find(|&&(f, t, _)| f == from && t == to)
+        .map(|(_, _, table)| table.clone());
+    if cached.is_some() {
+        return Ok(cached);
+    }
+    let mut decoder = from.decoder()?;
+    let mut encoder = to.encoder()?;
+    let mut table = [None; 256];
+    let mut text = Wtf8Buf::new();
+    let mut bytes = Vec::new();
+    for (byte, entry) in table.iter_mut().enumerate() {
+        text.clear();
+        bytes.clear();
+        if decoder.decode(&[byte as u8], &mut text, true).is_ok()
+            && encoder.encode(&text, &mut bytes, true).is_ok()
+        {
+            if let [byte] = bytes[..] {
+                *entry = Some(byte);
+            }
+        }
+    }
+    let table = Arc::new(table);
+    TABLES
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .push((from, to, table.clone()));
+    Ok(Some(table))
+}
+
+impl Encoding {
+    /// Converts `input` from the text encoding `from` to `to`, resolving invalid input with
+    /// `errors` on both sides.
+    ///
+    /// Between two single-byte encodings every byte maps through a table built once for the
+    /// pair; any other pair decodes into a scratch buffer instead of a `String`. To convert
+    /// many records, keep a [`Transcoder`], which also reuses that buffer. Errors are those
+    /// of decoding `input` with `from` or of encoding the decoded text with `to`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rsutil::types::{CodecError, Encoding, Errors};
+    ///
+    /// let sjis = Encoding::transcode(Encoding::Cp1252, Encoding::ShiftJis, b"\xa5100", Errors::Strict);
+    /// assert_eq!(sjis, Ok(b"\x5c100".to_vec()));
+    /// assert_eq!(
+    ///     Encoding::transcode(Encoding::ShiftJis, Encoding::Cp1252, b"\x82\xa0!", Errors::Replace),
+    ///     Ok(b"?!".to_vec())
+    /// );
+    ///
+    /// // a byte table between single-byte code pages
+    /// assert_eq!(
+    ///     Encoding::transcode(Encoding::Cp1251, Encoding::Koi8R, b"\xcc\xe8\xf0", Errors::Strict),
+    ///     Ok(b"\xed\xc9\xd2".to_vec())
+    /// );
+    /// let err = Encoding::transcode(Encoding::Cp1252, Encoding::Ascii, b"caf\xe9", Errors::Strict);
+    /// assert!(matches!(err, Err(CodecError::Encode { encoding: Encoding::Ascii, start: 3, .. })));
+    /// ```
+    pub fn transcode(
+        from: Self,
+        to: Self,
+        input: &[u8],
+        errors: Errors,
+    ) -> Result<Vec<u8>, CodecError> {
+        Transcoder::new(from, to, errors)?.transcode(input, true)
+    }
+}
+
+/// Converts a stream from one text encoding to another, fed with chunks like an
+/// [`IncrementalDecoder`].
+///
+/// A sequence cut by a chunk boundary is kept until the next call completes it, and the
+/// shift states of both encodings carry over; pass `last = true` with the final chunk. Decode
+/// errors have positions relative to the buffered tail followed by the chunk, and encode
+/// errors character positions in the text decoded from them.
+///
+/// Between two single-byte encodings the bytes go through a table. Otherwise the chunk is
+/// decoded into a buffer the transcoder keeps, so converting many records with one
+/// transcoder doesn't allocate text for each of them.
+///
+/// # Example
+///
+/// ```rust
+/// use rsutil::types::{Encoding, Errors, Transcoder};
+///
+/// let mut transcoder = Transcoder::new(Encoding::Utf8, Encoding::EucJp, Errors::Strict).unwrap();
+/// // "日" is e6 97 a5, split over two chunks
+/// assert_eq!(transcoder.transcode(b"a\xe6\x97", false), Ok(b"a".to_vec()));
+/// assert_eq!(transcoder.transcode(b"\xa5", true), Ok(b"\xc6\xfc".to_vec()));
+///
+/// let mut transcoder = Transcoder::new(Encoding::Cp1252, Encoding::Cp850, Errors::Strict).unwrap();
+/// let mut output = Vec::new();
+/// for record in [&b"na\xefve"[..], b"\xa3 5"] {
+///     output.clear();
+///     transcoder.transcode_into(record, &mut output, true).unwrap();
+/// }
+/// assert_eq!(output, b"\x9c 5");
+/// ```
+pub struct Transcoder {
+    decoder: IncrementalDecoder,
+    encoder: IncrementalEncoder,
+    table: Option<Arc<ByteTable>>,
+    text: Wtf8Buf,
+}
+
+impl Transcoder {
+    pub fn new(from: Encoding, to: Encoding, errors: Errors) -> Result<Self, CodecError> {
+        Ok(Self {
+            decoder: IncrementalDecoder::new(from, errors)?,
+            encoder: IncrementalEncoder::new(to, errors)?,
+            table: byte_table(from, to)?,
+            text: Wtf8Buf::new(),
+        })
+    }
+
+    /// The encoding of the input.
+    #[inline]
+    pub fn source(&self) -> Encoding {
+        self.decoder.encoding()
+    }
+
+    /// The encoding of the output.
+    #[inline]
+    pub fn target(&self) -> Encoding {
+        self.encoder.encoding()
+    }
+
+    #[inline]
+    pub fn errors(&self) -> Errors {
+        self.decoder.errors()
+    }
+
+    /// Converts the next chunk.
+    pub fn transcode(&mut self, input: &[u8], last: bool) -> Result<Vec<u8>, CodecError> {
+        let mut output = Vec::with_capacity(input.len());
+        self.transcode_into(input, &mut output, last)?;
+        Ok(output)
+    }
+
+    /// Converts the next chunk, appending the bytes to `output`; on an error nothing is
+    /// appended.
+    pub fn transcode_into(
+        &mut self,
+        input: &[u8],
+        output: &mut Vec<u8>,
+        last: bool,
+    ) -> Result<(), CodecError> {
+        if let Some(table) = &self.table {
+            let start = output.len();
+            output.reserve(input.len());
+            match input
+                .iter()
+                .try_for_each(|&b| table[b as usize].map(|byte| output.push(byte)).ok_or(()))
+            {
+                Ok(()) => return Ok(()),
+                // a byte without a match goes through the codecs and the error handler
+                Err(()) => output.truncate(start),
+            }
+        }
+        self.text.clear();
+        self.decoder.decode_into(input, &mut self.text, last)?;
+        let start = output.len();
+        self.encoder
+            .encode_into(&self.text, output, last)
+            .inspect_err(|_| output.truncate(start))
+    }
+
+    /// Drops any buffered input and state, like a freshly created transcoder.
+    pub fn reset(&mut self) -> Result<(), CodecError> {
+        self.decoder.reset()?;
+        self.encoder.reset()
+    }
+}
+
+impl Debug for Transcoder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Transcoder")
+            .field("decoder", &self.decoder)
+            .field("encoder", &self.encoder)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::names::encodings;
+    use super::*;
+
+    const ERRORS: [Errors; 8] = [
+        Errors::Strict,
+        Errors::Ignore,
+        Errors::Replace,
+        Errors::BackslashReplace,
+        Errors::XmlCharRefReplace,
+        Errors::NameReplace,
+        Errors::SurrogateEscape,
+        Errors::SurrogatePass,
+    ];
+
+    /// Decodes then encodes, without a byte table.
+    fn reference(
+        from: Encoding,
+        to: Encoding,
+        input: &[u8],
+        errors: Errors,
+    ) -> Result<Vec<u8>, CodecError> {
+        let mut text = Wtf8Buf::new();
+        IncrementalDecoder::new(from, errors)?.decode_into(input, &mut text, true)?;
+        let mut output = Vec::new();
+        IncrementalEncoder::new(to, errors)?.encode_into(&text, &mut output, true)?;
+        Ok(output)
+    }
+
+    #[test]
+    fn test_byte_tables() {
+        let single = encodings()
+            .into_iter()
+            .filter(|&e| single_byte(e))
+            .collect::<Vec<_>>();
+        let all = (0..=255).collect::<Vec<u8>>();
+        for &from in &single {
+            for &to in &single {
+                let table = byte_table(from, to).unwrap().unwrap();
+                // the bytes the table maps; strict fails at the first byte it doesn't
+                let mapped = all
+                    .iter()
+                    .copied()
+                    .filter(|&b| table[b as usize].is_some())
+                    .collect::<Vec<_>>();
+                let inputs = ERRORS.map(|errors| (errors, &all)).into_iter();
+                for (errors, input) in inputs.chain([(Errors::Strict, &mapped)]) {
+                    assert_eq!(
+                        Encoding::transcode(from, to, input, errors),
+                        reference(from, to, input, errors),
+                        "{from} to {to} with {}",
+                        errors.name()
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_chunks() {
+        let text = "a\u{65e5}\u{672c}\u{8a9e}\u{3042}b\n";
+        let pairs = [
+            (Encoding::Utf8, Encoding::ShiftJis),
+            (Encoding::Utf8, Encoding::Iso2022Jp),
+            (Encoding::Iso2022Jp, Encoding::EucJp),
+            (Encoding::Utf16le, Encoding::Utf8),
+            (Encoding::Cp932, Encoding::Utf32),
+        ];
+        for (from, to) in pairs {
+            let input = from.encode(text).unwrap();
+            let expected = to.encode(text).unwrap();
+            assert_eq!(
+                Encoding::transcode(from, to, &input, Errors::Strict),
+                Ok(expected.clone())
+            );
+            for i in 0..=input.len() {
+                for j in i..=input.len() {
+                    let mut transcoder = Transcoder::new(from, to, Errors::Strict).unwrap();
+                    let mut output = transcoder.transcode(&input[..i], false).unwrap();
+                    transcoder
+                        .transcode_into(&input[i..j], &mut output, false)
+                        .unwrap();
+                    transcoder
+                        .transcode_into(&input[j..], &mut output, true)
+                        .unwrap();
+                    assert_eq!(output, expected, "{from} to {to} split at {i} and {j}");
+                }
+            }
+        }
+
+        // a table with a missing byte in the middle of a chunk
+        let mut transcoder =
+            Transcoder::new(Encoding::Cp1252, Encoding::Cp1251, Errors::Replace).unwrap();
+        let mut output = transcoder.transcode(b"\x80a", false).unwrap();
+        transcoder
+            .transcode_into(b"\xe9b", &mut output, true)
+            .unwrap();
+        assert_eq!(output, b"\x88a?b");
+    }
+
+    #[test]
+    fn test_error_appends_nothing() {
+        let mut transcoder =
+            Transcoder::new(Encoding::Utf8, Encoding::Ascii, Errors::Strict).unwrap();
+        let mut output = b"kept".to_vec();
+        let result = transcoder.transcode_into("ab\u{e9}".as_bytes(), &mut output, true);
+        assert!(matches!(
+            result,
+            Err(CodecError::Encode {
+                start: 2,
+                end: 3,
+                ..
+            })
+        ));
+        assert_eq!(output, b"kept");
+
+        let mut transcoder =
+            Transcoder::new(Encoding::Cp1252, Encoding::Ascii, Errors::Strict).unwrap();
+        let result = transcoder.transcode_into(b"ab\xe9", &mut output, true);
+        assert!(matches!(
+            result,
+            Err(CodecError::Encode {
+                start: 2,
+                end: 3,
+                ..
+            })
+        ));
+        assert_eq!(output, b"kept");
+    }
+}
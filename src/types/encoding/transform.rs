@@ -0,0 +1,290 @@
+//! The bytes-to-bytes codecs, ports of Python's `base64_codec`, `hex_codec`, `quopri_codec`,
+//! `uu_codec` and `rot_13` with the `binascii` functions behind them.
+
+pub(crate) const BASE64: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const HEX: &[u8; 16] = b"0123456789abcdef";
+const HEX_UPPER: &[u8; 16] = b"0123456789ABCDEF";
+
+/// Input bytes per line of `base64.encodebytes`, which makes lines of 76 characters.
+const BASE64_LINE: usize = 57;
+/// Input bytes per line of a uuencoded file.
+const UU_LINE: usize = 45;
+/// The longest line quoted-printable writes, not counting the newline.
+const QP_LINE: usize = 76;
+
+#[inline]
+fn hex_value(c: u8) -> Option<u8> {
+    (c as char).to_digit(16).map(|d| d as u8)
+}
+
+#[inline]
+pub(crate) fn base64_value(c: u8) -> Option<u8> {
+    match c {
+        b'A'..=b'Z' => Some(c - b'A'),
+        b'a'..=b'z' => Some(c - b'a' + 26),
+        b'0'..=b'9' => Some(c - b'0' + 52),
+        b'+' => Some(62),
+        b'/' => Some(63),
+        _ => None,
+    }
+}
+
+/// Splits `group`, up to 3 bytes, into four 6-bit values, padding with zero bits.
+#[inline]
+fn sextets(group: &[u8]) -> [u8; 4] {
+    let mut bytes = [0; 4];
+    bytes[1..=group.len()].copy_from_slice(group);
+    let bits = u32::from_be_bytes(bytes);
+    [18, 12, 6, 0].map(|shift| (bits >> shift & 0x3F) as u8)
+}
+
+/// `base64.encodebytes`: lines of 76 characters, each ending with a newline.
+pub(crate) fn base64_encode(input: &[u8]) -> Vec<u8> {
+    let mut output = Vec::with_capacity(input.len().div_ceil(BASE64_LINE) * 77);
+    for line in input.chunks(BASE64_LINE) {
+        for group in line.chunks(3) {
+            let sextets = sextets(group);
+            for (i, &sextet) in sextets.iter().enumerate() {
+                output.push(match i <= group.len() {
+                    true => BASE64[sextet as usize],
+                    false => b'=',
+                });
+            }
+        }
+        output.push(b'\n');
+    }
+    output
+}
+
+/// `base64.decodebytes`, which skips the characters outside the alphabet and stops at the
+/// padding.
+pub(crate) fn base64_decode(input: &[u8]) -> Result<Vec<u8>, String> {
+    let mut output = Vec::with_capacity(input.len() / 4 * 3 + 2);
+    let (mut quad, mut pads, mut left) = (0, 0, 0u8);
+    for &c in input {
+        if c == b'=' {
+            if quad >= 2 {
+                pads += 1;
+                if quad + pads >= 4 {
+                    return Ok(output);
+                }
+            }
+            continue;
+        }
+        let Some(value) = base64_value(c) else {
+            continue;
+        };
+        pads = 0;
+        match quad {
+            0 => left = value,
+            1 => {
+                output.push(left << 2 | value >> 4);
+                left = value & 0x0F;
+            }
+            2 => {
+                output.push(left << 4 | value >> 2);
+                left = value & 0x03;
+            }
+            _ => output.push(left << 6 | value),
+        }
+        quad = (quad + 1) % 4;
+    }
+    match quad {
+        0 => Ok(output),
+        1 => Err(format!(
+            "Invalid base64-encoded string: number of data characters ({}) cannot be 1 more \
+             than a multiple of 4",
+            output.len() / 3 * 4 + 1
+        )),
+        _ => Err("Incorrect padding".to_string()),
+    }
+}
+
+pub(crate) fn hex_encode(input: &[u8]) -> Vec<u8> {
+    input
+        .iter()
+        .flat_map(|&c| [HEX[(c >> 4) as usize], HEX[(c & 0x0F) as usize]])
+        .collect()
+}
+
+pub(crate) fn hex_decode(input: &[u8]) -> Result<Vec<u8>, String> {
+    if !input.len().is_multiple_of(2) {
+        return Err("Odd-length string".to_string());
+    }
+    input
+        .chunks(2)
+        .map(|pair| Some(hex_value(pair[0])? << 4 | hex_value(pair[1])?))
+        .collect::<Option<_>>()
+        .ok_or_else(|| "Non-hexadecimal digit found".to_string())
+}
+
+/// Quoted-printable with tabs and spaces quoted, as `quopri.encodestring(input, True)`. The
+/// newlines are written as `\r\n` if the first one in the input is.
+pub(crate) fn quopri_encode(input: &[u8]) -> Vec<u8> {
+    let crlf = input
+        .iter()
+        .position(|&c| c == b'\n')
+        .is_some_and(|i| i > 0 && input[i - 1] == b'\r');
+    let newline: &[u8] = if crlf { b"\r\n" } else { b"\n" };
+    let mut output = Vec::with_capacity(input.len());
+    let (mut i, mut len) = (0, 0);
+    while i < input.len() {
+        let c = input[i];
+        let next = input.get(i + 1).copied();
+        let quote = match c {
+            b'=' | 0x7F.. => true,
+            // a lone dot would end an SMTP message
+            b'.' => len == 0 && matches!(next, None | Some(b'\n' | b'\r' | 0)),
+            b'\n' | b'\r' => false,
+            _ => c < b'!',
+        };
+        if quote {
+            if len + 3 >= QP_LINE {
+                output.push(b'=');
+                output.extend_from_slice(newline);
+                len = 0;
+            }
+            output.extend_from_slice(&[
+                b'=',
+                HEX_UPPER[(c >> 4) as usize],
+                HEX_UPPER[(c & 0x0F) as usize],
+            ]);
+            len += 3;
+            i += 1;
+        } else if c == b'\n' || (c == b'\r' && next == Some(b'\n')) {
+            output.extend_from_slice(newline);
+            len = 0;
+            i += if c == b'\r' { 2 } else { 1 };
+        } else {
+            if next.is_some_and(|next| next != b'\n') && len + 1 >= QP_LINE {
+                output.push(b'=');
+                output.extend_from_slice(newline);
+                len = 0;
+            }
+            output.push(c);
+            len += 1;
+            i += 1;
+        }
+    }
+    output
+}
+
+/// Decodes quoted-printable; a malformed `=` escape is kept as it is.
+pub(crate) fn quopri_decode(input: &[u8]) -> Vec<u8> {
+    let mut output = Vec::with_capacity(input.len());
+    let mut i = 0;
+    while i < input.len() {
+        let c = input[i];
+        i += 1;
+        if c != b'=' {
+            output.push(c);
+            continue;
+        }
+        match input.get(i) {
+            None => break,
+            // a soft line break, up to the next newline
+            Some(b'\n' | b'\r') => {
+                while i < input.len() && input[i] != b'\n' {
+                    i += 1;
+                }
+                i = (i + 1).min(input.len());
+            }
+            Some(b'=') => {
+                output.push(b'=');
+                i += 1;
+            }
+            Some(&hi) => match (
+                hex_value(hi),
+                input.get(i + 1).and_then(|&lo| hex_value(lo)),
+            ) {
+                (Some(hi), Some(lo)) => {
+                    output.push(hi << 4 | lo);
+                    i += 2;
+                }
+                _ => output.push(b'='),
+            },
+        }
+    }
+    output
+}
+
+/// A uuencoded file named `<data>` with mode 666, as Python writes it.
+pub(crate) fn uu_encode(input: &[u8]) -> Vec<u8> {
+    let mut output = Vec::with_capacity(input.len() / 3 * 4 + input.len() / UU_LINE + 32);
+    output.extend_from_slice(b"begin 666 <data>\n");
+    for line in input.chunks(UU_LINE) {
+        output.push(b' ' + line.len() as u8);
+        for group in line.chunks(3) {
+            output.extend(sextets(group).map(|sextet| b' ' + sextet));
+        }
+        output.push(b'\n');
+    }
+    output.extend_from_slice(b" \nend\n");
+    output
+}
+
+/// Decodes one line of a uuencoded file, `binascii.a2b_uu`.
+fn uu_decode_line(line: &[u8], output: &mut Vec<u8>) -> Result<(), &'static str> {
+    let Some((&len, data)) = line.split_first() else {
+        return Ok(());
+    };
+    let mut len = len.wrapping_sub(b' ') & 0x3F;
+    let mut data = data.iter();
+    let (mut bits, mut left) = (0, 0u32);
+    while len > 0 {
+        // the spaces eaten at the end of the line count as zeros
+        let sextet = match data.next() {
+            None | Some(b'\n' | b'\r') => 0,
+            // some encoders write '`' for zero
+            Some(&c @ b' '..=b'`') => (c - b' ') & 0x3F,
+            Some(_) => return Err("Illegal char"),
+        };
+        left = left << 6 | sextet as u32;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            output.push((left >> bits) as u8);
+            left &= (1 << bits) - 1;
+            len -= 1;
+        }
+    }
+    match data.all(|c| matches!(c, b' ' | b'`' | b'\n' | b'\r')) {
+        true => Ok(()),
+        false => Err("Trailing garbage"),
+    }
+}
+
+/// Decodes the lines after `begin` up to `end`; a line with trailing garbage is cut to its
+/// length byte, as Python does for broken encoders.
+pub(crate) fn uu_decode(input: &[u8]) -> Result<Vec<u8>, String> {
+    let mut lines = input.split_inclusive(|&c| c == b'\n');
+    if !lines.any(|line| line.starts_with(b"begin")) {
+        return Err("Missing \"begin\" line in input data".to_string());
+    }
+    let mut output = Vec::with_capacity(input.len() / 4 * 3);
+    for line in lines {
+        if line == b"end\n" {
+            return Ok(output);
+        }
+        let len = output.len();
+        if uu_decode_line(line, &mut output).is_err() {
+            output.truncate(len);
+            let chars = ((line[0].wrapping_sub(b' ') & 0x3F) as usize * 4 + 5) / 3;
+            uu_decode_line(&line[..chars.min(line.len())], &mut output)?;
+        }
+    }
+    Err("Truncated input data".to_string())
+}
+
+/// Rotates the ASCII letters by 13 places, its own inverse.
+pub(crate) fn rot13(input: &[u8]) -> Vec<u8> {
+    input
+        .iter()
+        .map(|&c| match c {
+            b'a'..=b'm' | b'A'..=b'M' => c + 13,
+            b'n'..=b'z' | b'N'..=b'Z' => c - 13,
+            _ => c,
+        })
+        .collect()
+}
@@ -0,0 +1,661 @@
+use super::codec::{run_fault, Decoder, Encoder, Fault, Form};
+use super::transform::{base64_value, BASE64};
+use super::{Wtf8, Wtf8Buf};
+use crate::types::{ByteOrder, Ordered};
+
+pub(crate) struct Utf8;
+
+impl Decoder for Utf8 {
+    fn decode(&mut self, input: &[u8], output: &mut Wtf8Buf, last: bool) -> Result<usize, Fault> {
+        match std::str::from_utf8(input) {
+            Ok(s) => {
+                output.push_str(s);
+                Ok(input.len())
+            }
+            Err(e) => {
+                let valid = e.valid_up_to();
+                // the prefix was just validated
+                output.push_str(std::str::from_utf8(&input[..valid]).unwrap_or_default());
+                match e.error_len() {
+                    None if !last => Ok(valid),
+                    // a truncated surrogate waits for the rest like Python, for `surrogatepass`
+                    Some(1) if !last && matches!(input[valid..], [0xED, 0xA0..=0xBF]) => Ok(valid),
+                    None => Err(Fault::new(valid, input.len(), "unexpected end of data")),
+                    Some(len) => {
+                        let reason = match input[valid] {
+                            0xC2..=0xF4 => "invalid continuation byte",
+                            _ => "invalid start byte",
+                        };
+                        Err(Fault::new(valid, valid + len, reason))
+                    }
+                }
+            }
+        }
+    }
+
+    fn form(&self) -> Option<Form> {
+        Some(Form::Utf8)
+    }
+}
+
+impl Encoder for Utf8 {
+    fn encode(&mut self, input: &Wtf8, output: &mut Vec<u8>, _last: bool) -> Result<usize, Fault> {
+        if input.as_str().is_none() {
+            let mut points = input.code_points();
+            while let Some(c) = points.next() {
+                if is_surrogate(c) {
+                    let start = points.offset() - 3;
+                    output.extend_from_slice(&input.as_bytes()[..start]);
+                    return Err(surrogates(input, start));
+                }
+            }
+        }
+        output.extend_from_slice(input.as_bytes());
+        Ok(input.len())
+    }
+
+    fn partial_escape(&self) -> bool {
+        true
+    }
+
+    fn form(&self) -> Option<Form> {
+        Some(Form::Utf8)
+    }
+}
+
+#[inline]
+fn is_surrogate(c: u32) -> bool {
+    (0xD800..=0xDFFF).contains(&c)
+}
+
+/// The fault of the UTF-8 encoder, which can't represent lone surrogates.
+fn surrogates(input: &Wtf8, start: usize) -> Fault {
+    run_fault(input, start, "surrogates not allowed", is_surrogate)
+}
+
+/// The UTF-16/32 encoders report lone surrogates one at a time.
+fn surrogate(start: usize) -> Fault {
+    Fault::new(start, start + 3, "surrogates not allowed")
+}
+
+/// Resolves the byte order of `utf-16`/`utf-32` from a leading BOM.
+///
+/// Python writes a BOM in native order when encoding and, when decoding, consumes a BOM at the
+/// very start or falls back to the native order.
+#[derive(Debug, Clone, Copy)]
+enum Bom {
+    /// A fixed order, no BOM is read or written.
+    Fixed(ByteOrder),
+    /// The order is not known yet.
+    Pending,
+    /// The order was resolved from the BOM or the BOM was written.
+    Resolved(ByteOrder),
+}
+
+impl Bom {
+    fn new(order: Option<ByteOrder>) -> Self {
+        order.map_or(Self::Pending, Self::Fixed)
+    }
+
+    fn order(&self) -> ByteOrder {
+        match self {
+            Self::Fixed(order) | Self::Resolved(order) => *order,
+            Self::Pending => ByteOrder::Native,
+        }
+    }
+
+    /// Detects the BOM at the head of `input`; returns its length, or `None` when more bytes
+    /// are needed to tell.
+    fn detect(&mut self, input: &[u8], le: &[u8], be: &[u8], last: bool) -> Option<usize> {
+        if !matches!(self, Self::Pending) {
+            return Some(0);
+        }
+        let (order, len) = if input.starts_with(le) {
+            (ByteOrder::Little, le.len())
+        } else if input.starts_with(be) {
+            (ByteOrder::Big, be.len())
+        } else if input.len() < le.len()
+            && !last
+            && (le.starts_with(input) || be.starts_with(input))
+        {
+            return None;
+        } else {
+            (ByteOrder::Native, 0)
+        };
+        *self = Self::Resolved(order);
+        Some(len)
+    }
+
+    /// Writes the native `bom` on first use; returns the order to encode with.
+    fn write(&mut self, output: &mut Vec<u8>, bom: &[u8]) -> ByteOrder {
+        if let Self::Pending = self {
+            output.extend_from_slice(bom);
+            *self = Self::Resolved(ByteOrder::Native);
+        }
+        self.order()
+    }
+}
+
+/// `utf-16`, `utf-16-le` and `utf-16-be`.
+pub(crate) struct Utf16(Bom);
+
+impl Utf16 {
+    pub fn new(order: Option<ByteOrder>) -> Self {
+        Self(Bom::new(order))
+    }
+}
+
+impl Decoder for Utf16 {
+    fn decode(&mut self, input: &[u8], output: &mut Wtf8Buf, last: bool) -> Result<usize, Fault> {
+        let Some(mut pos) = self.0.detect(input, &[0xFF, 0xFE], &[0xFE, 0xFF], last) else {
+            return Ok(0);
+        };
+        let order = self.0.order();
+        while pos < input.len() {
+            let rest = &input[pos..];
+            let Ok(unit) = order.read_u16(rest) else {
+                return match last {
+                    true => Err(Fault::new(pos, input.len(), "truncated data")),
+                    false => Ok(pos),
+                };
+            };
+            match unit {
+                0xD800..=0xDBFF => {
+                    let Ok(low) = order.read_u16(&rest[2..]) else {
+                        return match last {
+                            true => Err(Fault::new(pos, input.len(), "unexpected end of data")),
+                            false => Ok(pos),
+                        };
+                    };
+                    if !(0xDC00..=0xDFFF).contains(&low) {
+                        return Err(Fault::new(pos, pos + 2, "illegal UTF-16 surrogate"));
+                    }
+                    let ch = 0x10000 + ((unit as u32 - 0xD800) << 10) + (low as u32 - 0xDC00);
+                    output.push(char::from_u32(ch).unwrap_or_default());
+                    pos += 4;
+                }
+                0xDC00..=0xDFFF => return Err(Fault::new(pos, pos + 2, "illegal encoding")),
+                _ => {
+                    output.push(char::from_u32(unit as u32).unwrap_or_default());
+                    pos += 2;
+                }
+            }
+        }
+        Ok(pos)
+    }
+
+    fn form(&self) -> Option<Form> {
+        Some(Form::Utf16(self.0.order()))
+    }
+}
+
+impl Encoder for Utf16 {
+    fn encode(&mut self, input: &Wtf8, output: &mut Vec<u8>, _last: bool) -> Result<usize, Fault> {
+        let order = self.0.write(output, &0xFEFFu16.to_ne_bytes());
+        output.reserve(input.len() * 2);
+        let mut points = input.code_points();
+        while let Some(c) = points.next() {
+            let Some(ch) = char::from_u32(c) else {
+                return Err(surrogate(points.offset() - 3));
+            };
+            for unit in ch.encode_utf16(&mut [0; 2]) {
+                output.extend_from_slice(&unit.to_ordered(order));
+            }
+        }
+        Ok(input.len())
+    }
+
+    fn unit(&self) -> usize {
+        2
+    }
+
+    fn form(&self) -> Option<Form> {
+        Some(Form::Utf16(self.0.order()))
+    }
+}
+
+/// `utf-32`, `utf-32-le` and `utf-32-be`.
+pub(crate) struct Utf32(Bom);
+
+impl Utf32 {
+    pub fn new(order: Option<ByteOrder>) -> Self {
+        Self(Bom::new(order))
+    }
+}
+
+impl Decoder for Utf32 {
+    fn decode(&mut self, input: &[u8], output: &mut Wtf8Buf, last: bool) -> Result<usize, Fault> {
+        let Some(mut pos) = self
+            .0
+            .detect(input, &[0xFF, 0xFE, 0, 0], &[0, 0, 0xFE, 0xFF], last)
+        else {
+            return Ok(0);
+        };
+        let order = self.0.order();
+        while pos < input.len() {
+            let Ok(ch) = order.read_u32(&input[pos..]) else {
+                return match last {
+                    true => Err(Fault::new(pos, input.len(), "truncated data")),
+                    false => Ok(pos),
+                };
+            };
+            match ch {
+                0xD800..=0xDFFF => {
+                    return Err(Fault::new(
+                        pos,
+                        pos + 4,
+                        "code point in surrogate code point range(0xd800, 0xe000)",
+                    ))
+                }
+                0x110000.. => {
+                    return Err(Fault::new(
+                        pos,
+                        pos + 4,
+                        "code point not in range(0x110000)",
+                    ))
+                }
+                _ => output.push(char::from_u32(ch).unwrap_or_default()),
+            }
+            pos += 4;
+        }
+        Ok(pos)
+    }
+
+    fn form(&self) -> Option<Form> {
+        Some(Form::Utf32(self.0.order()))
+    }
+}
+
+impl Encoder for Utf32 {
+    fn encode(&mut self, input: &Wtf8, output: &mut Vec<u8>, _last: bool) -> Result<usize, Fault> {
+        let order = self.0.write(output, &0xFEFFu32.to_ne_bytes());
+        output.reserve(input.len() * 4);
+        let mut points = input.code_points();
+        while let Some(c) = points.next() {
+            if is_surrogate(c) {
+                return Err(surrogate(points.offset() - 3));
+            }
+            output.extend_from_slice(&c.to_ordered(order));
+        }
+        Ok(input.len())
+    }
+
+    fn unit(&self) -> usize {
+        4
+    }
+
+    fn form(&self) -> Option<Form> {
+        Some(Form::Utf32(self.0.order()))
+    }
+}
+
+/// UTF-7 of RFC 2152, where the characters outside the direct set are written in modified
+/// base64 between `+` and an optional `-`.
+///
+/// Like Python, the optional direct characters (set O: `!"#$%&*;<=>@[]^_`{|}`) and the
+/// whitespace are written directly, and the base64 runs may hold lone surrogates, which
+/// decode into the `Wtf8Buf` as they are.
+pub(crate) struct Utf7;
+
+impl Utf7 {
+    /// The characters written as themselves: sets D and O, and the whitespace.
+    #[inline]
+    fn is_direct(c: u32) -> bool {
+        matches!(c, 0x09 | 0x0A | 0x0D | 0x20..=0x7D) && c != b'+' as u32 && c != b'\\' as u32
+    }
+
+    /// Decodes the base64 run starting with the `+` at `start`; returns where it ends, or
+    /// `None` if more input is needed to tell.
+    fn decode_shift(
+        input: &[u8],
+        start: usize,
+        output: &mut Wtf8Buf,
+        last: bool,
+    ) -> Result<Option<usize>, Fault> {
+        // the run is decoded again with more input if it is cut off, so it's held back
+        let mut points = Vec::new();
+        let (mut bits, mut buffer, mut high) = (0, 0u32, None);
+        let mut pos = start + 1;
+        while pos < input.len() {
+            let c = input[pos];
+            let Some(value) = base64_value(c) else {
+                points.into_iter().for_each(|c| output.push_code_point(c));
+                let reason = if bits >= 6 {
+                    "partial character in shift sequence"
+                } else if buffer != 0 {
+                    "non-zero padding bits in shift sequence"
+                } else {
+                    // a pending high surrogate is kept unless the run ends on a non-ASCII byte
+                    if let Some(high) = high.filter(|_| c < 0x80) {
+                        output.push_code_point(high);
+                    }
+                    // the `-` ending the run is absorbed, any other character kept
+                    return Ok(Some(if c == b'-' { pos + 1 } else { pos }));
+                };
+                return Err(Fault::new(start, pos + 1, reason));
+            };
+            buffer = buffer << 6 | value as u32;
+            bits += 6;
+            pos += 1;
+            if bits < 16 {
+                continue;
+            }
+            bits -= 16;
+            let unit = buffer >> bits;
+            buffer &= (1 << bits) - 1;
+            if let Some(high) = high.take() {
+                if (0xDC00..=0xDFFF).contains(&unit) {
+                    points.push(0x10000 + ((high - 0xD800) << 10) + (unit - 0xDC00));
+                    continue;
+                }
+                points.push(high);
+            }
+            match unit {
+                0xD800..=0xDBFF => high = Some(unit),
+                _ => points.push(unit),
+            }
+        }
+        if !last {
+            return Ok(None);
+        }
+        points.into_iter().for_each(|c| output.push_code_point(c));
+        match high.is_some() || bits >= 6 || buffer != 0 {
+            true => Err(Fault::new(
+                start,
+                input.len(),
+                "unterminated shift sequence",
+            )),
+            false => Ok(Some(input.len())),
+        }
+    }
+}
+
+impl Decoder for Utf7 {
+    fn decode(&mut self, input: &[u8], output: &mut Wtf8Buf, last: bool) -> Result<usize, Fault> {
+        let mut pos = 0;
+        while pos < input.len() {
+            match (input[pos], input.get(pos + 1)) {
+                (b'+', Some(b'-')) => {
+                    output.push('+');
+                    pos += 2;
+                }
+                (b'+', Some(&c)) if base64_value(c).is_none() => {
+                    return Err(Fault::new(pos, pos + 2, "ill-formed sequence"))
+                }
+                (b'+', _) => match Self::decode_shift(input, pos, output, last)? {
+                    Some(end) => pos = end,
+                    None => return Ok(pos),
+                },
+                (c @ 0..=0x7F, _) => {
+                    output.push(c as char);
+                    pos += 1;
+                }
+                _ => return Err(Fault::new(pos, pos + 1, "unexpected special character")),
+            }
+        }
+        Ok(pos)
+    }
+}
+
+impl Encoder for Utf7 {
+    /// Every call ends its base64 run, as Python's incremental encoder does.
+    fn encode(&mut self, input: &Wtf8, output: &mut Vec<u8>, _last: bool) -> Result<usize, Fault> {
+        let (mut shifted, mut bits, mut buffer) = (false, 0, 0u32);
+        for c in input.code_points() {
+            if Self::is_direct(c) {
+                if shifted {
+                    if bits > 0 {
+                        output.push(BASE64[(buffer << (6 - bits) & 0x3F) as usize]);
+                        (bits, buffer) = (0, 0);
+                    }
+                    shifted = false;
+                    // a character that could continue the run needs the `-`
+                    if base64_value(c as u8).is_some() || c == b'-' as u32 {
+                        output.push(b'-');
+                    }
+                }
+                output.push(c as u8);
+                continue;
+            }
+            if !shifted {
+                if c == b'+' as u32 {
+                    output.extend_from_slice(b"+-");
+                    continue;
+                }
+                output.push(b'+');
+                shifted = true;
+            }
+            let mut units = [0; 2];
+            let units = match char::from_u32(c) {
+                Some(ch) => &*ch.encode_utf16(&mut units),
+                // a lone surrogate is written as it is
+                None => &[c as u16][..],
+            };
+            for &unit in units {
+                buffer = buffer << 16 | unit as u32;
+                bits += 16;
+                while bits >= 6 {
+                    bits -= 6;
+                    output.push(BASE64[(buffer >> bits & 0x3F) as usize]);
+                }
+                buffer &= (1 << bits) - 1;
+            }
+        }
+        if bits > 0 {
+            output.push(BASE64[(buffer << (6 - bits) & 0x3F) as usize]);
+        }
+        if shifted {
+            output.push(b'-');
+        }
+        Ok(input.len())
+    }
+}
+
+/// The base64 alphabet of IMAP's modified UTF-7, with `,` in place of `/`.
+const IMAP_BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+,";
+
+#[inline]
+fn imap_base64_value(c: u8) -> Option<u8> {
+    match c {
+        b',' => Some(63),
+        b'/' => None,
+        c => base64_value(c),
+    }
+}
+
+/// The modified UTF-7 of IMAP mailbox names, RFC 3501 §5.1.3: printable ASCII stands for
+/// itself except `&`, which is written `&-`, and the other characters are written in base64
+/// with `,` in place of `/` between `&` and a `-` that can't be left out.
+///
+/// Decoding is strict: a run must be closed, hold whole UTF-16 units and end on zero padding
+/// bits, and anything outside printable ASCII is a fault. Lone surrogates in a run decode into
+/// the `Wtf8Buf` as they are, like with `utf-7`.
+pub(crate) struct Imap4Utf7;
+
+impl Imap4Utf7 {
+    /// Decodes the base64 characters of a run into `output`.
+    fn decode_run(run: &[u8], output: &mut Wtf8Buf) -> Result<(), &'static str> {
+        let mut points = Vec::new();
+        let (mut bits, mut buffer, mut high) = (0, 0u32, None::<u32>);
+        for value in run.iter().filter_map(|&c| imap_base64_value(c)) {
+            buffer = buffer << 6 | value as u32;
+            bits += 6;
+            if bits < 16 {
+                continue;
+            }
+            bits -= 16;
+            let unit = buffer >> bits;
+            buffer &= (1 << bits) - 1;
+            if let Some(high) = high.take() {
+                if (0xDC00..=0xDFFF).contains(&unit) {
+                    points.push(0x10000 + ((high - 0xD800) << 10) + (unit - 0xDC00));
+                    continue;
+                }
+                points.push(high);
+            }
+            match unit {
+                0xD800..=0xDBFF => high = Some(unit),
+                _ => points.push(unit),
+            }
+        }
+        if bits >= 6 {
+            return Err("partial character in shift sequence");
+        }
+        if buffer != 0 {
+            return Err("non-zero padding bits in shift sequence");
+        }
+        points.extend(high);
+        points.into_iter().for_each(|c| output.push_code_point(c));
+        Ok(())
+    }
+
+    /// Writes the UTF-16 `units` as a base64 run, if there are any.
+    fn encode_run(units: &[u16], output: &mut Vec<u8>) {
+        if units.is_empty() {
+            return;
+        }
+        output.push(b'&');
+        let (mut bits, mut buffer) = (0, 0u32);
+        for &unit in units {
+            buffer = buffer << 16 | unit as u32;
+            bits += 16;
+            while bits >= 6 {
+                bits -= 6;
+                output.push(IMAP_BASE64[(buffer >> bits & 0x3F) as usize]);
+            }
+            buffer &= (1 << bits) - 1;
+        }
+        if bits > 0 {
+            output.push(IMAP_BASE64[(buffer << (6 - bits) & 0x3F) as usize]);
+        }
+        output.push(b'-');
+    }
+}
+
+impl Decoder for Imap4Utf7 {
+    fn decode(&mut self, input: &[u8], output: &mut Wtf8Buf, last: bool) -> Result<usize, Fault> {
+        let mut pos = 0;
+        while pos < input.len() {
+            match input[pos] {
+                b'&' => {
+                    let run = &input[pos + 1..];
+                    let len = match run.iter().position(|&c| imap_base64_value(c).is_none()) {
+                        Some(len) => len,
+                        None if !last => return Ok(pos),
+                        None => {
+                            let reason = "unterminated shift sequence";
+                            return Err(Fault::new(pos, input.len(), reason));
+                        }
+                    };
+                    let end = pos + len + 2;
+                    if run[len] != b'-' {
+                        return Err(Fault::new(pos, end, "ill-formed sequence"));
+                    }
+                    match len {
+                        0 => output.push('&'),
+                        _ => Self::decode_run(&run[..len], output)
+                            .map_err(|reason| Fault::new(pos, end, reason))?,
+                    }
+                    pos = end;
+                }
+                c @ 0x20..=0x7E => {
+                    output.push(c as char);
+                    pos += 1;
+                }
+                _ => return Err(Fault::new(pos, pos + 1, "unexpected special character")),
+            }
+        }
+        Ok(pos)
+    }
+}
+
+impl Encoder for Imap4Utf7 {
+    /// Every call ends its base64 run, so a name encoded in chunks may have more runs than
+    /// needed, which still decode to the same name.
+    fn encode(&mut self, input: &Wtf8, output: &mut Vec<u8>, _last: bool) -> Result<usize, Fault> {
+        let mut units = Vec::new();
+        for c in input.code_points() {
+            if !(0x20..=0x7E).contains(&c) {
+                let mut buf = [0; 2];
+                match char::from_u32(c) {
+                    Some(ch) => units.extend_from_slice(ch.encode_utf16(&mut buf)),
+                    // a lone surrogate is written as it is
+                    None => units.push(c as u16),
+                }
+                continue;
+            }
+            Self::encode_run(&units, output);
+            units.clear();
+            output.push(c as u8);
+            if c == b'&' as u32 {
+                output.push(b'-');
+            }
+        }
+        Self::encode_run(&units, output);
+        Ok(input.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{CodecError, Encoding, Errors, IncrementalDecoder};
+
+    #[test]
+    fn test_imap4_utf7() {
+        let encoding = Encoding::Imap4Utf7;
+        // the examples of RFC 3501 and RFC 2060
+        let cases = [
+            ("\u{e9}", &b"&AOk-"[..]),
+            (
+                "~peter/mail/\u{53f0}\u{5317}/\u{65e5}\u{672c}\u{8a9e}",
+                b"~peter/mail/&U,BTFw-/&ZeVnLIqe-",
+            ),
+            ("Tom & Jerry", b"Tom &- Jerry"),
+            ("\u{1f600}", b"&2D3eAA-"),
+            ("a\u{7f}b", b"a&AH8-b"),
+        ];
+        for (text, bytes) in cases {
+            assert_eq!(encoding.encode(text), Ok(bytes.to_vec()));
+            assert_eq!(encoding.decode(bytes), Ok(text.to_string()));
+        }
+    }
+
+    #[test]
+    fn test_imap4_utf7_faults() {
+        let fault = |input: &[u8]| match Encoding::Imap4Utf7.decode(input) {
+            Err(CodecError::Decode {
+                start, end, reason, ..
+            }) => (start, end, reason),
+            other => panic!("{other:?}"),
+        };
+        assert_eq!(fault(b"a&AOk"), (1, 5, "unterminated shift sequence"));
+        assert_eq!(fault(b"&AOk!"), (0, 5, "ill-formed sequence"));
+        // `/` is the alphabet of `utf-7`, not this one
+        assert_eq!(fault(b"&U/BTFw-"), (0, 3, "ill-formed sequence"));
+        assert_eq!(
+            fault(b"&AOl-"),
+            (0, 5, "non-zero padding bits in shift sequence")
+        );
+        assert_eq!(
+            fault(b"&AOkA-"),
+            (0, 6, "partial character in shift sequence")
+        );
+        assert_eq!(fault(b"a\tb"), (1, 2, "unexpected special character"));
+        assert_eq!(fault(b"\xc3\xa9"), (0, 1, "unexpected special character"));
+        assert_eq!(
+            Encoding::Imap4Utf7.decode_with(b"&AOk!x&-", Errors::Replace),
+            Ok("\u{fffd}x&".into())
+        );
+    }
+
+    #[test]
+    fn test_imap4_utf7_chunks() {
+        let input = b"&ZeVnLIqe-/&-&AOk-";
+        for split in 0..=input.len() {
+            let mut decoder = IncrementalDecoder::new(Encoding::Imap4Utf7, Errors::Strict).unwrap();
+            let mut text = decoder.decode(&input[..split], false).unwrap();
+            text.push_str(&decoder.decode(&input[split..], true).unwrap());
+            assert_eq!(text, "\u{65e5}\u{672c}\u{8a9e}/&\u{e9}", "split at {split}");
+        }
+    }
+}
@@ -0,0 +1,332 @@
+use std::borrow::{Borrow, Cow};
+use std::fmt::{Debug, Display, Formatter, Result, Write};
+use std::ops::Deref;
+
+/// A borrowed WTF-8 string: UTF-8 that may also carry lone surrogates.
+///
+/// This is how Rust spells a Python `str`, which can hold code points like `U+DC80` produced
+/// by the `surrogateescape` error handler or the unpaired ones `surrogatepass` lets through
+/// UTF-16. Every `str` is valid WTF-8.
+#[derive(Eq, PartialEq, Hash)]
+#[repr(transparent)]
+pub struct Wtf8([u8]);
+
+impl Wtf8 {
+    #[inline]
+    pub fn new(s: &str) -> &Self {
+        Self::from_bytes_unchecked(s.as_bytes())
+    }
+
+    #[inline]
+    fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
+        // SAFETY: `Wtf8` is a `repr(transparent)` wrapper around `[u8]`
+        unsafe { &*(bytes as *const [u8] as *const Self) }
+    }
+
+    /// The generalized UTF-8 bytes, surrogates taking three bytes each.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Length in bytes.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The string as a `str`, or `None` if it holds a surrogate.
+    #[inline]
+    pub fn as_str(&self) -> Option<&str> {
+        std::str::from_utf8(&self.0).ok()
+    }
+
+    /// The string with every surrogate replaced by `U+FFFD`.
+    pub fn to_string_lossy(&self) -> Cow<'_, str> {
+        match self.as_str() {
+            Some(s) => Cow::Borrowed(s),
+            None => Cow::Owned(
+                self.code_points()
+                    .map(|c| char::from_u32(c).unwrap_or(char::REPLACEMENT_CHARACTER))
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Iterates over the code points, surrogates included.
+    #[inline]
+    pub fn code_points(&self) -> CodePoints<'_> {
+        CodePoints {
+            bytes: &self.0,
+            pos: 0,
+        }
+    }
+
+    /// Slices by byte offsets, which must lie on code point boundaries.
+    #[inline]
+    pub(crate) fn slice(&self, start: usize, end: usize) -> &Self {
+        Self::from_bytes_unchecked(&self.0[start..end])
+    }
+
+    /// Number of code points in `self[..end]`.
+    #[inline]
+    pub(crate) fn count_to(&self, end: usize) -> usize {
+        self.0[..end].iter().filter(|&&b| b & 0xC0 != 0x80).count()
+    }
+}
+
+impl Debug for Wtf8 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_char('"')?;
+        for c in self.code_points() {
+            match char::from_u32(c) {
+                Some(ch) => write!(f, "{}", ch.escape_debug())?,
+                None => write!(f, "\\u{{{c:x}}}")?,
+            }
+        }
+        f.write_char('"')
+    }
+}
+
+impl Display for Wtf8 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str(&self.to_string_lossy())
+    }
+}
+
+impl ToOwned for Wtf8 {
+    type Owned = Wtf8Buf;
+
+    fn to_owned(&self) -> Wtf8Buf {
+        Wtf8Buf {
+            bytes: self.0.to_vec(),
+        }
+    }
+}
+
+impl AsRef<Wtf8> for Wtf8 {
+    #[inline]
+    fn as_ref(&self) -> &Wtf8 {
+        self
+    }
+}
+
+impl AsRef<Wtf8> for str {
+    #[inline]
+    fn as_ref(&self) -> &Wtf8 {
+        Wtf8::new(self)
+    }
+}
+
+impl AsRef<Wtf8> for String {
+    #[inline]
+    fn as_ref(&self) -> &Wtf8 {
+        Wtf8::new(self)
+    }
+}
+
+/// Iterator over the code points of a [`Wtf8`], see [`Wtf8::code_points`].
+#[derive(Debug, Clone)]
+pub struct CodePoints<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl CodePoints<'_> {
+    /// Byte offset of the next code point.
+    #[inline]
+    pub(crate) fn offset(&self) -> usize {
+        self.pos
+    }
+}
+
+impl Iterator for CodePoints<'_> {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        let bytes = &self.bytes[self.pos..];
+        let (c, len) = match *bytes {
+            [] => return None,
+            [b0, ..] if b0 < 0x80 => (b0 as u32, 1),
+            [b0, b1, ..] if b0 < 0xE0 => (((b0 as u32 & 0x1F) << 6) | (b1 as u32 & 0x3F), 2),
+            [b0, b1, b2, ..] if b0 < 0xF0 => (
+                ((b0 as u32 & 0x0F) << 12) | ((b1 as u32 & 0x3F) << 6) | (b2 as u32 & 0x3F),
+                3,
+            ),
+            [b0, b1, b2, b3, ..] => (
+                ((b0 as u32 & 0x07) << 18)
+                    | ((b1 as u32 & 0x3F) << 12)
+                    | ((b2 as u32 & 0x3F) << 6)
+                    | (b3 as u32 & 0x3F),
+                4,
+            ),
+            _ => return None,
+        };
+        self.pos += len;
+        Some(c)
+    }
+}
+
+/// An owned, growable [`Wtf8`] string, the result of decoding with an error handler.
+///
+/// # Example
+///
+/// ```rust
+/// use rsutil::types::{Encoding, Errors, Wtf8Buf};
+///
+/// let text = Encoding::Utf8.decode_with(b"log\xff", Errors::SurrogateEscape).unwrap();
+/// assert_eq!(text.as_str(), None);
+/// assert_eq!(text.code_points().last(), Some(0xDCFF));
+/// assert_eq!(text.to_string_lossy(), "log\u{fffd}");
+///
+/// let mut plain = Wtf8Buf::from("ab");
+/// plain.push('c');
+/// assert_eq!(plain.into_string(), Ok("abc".to_string()));
+/// ```
+#[derive(Clone, Default, Eq, PartialEq, Hash)]
+pub struct Wtf8Buf {
+    bytes: Vec<u8>,
+}
+
+impl Wtf8Buf {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            bytes: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Empties the buffer, keeping its capacity.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.bytes.clear();
+    }
+
+    #[inline]
+    pub fn push(&mut self, ch: char) {
+        let mut buf = [0; 4];
+        self.bytes
+            .extend_from_slice(ch.encode_utf8(&mut buf).as_bytes());
+    }
+
+    #[inline]
+    pub fn push_str(&mut self, s: &str) {
+        self.bytes.extend_from_slice(s.as_bytes());
+    }
+
+    /// Appends a code point, surrogates included.
+    ///
+    /// As WTF-8 requires, a low surrogate following a high one merges with it into the
+    /// supplementary character they encode. Values above `U+10FFFF` are ignored.
+    pub(crate) fn push_code_point(&mut self, c: u32) {
+        if let Some(ch) = char::from_u32(c) {
+            return self.push(ch);
+        }
+        match c {
+            0xDC00..=0xDFFF => {
+                if let [.., 0xED, b1 @ 0xA0..=0xAF, b2] = *self.bytes {
+                    let high = 0xD000 | ((b1 as u32 & 0x3F) << 6) | (b2 as u32 & 0x3F);
+                    self.bytes.truncate(self.bytes.len() - 3);
+                    let ch = 0x10000 + ((high - 0xD800) << 10) + (c - 0xDC00);
+                    return self.push(char::from_u32(ch).unwrap_or_default());
+                }
+            }
+            0xD800..=0xDBFF => {}
+            _ => return,
+        }
+        self.bytes.extend_from_slice(&[
+            0xED,
+            0x80 | ((c >> 6) & 0x3F) as u8,
+            0x80 | (c & 0x3F) as u8,
+        ]);
+    }
+
+    /// Appends `s`, merging a leading low surrogate with a trailing high one.
+    pub(crate) fn push_wtf8(&mut self, s: &Wtf8) {
+        let mut points = s.code_points();
+        if let Some(c @ 0xDC00..=0xDFFF) = points.clone().next() {
+            let _ = points.next();
+            self.push_code_point(c);
+        }
+        self.bytes
+            .extend_from_slice(&s.as_bytes()[points.offset()..]);
+    }
+
+    /// Converts to a `String`, or gives `self` back if it holds a surrogate.
+    pub fn into_string(self) -> std::result::Result<String, Self> {
+        String::from_utf8(self.bytes).map_err(|e| Self {
+            bytes: e.into_bytes(),
+        })
+    }
+
+    /// Converts to a `String`, replacing every surrogate by `U+FFFD`.
+    pub fn into_string_lossy(self) -> String {
+        match self.into_string() {
+            Ok(s) => s,
+            Err(s) => s.to_string_lossy().into_owned(),
+        }
+    }
+}
+
+impl Deref for Wtf8Buf {
+    type Target = Wtf8;
+
+    #[inline]
+    fn deref(&self) -> &Wtf8 {
+        Wtf8::from_bytes_unchecked(&self.bytes)
+    }
+}
+
+impl Borrow<Wtf8> for Wtf8Buf {
+    #[inline]
+    fn borrow(&self) -> &Wtf8 {
+        self
+    }
+}
+
+impl AsRef<Wtf8> for Wtf8Buf {
+    #[inline]
+    fn as_ref(&self) -> &Wtf8 {
+        self
+    }
+}
+
+impl From<String> for Wtf8Buf {
+    #[inline]
+    fn from(s: String) -> Self {
+        Self {
+            bytes: s.into_bytes(),
+        }
+    }
+}
+
+impl From<&str> for Wtf8Buf {
+    #[inline]
+    fn from(s: &str) -> Self {
+        Self {
+            bytes: s.as_bytes().to_vec(),
+        }
+    }
+}
+
+impl Debug for Wtf8Buf {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Debug::fmt(&**self, f)
+    }
+}
+
+impl Display for Wtf8Buf {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Display::fmt(&**self, f)
+    }
+}
@@ -0,0 +1,6 @@
+/// Errors raised by the binary helpers in [`types`](crate::types).
+#[derive(Debug, Clone, Eq, PartialEq, thiserror::Error)]
+pub enum Error {
+    #[error("buffer too short: expected at least {expected} bytes, got {actual}")]
+    BufferTooShort { expected: usize, actual: usize },
+}
@@ -0,0 +1,105 @@
+use super::{ByteOrder, Ordered};
+use std::io::{Read, Result, Write};
+
+macro_rules! ordered_methods {
+    (read_ordered => $($name:ident: $ty:ty),* $(,)?) => {
+        $(
+        #[doc = concat!("Reads a `", stringify!($ty), "` in the given byte order.")]
+        #[inline]
+        fn $name(&mut self, order: ByteOrder) -> Result<$ty> {
+            self.read_ordered(order)
+        }
+        )*
+    };
+    (write_ordered => $($name:ident: $ty:ty),* $(,)?) => {
+        $(
+        #[doc = concat!("Writes a `", stringify!($ty), "` in the given byte order.")]
+        #[inline]
+        fn $name(&mut self, order: ByteOrder, value: $ty) -> Result<()> {
+            self.write_ordered(order, value)
+        }
+        )*
+    };
+}
+
+/// Extends every [`Read`] with reads whose [`ByteOrder`] is chosen at runtime.
+///
+/// # Example
+///
+/// ```rust
+/// use rsutil::types::{ByteOrder, ReadBytesOrdered};
+///
+/// // the first byte tells the order of the rest of the stream
+/// let mut stream: &[u8] = &[0x01, 0x78, 0x56, 0x34, 0x12];
+/// let order = match stream.read_u8().unwrap() {
+///     0x00 => ByteOrder::Big,
+///     _ => ByteOrder::Little,
+/// };
+/// assert_eq!(stream.read_u32(order).unwrap(), 0x12345678);
+/// assert!(stream.read_u16(order).is_err());
+/// ```
+pub trait ReadBytesOrdered: Read {
+    /// Reads a `T` in the given byte order.
+    #[inline]
+    fn read_ordered<T: Ordered>(&mut self, order: ByteOrder) -> Result<T> {
+        let mut bytes = T::Bytes::default();
+        self.read_exact(bytes.as_mut())?;
+        Ok(T::from_ordered(order, bytes))
+    }
+
+    #[inline]
+    fn read_u8(&mut self) -> Result<u8> {
+        let mut byte = [0];
+        self.read_exact(&mut byte)?;
+        Ok(byte[0])
+    }
+
+    #[inline]
+    fn read_i8(&mut self) -> Result<i8> {
+        self.read_u8().map(|v| v as i8)
+    }
+
+    ordered_methods!(read_ordered => read_u16: u16, read_u32: u32, read_u64: u64, read_u128: u128,
+        read_i16: i16, read_i32: i32, read_i64: i64, read_i128: i128, read_f32: f32, read_f64: f64);
+}
+
+impl<R: Read + ?Sized> ReadBytesOrdered for R {}
+
+/// Extends every [`Write`] with writes whose [`ByteOrder`] is chosen at runtime.
+///
+/// # Example
+///
+/// ```rust
+/// use rsutil::types::{ByteOrder, WriteBytesOrdered};
+///
+/// let mut out = Vec::new();
+/// out.write_u8(0x01).unwrap();
+/// out.write_u16(ByteOrder::Big, 0x0203).unwrap();
+/// out.write_f64(ByteOrder::Little, 0.5).unwrap();
+///
+/// assert_eq!(&out[..3], &[0x01, 0x02, 0x03]);
+/// assert_eq!(&out[3..], &0.5f64.to_le_bytes());
+/// ```
+pub trait WriteBytesOrdered: Write {
+    /// Writes a `T` in the given byte order.
+    #[inline]
+    fn write_ordered<T: Ordered>(&mut self, order: ByteOrder, value: T) -> Result<()> {
+        self.write_all(value.to_ordered(order).as_ref())
+    }
+
+    #[inline]
+    fn write_u8(&mut self, value: u8) -> Result<()> {
+        self.write_all(&[value])
+    }
+
+    #[inline]
+    fn write_i8(&mut self, value: i8) -> Result<()> {
+        self.write_u8(value as u8)
+    }
+
+    ordered_methods!(write_ordered => write_u16: u16, write_u32: u32, write_u64: u64,
+        write_u128: u128, write_i16: i16, write_i32: i32, write_i64: i64, write_i128: i128,
+        write_f32: f32, write_f64: f64);
+}
+
+impl<W: Write + ?Sized> WriteBytesOrdered for W {}
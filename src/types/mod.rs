@@ -0,0 +1,14 @@
+#[cfg(feature = "tokio")]
+mod aio;
+#[cfg(feature = "tokio")]
+pub use aio::*;
+mod byteorder;
+pub use byteorder::*;
+mod cursor;
+pub use cursor::*;
+mod encoding;
+pub use encoding::*;
+mod error;
+pub use error::*;
+mod io;
+pub use io::*;
@@ -0,0 +1,469 @@
+#!/usr/bin/env python3
+"""Generates the codec tables of `src/types/encoding` from the codecs of CPython.
+
+Every table is read back from the codec it stands for, by decoding each code with it, so
+the tables agree with the Python the script runs on; they were last generated with
+CPython 3.11. Run from the root of the repository:
+
+    python3 tools/gen_tables.py            # rewrite the tables
+    python3 tools/gen_tables.py --check    # fail if a table differs from CPython's codecs
+    python3 tools/gen_tables.py --digests  # print the digests the unit tests compare with
+
+The output needs no formatting: the single-byte tables come out as rustfmt lays them out,
+and rustfmt keeps the string rows of the CJK tables as they are.
+"""
+
+import argparse
+import sys
+import unicodedata
+from pathlib import Path
+
+ROOT = Path(__file__).resolve().parent.parent / "src" / "types" / "encoding"
+
+# (Encoding variant, static, Python codec) of the single-byte code pages, in table order
+CHARMAPS = [
+    ("Cp1250", "CP1250", "cp1250"),
+    ("Cp1251", "CP1251", "cp1251"),
+    ("Cp1252", "CP1252", "cp1252"),
+    ("Cp1253", "CP1253", "cp1253"),
+    ("Cp1254", "CP1254", "cp1254"),
+    ("Cp1255", "CP1255", "cp1255"),
+    ("Cp1256", "CP1256", "cp1256"),
+    ("Cp1257", "CP1257", "cp1257"),
+    ("Cp1258", "CP1258", "cp1258"),
+    ("Iso8859_1", "ISO8859_1", "iso8859_1"),
+    ("Iso8859_2", "ISO8859_2", "iso8859_2"),
+    ("Iso8859_3", "ISO8859_3", "iso8859_3"),
+    ("Iso8859_4", "ISO8859_4", "iso8859_4"),
+    ("Iso8859_5", "ISO8859_5", "iso8859_5"),
+    ("Iso8859_6", "ISO8859_6", "iso8859_6"),
+    ("Iso8859_7", "ISO8859_7", "iso8859_7"),
+    ("Iso8859_8", "ISO8859_8", "iso8859_8"),
+    ("Iso8859_9", "ISO8859_9", "iso8859_9"),
+    ("Iso8859_10", "ISO8859_10", "iso8859_10"),
+    ("Iso8859_11", "ISO8859_11", "iso8859_11"),
+    ("Iso8859_13", "ISO8859_13", "iso8859_13"),
+    ("Iso8859_14", "ISO8859_14", "iso8859_14"),
+    ("Iso8859_15", "ISO8859_15", "iso8859_15"),
+    ("Iso8859_16", "ISO8859_16", "iso8859_16"),
+    ("Cp037", "CP037", "cp037"),
+    ("Cp273", "CP273", "cp273"),
+    ("Cp424", "CP424", "cp424"),
+    ("Cp500", "CP500", "cp500"),
+    ("Cp1026", "CP1026", "cp1026"),
+    ("Cp1140", "CP1140", "cp1140"),
+    ("Cp437", "CP437", "cp437"),
+    ("Cp775", "CP775", "cp775"),
+    ("Cp850", "CP850", "cp850"),
+    ("Cp852", "CP852", "cp852"),
+    ("Cp855", "CP855", "cp855"),
+    ("Cp857", "CP857", "cp857"),
+    ("Cp858", "CP858", "cp858"),
+    ("Cp860", "CP860", "cp860"),
+    ("Cp861", "CP861", "cp861"),
+    ("Cp862", "CP862", "cp862"),
+    ("Cp863", "CP863", "cp863"),
+    ("Cp864", "CP864", "cp864"),
+    ("Cp865", "CP865", "cp865"),
+    ("Cp866", "CP866", "cp866"),
+    ("Cp869", "CP869", "cp869"),
+    ("Cp1125", "CP1125", "cp1125"),
+    ("MacCyrillic", "MACCYRILLIC", "mac_cyrillic"),
+    ("MacGreek", "MACGREEK", "mac_greek"),
+    ("MacIceland", "MACICELAND", "mac_iceland"),
+    ("MacLatin2", "MACLATIN2", "mac_latin2"),
+    ("MacRoman", "MACROMAN", "mac_roman"),
+    ("MacTurkish", "MACTURKISH", "mac_turkish"),
+    ("HpRoman8", "HPROMAN8", "hp_roman8"),
+    ("Koi8R", "KOI8R", "koi8_r"),
+    ("Kz1048", "KZ1048", "kz1048"),
+    ("Ptcp154", "PTCP154", "ptcp154"),
+    ("Tis620", "TIS620", "tis_620"),
+]
+
+# the categories a string or char literal spells as `\u{..}`
+ESCAPED = {"Zs", "Zl", "Zp", "Cc", "Cf", "Co", "Cs", "Cn", "Mn", "Me"}
+
+
+def decode(code, codec, chars=1):
+    """The `chars` characters `code` decodes to with `codec`, or None."""
+    try:
+        text = code.decode(codec)
+    except UnicodeDecodeError:
+        return None
+    return text if len(text) == chars else None
+
+
+def literal(ch):
+    if ch in "'\"\\":
+        return "\\" + ch
+    if unicodedata.category(ch) in ESCAPED:
+        return "\\u{%x}" % ord(ch)
+    return ch
+
+
+def charmap_tables():
+    out = [
+        "// Decoding tables of the single-byte codecs, generated by `tools/gen_tables.py` from",
+        "// CPython's `encodings` modules; `0xFFFE` marks a byte that maps to <undefined>.",
+        "",
+        "use super::Charmap;",
+        "use crate::types::Encoding;",
+        "",
+        "pub(crate) fn table(encoding: Encoding) -> Option<&'static Charmap> {",
+        "    Some(match encoding {",
+    ]
+    out += [f"        Encoding::{variant} => &{name}," for variant, name, _ in CHARMAPS]
+    out += ["        _ => return None,", "    })", "}"]
+    for _, name, codec in CHARMAPS:
+        points = [decode(bytes([b]), codec) for b in range(256)]
+        cells = ["0x%04X," % (0xFFFE if c is None else ord(c)) for c in points]
+        out += ["", f"static {name}: Charmap = Charmap::new(["]
+        out += ["    " + " ".join(cells[i : i + 12]) for i in range(0, 256, 12)]
+        out.append("]);")
+    return out
+
+
+def table(doc, name, leads, trails, char):
+    """A `Table` of the codes `lead << 8 | trail`, `char(lead, trail)` giving each one."""
+    out = [""] + [f"/// {line}" for line in doc]
+    out += [
+        f"pub(crate) static {name}: Table = Table::new(",
+        "    0x%02X%02X," % (leads[0], trails[0]),
+        f"    {len(trails)},",
+        "    concat!(",
+    ]
+    for lead in leads:
+        row = "".join(literal(ch) if (ch := char(lead, t)) else "\\0" for t in trails)
+        out.append(f'        "{row}",')
+    return out + ["    ),", ");"]
+
+
+def sparse(doc, name, codes):
+    out = [""] + [f"/// {line}" for line in doc]
+    out.append(f"pub(crate) static {name}: Sparse = Sparse::new(&[")
+    out += ["    (0x%04X, '%s')," % (code, literal(ch)) for code, ch in codes]
+    return out + ["]);"]
+
+
+def pairs(doc, name, codes):
+    out = [""] + [f"/// {line}" for line in doc]
+    out.append(f"pub(crate) static {name}: &[(u16, char, char)] = &[")
+    out += ["    (0x%04X, '%s', '%s')," % (c, literal(s[0]), literal(s[1])) for c, s in codes]
+    return out + ["];"]
+
+
+def header(uses):
+    return [
+        "// Generated by `tools/gen_tables.py` from CPython's codecs; do not edit by hand.",
+        "",
+        f"use super::super::{uses};",
+    ]
+
+
+def differing(codec, base, leads, trails):
+    """The codes `codec` decodes to a single character that `base` doesn't decode to."""
+    for lead in leads:
+        for trail in trails:
+            code = bytes([lead, trail])
+            ch = decode(code, codec)
+            if ch and ch != decode(code, base):
+                yield lead << 8 | trail, ch
+
+
+def two_chars(codec, prefix, leads, trails):
+    for lead in leads:
+        for trail in trails:
+            text = decode(prefix + bytes([lead, trail]), codec, 2)
+            if text:
+                yield lead << 8 | trail, text
+
+
+def big5_tables():
+    trails = range(0x40, 0xFF)
+    hkscs = range(0x87, 0xFF)
+
+    def hkscs_char(lead, trail):
+        ch = decode(bytes([lead, trail]), "big5hkscs")
+        return ch if ch != decode(bytes([lead, trail]), "big5") else None
+
+    return (
+        header("{Sparse, Table}")
+        + table(
+            ["Big5, leads `0xA1..=0xF9` and trails `0x40..=0xFE`."],
+            "BIG5",
+            range(0xA1, 0xFA),
+            trails,
+            lambda lead, trail: decode(bytes([lead, trail]), "big5"),
+        )
+        + sparse(
+            ["The codes Microsoft's cp950 adds to Big5 or maps differently."],
+            "CP950EXT",
+            differing("cp950", "big5", range(0x81, 0xFF), trails),
+        )
+        + table(
+            [
+                "The codes HKSCS-2004 adds to Big5 or maps differently, leads `0x87..=0xFE`; see",
+                "[`BIG5HKSCS_PAIRS`] for the codes decoding to two code points.",
+            ],
+            "BIG5HKSCS",
+            hkscs,
+            trails,
+            hkscs_char,
+        )
+        + pairs(
+            ["The codes of HKSCS decoding to a base character and a combining mark."],
+            "BIG5HKSCS_PAIRS",
+            two_chars("big5hkscs", b"", hkscs, trails),
+        )
+    )
+
+
+def gb18030_ranges():
+    """The runs of code points whose four-byte codes are consecutive."""
+    points, index = [], 0
+    for b1 in range(0x81, 0x85):
+        for b2 in range(0x30, 0x3A):
+            for b3 in range(0x81, 0xFF):
+                for b4 in range(0x30, 0x3A):
+                    ch = decode(bytes([b1, b2, b3, b4]), "gb18030")
+                    if ch and ord(ch) <= 0xFFFF:
+                        points.append((ord(ch), index))
+                    index += 1
+    ranges = []
+    for point, index in points:
+        if ranges and ranges[-1][1] + 1 == point and ranges[-1][2] + point - ranges[-1][0] == index:
+            ranges[-1][1] = point
+        else:
+            ranges.append([point, point, index])
+    return ranges
+
+
+def gb_tables():
+    leads, trails = range(0x81, 0xFF), range(0x40, 0xFF)
+    out = header("{Sparse, Table}")
+    out += table(
+        ["GB 2312, rows `0x21..=0x77` and columns `0x21..=0x7E`."],
+        "GB2312",
+        range(0x21, 0x78),
+        range(0x21, 0x7F),
+        lambda row, col: decode(bytes([row | 0x80, col | 0x80]), "gb2312"),
+    )
+    out += table(
+        [
+            "GBK, leads `0x81..=0xFE` and trails `0x40..=0xFE`; in the GB 2312 area it differs from",
+            "[`GB2312`] on `0xA1A4` and `0xA1AA` only.",
+        ],
+        "GBK",
+        leads,
+        trails,
+        lambda lead, trail: decode(bytes([lead, trail]), "gbk"),
+    )
+    out += sparse(
+        ["The two-byte codes GB 18030 adds to GBK."],
+        "GB18030EXT",
+        differing("gb18030", "gbk", leads, trails),
+    )
+    out += [
+        "",
+        "/// The code points of the four-byte codes `0x81308130..=0x8439FE39`, numbered from 0: each",
+        "/// `(first, last, index)` range maps the code points `first..=last` from `index` on.",
+        "pub(crate) static GB18030_RANGES: &[(u16, u16, u16)] = &[",
+    ]
+    out += ["    (0x%04X, 0x%04X, %d)," % tuple(r) for r in gb18030_ranges()]
+    return out + ["];"]
+
+
+def jis_tables():
+    rows = range(0x21, 0x7F)
+
+    def iso2022(codec, escape):
+        return lambda row, col: decode(escape + bytes([row, col]), codec)
+
+    # the user-defined area `0xF040..=0xF9FC` maps to the private use area by rule
+    sjis = [lead for lead in [*range(0x81, 0xA0), *range(0xE0, 0xFD)] if not 0xF0 <= lead <= 0xF9]
+    return (
+        header("{Sparse, Table}")
+        + table(
+            ["JIS X 0208, rows and columns `0x21..=0x7E`."],
+            "JISX0208",
+            rows,
+            rows,
+            iso2022("iso2022_jp", b"\x1b$B"),
+        )
+        + table(
+            ["JIS X 0212, the supplementary kanji of EUC-JP."],
+            "JISX0212",
+            rows,
+            rows,
+            iso2022("iso2022_jp_1", b"\x1b$(D"),
+        )
+        + table(
+            [
+                "JIS X 0213:2004 plane 1, a superset of JIS X 0208; see [`JISX0213_PAIRS`] for the codes",
+                "decoding to two code points.",
+            ],
+            "JISX0213_1",
+            rows,
+            rows,
+            iso2022("iso2022_jp_2004", b"\x1b$(Q"),
+        )
+        + table(
+            ["JIS X 0213 plane 2."],
+            "JISX0213_2",
+            rows,
+            rows,
+            iso2022("iso2022_jp_2004", b"\x1b$(P"),
+        )
+        + pairs(
+            ["The codes of JIS X 0213 plane 1 decoding to a base character and a combining mark."],
+            "JISX0213_PAIRS",
+            two_chars("iso2022_jp_2004", b"\x1b$(Q", rows, rows),
+        )
+        + sparse(
+            [
+                "The Shift_JIS codes of the NEC and IBM extensions of cp932, and the codes it maps",
+                "differently from JIS X 0208.",
+            ],
+            "CP932EXT",
+            differing("cp932", "shift_jis", sjis, range(0x40, 0xFD)),
+        )
+    )
+
+
+def ksc_tables():
+    def cp949_ext(lead, trail):
+        if lead >= 0xA1 and trail >= 0xA1:
+            return None
+        return decode(bytes([lead, trail]), "cp949")
+
+    # KS X 1001 comes from cp949, as euc_kr only decodes the Hangul filler `0xA4D4` as the
+    # head of a composed syllable
+    return (
+        header("Table")
+        + table(
+            ["KS X 1001, rows `0x21..=0x7D` and columns `0x21..=0x7E`."],
+            "KSX1001",
+            range(0x21, 0x7E),
+            range(0x21, 0x7F),
+            lambda row, col: decode(bytes([row | 0x80, col | 0x80]), "cp949"),
+        )
+        + table(
+            [
+                "The Hangul syllables Microsoft's cp949 adds to KS X 1001, leads `0x81..=0xFE` and",
+                "trails `0x41..=0xFE`.",
+            ],
+            "CP949EXT",
+            range(0x81, 0xFF),
+            range(0x41, 0xFF),
+            cp949_ext,
+        )
+    )
+
+
+# (Encoding variant, Python codec) of the CJK codecs whose tables the tests check
+CJK = [
+    ("ShiftJis", "shift_jis"),
+    ("Cp932", "cp932"),
+    ("EucJp", "euc_jp"),
+    ("ShiftJis2004", "shift_jis_2004"),
+    ("ShiftJisx0213", "shift_jisx0213"),
+    ("EucJis2004", "euc_jis_2004"),
+    ("EucJisx0213", "euc_jisx0213"),
+    ("Gb2312", "gb2312"),
+    ("Gbk", "gbk"),
+    ("Gb18030", "gb18030"),
+    ("Big5", "big5"),
+    ("Cp950", "cp950"),
+    ("Big5HkScs", "big5hkscs"),
+    ("EucKr", "euc_kr"),
+    ("Cp949", "cp949"),
+    ("Johab", "johab"),
+]
+
+# the codes of each digest, like the tests make them, and the codecs it is taken of
+DIGESTS = [
+    (
+        "every byte",
+        [bytes([b]) for b in range(0x100)],
+        [(variant, codec) for variant, _, codec in CHARMAPS]
+        + [("Ascii", "ascii"), ("Latin1", "latin-1")],
+    ),
+    (
+        "leads `0x80..=0xFF` and trails `0x30..=0xFF`",
+        [bytes([lead, trail]) for lead in range(0x80, 0x100) for trail in range(0x30, 0x100)],
+        CJK,
+    ),
+    (
+        "`0x8F` and two bytes `0xA1..=0xFE`",
+        [bytes([0x8F, a, b]) for a in range(0xA1, 0xFF) for b in range(0xA1, 0xFF)],
+        [("EucJp", "euc_jp"), ("EucJis2004", "euc_jis_2004"), ("EucJisx0213", "euc_jisx0213")],
+    ),
+    (
+        "four-byte codes with the first byte `0x81..=0x84`, `0x90` or `0xE3`",
+        [
+            bytes([a, b, c, d])
+            for a in [0x81, 0x82, 0x83, 0x84, 0x90, 0xE3]
+            for b in range(0x30, 0x3A)
+            for c in range(0x81, 0xFF)
+            for d in range(0x30, 0x3A)
+        ],
+        [("Gb18030", "gb18030")],
+    ),
+]
+
+
+def digest(codec, codes):
+    """FNV-1a of each code decoded and the text encoded again, as `digest` in codec.rs."""
+    h = 0xCBF29CE484222325
+    for code in codes:
+        try:
+            text = code.decode(codec)
+            data = text.encode() + b"\xff"
+            try:
+                data += text.encode(codec) + b"\xff"
+            except UnicodeEncodeError:
+                data += b"\xfe\xff"
+        except UnicodeDecodeError:
+            data = b"\xfe\xff"
+        for byte in data:
+            h = (h ^ byte) * 0x100000001B3 % 2**64
+    return h
+
+
+FILES = {
+    "charmap/tables.rs": charmap_tables,
+    "cjk/tables/big5.rs": big5_tables,
+    "cjk/tables/gb.rs": gb_tables,
+    "cjk/tables/jis.rs": jis_tables,
+    "cjk/tables/ksc.rs": ksc_tables,
+}
+
+
+def main():
+    parser = argparse.ArgumentParser(description=__doc__.splitlines()[0])
+    parser.add_argument("--check", action="store_true", help="only compare the tables")
+    parser.add_argument("--digests", action="store_true", help="print the digests of the tests")
+    args = parser.parse_args()
+    if args.digests:
+        for codes, items, codecs in DIGESTS:
+            print(f"// {codes}")
+            for variant, codec in codecs:
+                print(f"(Encoding::{variant}, 0x{digest(codec, items):016x}),")
+        return 0
+    stale = []
+    for path, generate in FILES.items():
+        text = "\n".join(generate()) + "\n"
+        target = ROOT / path
+        if args.check:
+            if target.read_text(encoding="utf-8") != text:
+                stale.append(path)
+        else:
+            target.write_text(text, encoding="utf-8")
+    for path in stale:
+        print(f"{path} differs from the codecs of Python {sys.version.split()[0]}")
+    return 1 if stale else 0
+
+
+if __name__ == "__main__":
+    sys.exit(main())